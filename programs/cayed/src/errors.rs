@@ -60,4 +60,222 @@ pub enum CayedError {
     // Reveal Winner
     #[msg("Not all ships have been sunk yet")]
     NotAllShipsSunk,
+
+    // Map
+    #[msg("Blocked cells must lie within the grid")]
+    BlockedCellOutOfBounds,
+    #[msg("Map leaves too few open cells to hide a fleet")]
+    MapTooCrowded,
+    #[msg("Map grid size does not match the game grid size")]
+    MapGridMismatch,
+    #[msg("Ships cannot be placed on blocked cells")]
+    ShipOnBlockedCell,
+    #[msg("Blocked cells cannot be attacked")]
+    AttackOnBlockedCell,
+
+    // Traps
+    #[msg("Mines and decoys are limited to half the grid size each")]
+    TooManyTraps,
+    #[msg("Incorrect number of mines or decoys for this game")]
+    IncorrectTrapsLen,
+    #[msg("Mines and decoys must sit on open cells inside the grid, away from ships")]
+    InvalidTrapPlacement,
+    #[msg("Ships, mines and decoys don't fit on the board")]
+    BoardTooCrowded,
+
+    // Special Weapons
+    #[msg("Special weapons are limited to 3 charges each")]
+    TooManyCharges,
+    #[msg("No charges left for this special weapon")]
+    NoChargesLeft,
+
+    // Match
+    #[msg("Best-of must be an odd number of games up to 9")]
+    InvalidBestOf,
+    #[msg("The current match game has not been recorded yet")]
+    MatchGameInProgress,
+    #[msg("Game is not the match's current game")]
+    NotCurrentMatchGame,
+    #[msg("Only the creator can cancel a game")]
+    NotGameCreator,
+
+    // Multiplayer
+    #[msg("Multiplayer games seat 3 or 4 players, team games exactly 4")]
+    InvalidPlayerCount,
+    #[msg("Board or permission accounts do not match the seated players")]
+    InvalidBoardAccounts,
+    #[msg("The pot has already been claimed")]
+    PotAlreadyClaimed,
+    #[msg("Refund accounts must match the players being refunded")]
+    InvalidRefundAccounts,
+    #[msg("This action is only available in team games")]
+    NotATeamGame,
+    #[msg("Both teams must be full before boards can be shared")]
+    TeamIncomplete,
+
+    // First Move
+    #[msg("Who moves first has already been decided")]
+    FirstMoveAlreadyDecided,
+    #[msg("Who moves first has not been decided yet")]
+    FirstMoveNotDecided,
+    #[msg("Both players must commit to the coin flip, or neither")]
+    CoinFlipMismatch,
+    #[msg("This game decides the first move by coin flip")]
+    CoinFlipInUse,
+    #[msg("This game has no coin flip")]
+    NoCoinFlip,
+    #[msg("Secret already revealed")]
+    SecretAlreadyRevealed,
+    #[msg("Secret does not match the commitment")]
+    InvalidSecret,
+
+    // Auto Placement
+    #[msg("Not enough open water to place the fleet")]
+    NoRoomForFleet,
+
+    // Time Control
+    #[msg("A clock increment needs a time bank")]
+    InvalidTimeControl,
+    #[msg("Your time bank has run out")]
+    TimeBankExpired,
+    #[msg("The clock isn't running for this game")]
+    ClockNotRunning,
+    #[msg("The player on turn still has time left")]
+    OpponentHasTime,
+    #[msg("Reveal your own coin flip secret before claiming")]
+    CoinFlipNotRevealed,
+    #[msg("The opponent can still reveal their coin flip secret")]
+    RevealWindowOpen,
+
+    // Handicap
+    #[msg("Handicap is not valid for this grid")]
+    InvalidHandicap,
+    #[msg("A handicap's extra ship must be a single cell, listed last")]
+    ExtraShipTooLong,
+
+    // Ammunition
+    #[msg("More ammunition than cells on the board")]
+    TooMuchAmmo,
+    #[msg("You have fired all your shots")]
+    NoAmmoLeft,
+
+    // Maneuvers
+    #[msg("Maneuvers are not enabled for this game")]
+    ManeuversDisabled,
+    #[msg("No ship at that index")]
+    InvalidShipIndex,
+    #[msg("Damaged ships cannot maneuver")]
+    ShipDamaged,
+    #[msg("Ships must stay on open, unattacked water when maneuvering")]
+    IllegalManeuver,
+    #[msg("You have used all your maneuvers")]
+    NoManeuversLeft,
+
+    // Invites
+    #[msg("This game is invite-only and you are not invited")]
+    NotInvited,
+
+    // Challenge
+    #[msg("This challenge has expired")]
+    ChallengeExpired,
+    #[msg("Challenge is not signed by the challenger")]
+    InvalidChallengeSignature,
+    #[msg("A wagered challenge needs the challenger's escrow")]
+    EscrowRequired,
+    #[msg("Not enough lamports in escrow")]
+    InsufficientEscrow,
+
+    // Queue
+    #[msg("No such wager bucket")]
+    InvalidWagerBucket,
+    #[msg("You are already waiting in this queue")]
+    AlreadyQueued,
+    #[msg("The queue is full")]
+    QueueFull,
+    #[msg("You are not in this queue")]
+    NotQueued,
+    #[msg("At least two players must be queued to make a match")]
+    NotEnoughPlayers,
+
+    // Open Games
+    #[msg("Open games pages must be created in order")]
+    InvalidOpenGamesPage,
+    #[msg("This open games page is full")]
+    OpenGamesPageFull,
+    #[msg("The game is not listed on this open games page")]
+    GameNotListed,
+
+    // Settle Game
+    #[msg("The game has already been settled")]
+    GameAlreadySettled,
+
+    // Active Games
+    #[msg("Active games cap must be between 1 and 32")]
+    InvalidActiveGamesCap,
+    #[msg("You are already in as many games as allowed")]
+    TooManyActiveGames,
+
+    // Fee
+    #[msg("Fee cannot exceed 10,000 basis points")]
+    InvalidFee,
+
+    // Rematch
+    #[msg("A rematch has already been offered")]
+    RematchAlreadyOffered,
+    #[msg("No rematch has been offered")]
+    NoRematchOffer,
+    #[msg("Double or nothing needs a winner")]
+    RematchNeedsWinner,
+    #[msg("The other player has to accept the rematch")]
+    CannotAcceptOwnRematch,
+
+    // Ranked
+    #[msg("Ranked games cannot have a handicap")]
+    RankedHandicap,
+
+    // Season
+    #[msg("Season must end after it starts")]
+    InvalidSeasonSlots,
+    #[msg("Prize split must have at most 10 places and add up to no more than 100%")]
+    InvalidPrizeSplit,
+    #[msg("Another season is still running")]
+    SeasonInProgress,
+    #[msg("The current season account must be passed")]
+    SeasonRequired,
+    #[msg("The season has not ended yet")]
+    SeasonNotOver,
+    #[msg("The season is already closed")]
+    SeasonClosed,
+    #[msg("The season has not closed yet")]
+    SeasonNotClosed,
+    #[msg("Nothing to claim")]
+    NoPrize,
+    #[msg("Prize already claimed")]
+    PrizeAlreadyClaimed,
+
+    // Tournament
+    #[msg("Tournaments take a power of two between 2 and 16 players")]
+    InvalidTournamentSize,
+    #[msg("The tournament is full")]
+    TournamentFull,
+    #[msg("You are already registered")]
+    AlreadyRegistered,
+    #[msg("The tournament is not taking registrations")]
+    RegistrationClosed,
+    #[msg("The tournament is not in progress")]
+    TournamentNotInProgress,
+    #[msg("No such pairing in this round")]
+    InvalidPairing,
+    #[msg("This pairing already has a game or a winner")]
+    PairingStarted,
+    #[msg("The game is not part of this tournament's current round")]
+    NotTournamentGame,
+    #[msg("The tournament has not finished")]
+    TournamentNotFinished,
+    #[msg("A tournament needs at least two entrants")]
+    TooFewEntrants,
+    #[msg("The pairing's deadline has not passed")]
+    PairingNotOverdue,
+    #[msg("Both fleets are hidden and the clock is running")]
+    PairingUnderway,
 }
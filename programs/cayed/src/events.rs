@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// A new `Game` or `MultiGame` PDA was created under a program-assigned id.
+#[event]
+pub struct GameCreated {
+    pub id: u64,
+    pub creator: Pubkey,
+    pub kind: GameKind,
+}
+
+/// Which flow a `GameCreated` id was taken by.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum GameKind {
+    /// A one-on-one `Game` outside any series or bracket.
+    Single,
+    Match {
+        match_id: u64,
+    },
+    Tournament {
+        tournament_id: u64,
+    },
+    /// A free-for-all or team game at `["multi_game", id]`.
+    Multi,
+}
@@ -0,0 +1,190 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use solana_sdk_ids::sysvar::instructions;
+
+use crate::errors::CayedError;
+use crate::events::{GameCreated, GameKind};
+use crate::state::{
+    Challenge, ChallengeReceipt, Config, Escrow, Game, GameSettings, GameStatus, PlayerBoard,
+    PlayerGames, Vault,
+};
+
+/// Creates a game from a challenge player 1 signed off-chain. The transaction must carry
+/// an Ed25519 verification of that signature right before this instruction.
+#[derive(Accounts)]
+#[instruction(challenge: Challenge)]
+pub struct AcceptChallenge<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    /// CHECK: The challenger, whose signature is verified against the terms
+    #[account(address = challenge.challenger)]
+    pub challenger: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Game::INIT_SPACE,
+        seeds = [b"game", config.game_counter.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + PlayerBoard::INIT_SPACE,
+        seeds = [b"player", config.game_counter.to_le_bytes().as_ref(), challenger.key().as_ref()],
+        bump,
+    )]
+    pub challenger_board: Account<'info, PlayerBoard>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + PlayerBoard::INIT_SPACE,
+        seeds = [b"player", config.game_counter.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub player_board: Account<'info, PlayerBoard>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", challenger.key().as_ref()],
+        bump,
+    )]
+    pub challenger_games: Account<'info, PlayerGames>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", player.key().as_ref()],
+        bump,
+    )]
+    pub player_games: Account<'info, PlayerGames>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + ChallengeReceipt::INIT_SPACE,
+        seeds = [b"challenge", challenger.key().as_ref(), challenge.nonce.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub receipt: Account<'info, ChallengeReceipt>,
+    /// Funds the challenger's stake; only needed for wagered challenges
+    #[account(
+        mut,
+        seeds = [b"escrow", challenger.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Option<Account<'info, Escrow>>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Instructions sysvar, read to find the Ed25519 verification
+    #[account(address = instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AcceptChallenge<'info> {
+    pub fn accept_challenge(
+        &mut self,
+        challenge: Challenge,
+        bumps: AcceptChallengeBumps,
+    ) -> Result<u64> {
+        let player = self.player.key();
+        require!(
+            player != challenge.challenger,
+            CayedError::CannotJoinSelfGame
+        );
+        require!(
+            challenge.opponent.is_none_or(|opponent| opponent == player),
+            CayedError::NotInvited
+        );
+        require!(
+            Clock::get()?.unix_timestamp <= challenge.expiry,
+            CayedError::ChallengeExpired
+        );
+
+        let grid_size = challenge.grid_size;
+        require!(grid_size % 2 == 0 && grid_size > 0, CayedError::GridNotEven);
+        require!(
+            grid_size <= self.config.max_grid_size,
+            CayedError::GridSizeTooLarge
+        );
+
+        challenge.verify_signature(&self.instructions)?;
+
+        // Both stakes land in the vault in this one transaction
+        let wager = challenge.wager;
+        if wager > 0 {
+            require!(wager.ge(&100_000u64), CayedError::MinimumWager);
+            let escrow = self.escrow.as_ref().ok_or(CayedError::EscrowRequired)?;
+            Escrow::pay_out(escrow, &self.vault.to_account_info(), wager)?;
+            self.deposit(wager)?;
+        }
+
+        let id = self.config.next_game_id()?;
+        let mut game = Game::new(
+            id,
+            grid_size,
+            challenge.challenger,
+            wager,
+            GameSettings::default(),
+            bumps.game,
+        );
+        game.player_2 = Some(player);
+        game.status = GameStatus::HidingShips;
+        self.game.set_inner(game);
+
+        self.challenger_board.set_inner(PlayerBoard::new(
+            id,
+            challenge.challenger,
+            bumps.challenger_board,
+        ));
+        self.player_board
+            .set_inner(PlayerBoard::new(id, player, bumps.player_board));
+        self.receipt.set_inner(ChallengeReceipt {
+            game_id: id,
+            bump: bumps.receipt,
+        });
+
+        let cap = self.config.max_active_games;
+        self.challenger_games.player = challenge.challenger;
+        self.challenger_games.bump = bumps.challenger_games;
+        self.challenger_games.track(id, cap)?;
+        self.player_games.player = player;
+        self.player_games.bump = bumps.player_games;
+        self.player_games.track(id, cap)?;
+
+        emit!(GameCreated {
+            id,
+            creator: challenge.challenger,
+            kind: GameKind::Single,
+        });
+
+        Ok(id)
+    }
+
+    pub fn deposit(&mut self, wager: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, wager)
+    }
+}
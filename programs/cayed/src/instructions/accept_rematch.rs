@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::CayedError;
+use crate::events::{GameCreated, GameKind};
+use crate::state::{
+    Config, Game, GameStatus, PlayerBoard, PlayerGames, PlayerProfile, Season, Vault,
+};
+
+/// Settles a finished game into a fresh one between the same players, on the terms the
+/// other player offered. Boards are keyed by game id, so the new game gets new ones.
+#[derive(Accounts)]
+pub struct AcceptRematch<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump = game.bump,
+        constraint = game.match_id.is_none() @ CayedError::InvalidGameStatus,
+        constraint = game.tournament_id.is_none() @ CayedError::InvalidGameStatus,
+        constraint = !game.settled @ CayedError::GameAlreadySettled,
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Game::INIT_SPACE,
+        seeds = [b"game", config.game_counter.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub new_game: Account<'info, Game>,
+
+    /// CHECK: Player 1, paid out here
+    #[account(mut, address = game.player_1)]
+    pub player_1: UncheckedAccount<'info>,
+    /// CHECK: Player 2, paid out here
+    #[account(mut, address = game.player_2.unwrap_or_default())]
+    pub player_2: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = player,
+        space = 8 + PlayerBoard::INIT_SPACE,
+        seeds = [b"player", config.game_counter.to_le_bytes().as_ref(), player_1.key().as_ref()],
+        bump,
+    )]
+    pub player1_board: Account<'info, PlayerBoard>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + PlayerBoard::INIT_SPACE,
+        seeds = [b"player", config.game_counter.to_le_bytes().as_ref(), player_2.key().as_ref()],
+        bump,
+    )]
+    pub player2_board: Account<'info, PlayerBoard>,
+    #[account(
+        mut,
+        seeds = [b"player_games", player_1.key().as_ref()],
+        bump = player1_games.bump,
+    )]
+    pub player1_games: Account<'info, PlayerGames>,
+    #[account(
+        mut,
+        seeds = [b"player_games", player_2.key().as_ref()],
+        bump = player2_games.bump,
+    )]
+    pub player2_games: Account<'info, PlayerGames>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"profile", player_1.key().as_ref()],
+        bump,
+    )]
+    pub player1_profile: Account<'info, PlayerProfile>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"profile", player_2.key().as_ref()],
+        bump,
+    )]
+    pub player2_profile: Account<'info, PlayerProfile>,
+    /// Required while `Config.season` is set
+    #[account(
+        mut,
+        seeds = [b"season", season.id.to_le_bytes().as_ref()],
+        bump = season.bump,
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AcceptRematch<'info> {
+    pub fn accept_rematch(&mut self, bumps: AcceptRematchBumps) -> Result<u64> {
+        let player = self.player.key();
+        let offer = self.game.rematch.take().ok_or(CayedError::NoRematchOffer)?;
+        require!(
+            player == self.game.player_1 || Some(player) == self.game.player_2,
+            CayedError::Unauthorized
+        );
+        require!(offer.from != player, CayedError::CannotAcceptOwnRematch);
+
+        self.player1_profile
+            .open(self.player_1.key(), bumps.player1_profile);
+        self.player2_profile
+            .open(self.player_2.key(), bumps.player2_profile);
+        self.game.settle(
+            &self.config,
+            &mut self.player1_profile,
+            &mut self.player2_profile,
+            self.season.as_deref_mut(),
+        )?;
+
+        let winner = self.game.winner();
+        let stake = self
+            .game
+            .rematch_wager(offer.double_or_nothing, &self.config)?;
+        // Double or nothing keeps the winner's payout in the vault as their new stake
+        if !offer.double_or_nothing {
+            self.game.pay_out(
+                &self.config,
+                &self.vault,
+                &self.player_1.to_account_info(),
+                &self.player_2.to_account_info(),
+            )?;
+        }
+        let deposit = if offer.double_or_nothing && winner == Some(player) {
+            0
+        } else {
+            stake
+        };
+        if deposit > 0 {
+            self.deposit(deposit)?;
+        }
+
+        let id = self.config.next_game_id()?;
+        let (player_1, player_2) = (self.player_1.key(), self.player_2.key());
+        let mut game = Game::new(
+            id,
+            self.game.grid_size,
+            player_1,
+            stake,
+            self.game.settings.clone(),
+            bumps.new_game,
+        );
+        game.map = self.game.map;
+        game.blocked_mask = self.game.blocked_mask;
+        game.player_2 = Some(player_2);
+        game.status = GameStatus::HidingShips;
+        // Whoever went second last time opens
+        game.next_move_player_1 = !self.game.player_1_opened();
+        game.first_move_decided = true;
+        self.new_game.set_inner(game);
+
+        self.player1_board
+            .set_inner(PlayerBoard::new(id, player_1, bumps.player1_board));
+        self.player2_board
+            .set_inner(PlayerBoard::new(id, player_2, bumps.player2_board));
+
+        let cap = self.config.max_active_games;
+        let old_id = self.game.id;
+        self.player1_games.untrack(old_id);
+        self.player1_games.track(id, cap)?;
+        self.player2_games.untrack(old_id);
+        self.player2_games.track(id, cap)?;
+
+        emit!(GameCreated {
+            id,
+            creator: offer.from,
+            kind: GameKind::Single,
+        });
+
+        Ok(id)
+    }
+
+    pub fn deposit(&mut self, wager: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, wager)
+    }
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use anchor_lang::Discriminator;
+use ephemeral_vrf_sdk::consts::{DEFAULT_EPHEMERAL_QUEUE, IDENTITY, VRF_PROGRAM_ID};
+use ephemeral_vrf_sdk::instructions::{create_request_randomness_ix, RequestRandomnessParams};
+use ephemeral_vrf_sdk::types::SerializableAccountMeta;
+
+use crate::errors::CayedError;
+use crate::state::{Game, GameStatus, PlayerBoard};
+
+/// Asks the MagicBlock VRF to lay out the player's fleet. Runs on the ER; the oracle
+/// answers through `callback_auto_place_ships`. Its randomness is public, so the layout
+/// is seeded with it and a salt the player keeps on their private board.
+#[derive(Accounts)]
+pub struct AutoPlaceShips<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump,
+        constraint = matches!(game.status, GameStatus::HidingShips) @ CayedError::InvalidGameStatus,
+    )]
+    pub game: Account<'info, Game>,
+
+    #[account(
+        mut,
+        seeds = [b"player", game.id.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump,
+        constraint = player_board.ship_coordinates.is_empty() @ CayedError::ShipsAlreadyPlaced,
+    )]
+    pub player_board: Account<'info, PlayerBoard>,
+
+    /// CHECK: This program's identity PDA, signs the request
+    #[account(seeds = [IDENTITY], bump)]
+    pub program_identity: UncheckedAccount<'info>,
+    /// CHECK: The oracle queue
+    #[account(mut, address = DEFAULT_EPHEMERAL_QUEUE)]
+    pub oracle_queue: UncheckedAccount<'info>,
+    /// CHECK: VRF PROGRAM
+    #[account(address = VRF_PROGRAM_ID)]
+    pub vrf_program: UncheckedAccount<'info>,
+    /// CHECK: Slot hashes sysvar
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AutoPlaceShips<'info> {
+    pub fn auto_place_ships(
+        &mut self,
+        client_seed: u8,
+        salt: [u8; 32],
+        bumps: AutoPlaceShipsBumps,
+    ) -> Result<()> {
+        self.player_board.placement_salt = salt;
+
+        let mut caller_seed = self.player_board.key().to_bytes();
+        caller_seed[0] ^= client_seed;
+
+        let ix = create_request_randomness_ix(RequestRandomnessParams {
+            payer: self.player.key(),
+            oracle_queue: self.oracle_queue.key(),
+            callback_program_id: crate::ID,
+            callback_discriminator: crate::instruction::CallbackAutoPlaceShips::DISCRIMINATOR
+                .to_vec(),
+            accounts_metas: Some(vec![
+                SerializableAccountMeta {
+                    pubkey: self.game.key(),
+                    is_signer: false,
+                    is_writable: true,
+                },
+                SerializableAccountMeta {
+                    pubkey: self.player_board.key(),
+                    is_signer: false,
+                    is_writable: true,
+                },
+            ]),
+            caller_seed,
+            ..Default::default()
+        });
+
+        invoke_signed(
+            &ix,
+            &[
+                self.player.to_account_info(),
+                self.program_identity.to_account_info(),
+                self.oracle_queue.to_account_info(),
+                self.system_program.to_account_info(),
+                self.slot_hashes.to_account_info(),
+                self.vrf_program.to_account_info(),
+            ],
+            &[&[IDENTITY, &[bumps.program_identity]]],
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use ephemeral_vrf_sdk::consts::scoped_vrf_identity;
+use solana_sha256_hasher::hashv;
+
+use crate::state::{fleet_masks, random_cells, random_fleet, BoardRng, Game, PlayerBoard};
+
+#[derive(Accounts)]
+pub struct CallbackAutoPlaceShips<'info> {
+    /// Only the VRF program can sign as its identity for this program, so the randomness
+    /// is genuine
+    #[account(address = scoped_vrf_identity(&crate::ID))]
+    pub vrf_program_identity: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub game: Account<'info, Game>,
+
+    #[account(
+        mut,
+        seeds = [b"player", game.id.to_le_bytes().as_ref(), player_board.player.as_ref()],
+        bump = player_board.bump,
+    )]
+    pub player_board: Account<'info, PlayerBoard>,
+}
+
+impl<'info> CallbackAutoPlaceShips<'info> {
+    pub fn callback_auto_place_ships(&mut self, randomness: [u8; 32]) -> Result<()> {
+        // The player may have hidden their ships by hand while the oracle was busy
+        if !self.player_board.ship_coordinates.is_empty() {
+            return Ok(());
+        }
+
+        let grid_size = self.game.grid_size;
+        let blocked_mask = self.game.blocked_mask;
+        // Anyone can read `randomness` off the callback; the salt never leaves the board
+        let seed = hashv(&[&randomness, &self.player_board.placement_salt]).to_bytes();
+        self.player_board.placement_salt = [0; 32];
+        let mut rng = BoardRng::new(&seed);
+
+        let is_p1 = self.player_board.player == self.game.player_1;
+        let extra_ships = self.game.settings.handicap.extra_ships(is_p1);
+        let ships = random_fleet(&mut rng, grid_size, blocked_mask, extra_ships)?;
+        let (ship_masks, all_ships_mask) = fleet_masks(&ships, grid_size, blocked_mask)?;
+
+        let occupied = all_ships_mask | blocked_mask;
+        let mines_mask = random_cells(&mut rng, self.game.settings.mines, grid_size, occupied)?;
+        let decoys_mask = random_cells(
+            &mut rng,
+            self.game.settings.decoys,
+            grid_size,
+            occupied | mines_mask,
+        )?;
+
+        self.player_board.ship_coordinates = ships;
+        self.player_board.ship_masks = ship_masks;
+        self.player_board.all_ships_mask = all_ships_mask;
+        self.player_board.mines_mask = mines_mask;
+        self.player_board.decoys_mask = decoys_mask;
+        self.game.ships_hidden(is_p1, Clock::get()?.unix_timestamp);
+
+        Ok(())
+    }
+}
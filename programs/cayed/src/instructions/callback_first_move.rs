@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use ephemeral_vrf_sdk::consts::scoped_vrf_identity;
+
+use crate::state::Game;
+
+#[derive(Accounts)]
+pub struct CallbackFirstMove<'info> {
+    /// Only the VRF program can sign as its identity for this program, so the randomness
+    /// is genuine
+    #[account(address = scoped_vrf_identity(&crate::ID))]
+    pub vrf_program_identity: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub game: Account<'info, Game>,
+}
+
+impl<'info> CallbackFirstMove<'info> {
+    pub fn callback_first_move(&mut self, randomness: [u8; 32]) -> Result<()> {
+        // A late answer to a duplicate request must not overturn the first one
+        if self.game.first_move_decided {
+            return Ok(());
+        }
+        self.game.next_move_player_1 = randomness[0] & 1 == 0;
+        self.game.first_move_decided = true;
+        self.game.start_clock(Clock::get()?.unix_timestamp);
+
+        Ok(())
+    }
+}
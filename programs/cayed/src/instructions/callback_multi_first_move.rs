@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use ephemeral_vrf_sdk::consts::scoped_vrf_identity;
+
+use crate::state::MultiGame;
+
+#[derive(Accounts)]
+pub struct CallbackMultiFirstMove<'info> {
+    /// Only the VRF program can sign as its identity for this program, so the randomness
+    /// is genuine
+    #[account(address = scoped_vrf_identity(&crate::ID))]
+    pub vrf_program_identity: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"multi_game", multi_game.id.to_le_bytes().as_ref()],
+        bump = multi_game.bump,
+    )]
+    pub multi_game: Account<'info, MultiGame>,
+}
+
+impl<'info> CallbackMultiFirstMove<'info> {
+    pub fn callback_multi_first_move(&mut self, randomness: [u8; 32]) -> Result<()> {
+        // A late answer to a duplicate request must not overturn the first one
+        if self.multi_game.first_move_decided {
+            return Ok(());
+        }
+        self.multi_game.draw_opener(randomness);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Game, GameStatus, OpenGames, PlayerGames, Vault};
+
+#[derive(Accounts)]
+pub struct CancelGame<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump = game.bump,
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        mut,
+        seeds = [b"open_games", open_games.page.to_le_bytes().as_ref()],
+        bump = open_games.bump,
+    )]
+    pub open_games: Account<'info, OpenGames>,
+    #[account(
+        mut,
+        seeds = [b"player_games", player.key().as_ref()],
+        bump = player_games.bump,
+    )]
+    pub player_games: Account<'info, PlayerGames>,
+
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+impl<'info> CancelGame<'info> {
+    pub fn cancel_game(&mut self) -> Result<()> {
+        require!(
+            self.game.player_1 == self.player.key(),
+            CayedError::NotGameCreator
+        );
+        require!(
+            self.game.status == GameStatus::AwaitingPlayerTwo,
+            CayedError::InvalidGameStatus
+        );
+
+        self.game.status = GameStatus::Cancelled;
+        self.open_games.unlist(self.game.id)?;
+        self.player_games.untrack(self.game.id);
+
+        if self.game.wager > 0 {
+            Vault::pay_out(&self.vault, &self.player.to_account_info(), self.game.wager)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Match, MatchStatus, Vault};
+
+#[derive(Accounts)]
+pub struct CancelMatch<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"match", game_match.id.to_le_bytes().as_ref()],
+        bump = game_match.bump,
+    )]
+    pub game_match: Account<'info, Match>,
+
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+impl<'info> CancelMatch<'info> {
+    pub fn cancel_match(&mut self) -> Result<()> {
+        require!(
+            self.game_match.player_1 == self.player.key(),
+            CayedError::NotGameCreator
+        );
+        require!(
+            self.game_match.status == MatchStatus::AwaitingPlayerTwo,
+            CayedError::InvalidGameStatus
+        );
+
+        self.game_match.status = MatchStatus::Cancelled;
+
+        if self.game_match.wager > 0 {
+            Vault::pay_out(
+                &self.vault,
+                &self.player.to_account_info(),
+                self.game_match.wager,
+            )?;
+        }
+
+        Ok(())
+    }
+}
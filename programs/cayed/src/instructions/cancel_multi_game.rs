@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{GameStatus, MultiGame, Vault};
+
+/// Calls off a multiplayer game that never filled and refunds every seated player. The
+/// remaining accounts are the seated players' wallets, in seat order.
+#[derive(Accounts)]
+pub struct CancelMultiGame<'info> {
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"multi_game", multi_game.id.to_le_bytes().as_ref()],
+        bump = multi_game.bump,
+        constraint = multi_game.status == GameStatus::AwaitingPlayerTwo @ CayedError::InvalidGameStatus,
+    )]
+    pub multi_game: Account<'info, MultiGame>,
+
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+impl<'info> CancelMultiGame<'info> {
+    pub fn cancel_multi_game(&mut self, remaining: &'info [AccountInfo<'info>]) -> Result<()> {
+        require!(
+            self.multi_game.players[0] == self.player.key(),
+            CayedError::NotGameCreator
+        );
+        require!(
+            remaining.len() == self.multi_game.players.len(),
+            CayedError::InvalidRefundAccounts
+        );
+
+        self.multi_game.status = GameStatus::Cancelled;
+
+        let wager = self.multi_game.wager;
+        for (player, wallet) in self.multi_game.players.iter().zip(remaining) {
+            require_keys_eq!(wallet.key(), *player, CayedError::InvalidRefundAccounts);
+            if wager > 0 {
+                Vault::pay_out(&self.vault, wallet, wager)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Tournament, TournamentStatus};
+
+/// Calls off a tournament that has not started and refunds every entry fee. The remaining
+/// accounts are the entrants' wallets, in registration order.
+#[derive(Accounts)]
+pub struct CancelTournament<'info> {
+    #[account(address = tournament.organizer @ CayedError::Unauthorized)]
+    pub organizer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Registration @ CayedError::RegistrationClosed,
+    )]
+    pub tournament: Account<'info, Tournament>,
+}
+
+impl<'info> CancelTournament<'info> {
+    pub fn cancel_tournament(&mut self, remaining: &'info [AccountInfo<'info>]) -> Result<()> {
+        require!(
+            remaining.len() == self.tournament.entrants.len(),
+            CayedError::InvalidRefundAccounts
+        );
+
+        self.tournament.status = TournamentStatus::Cancelled;
+
+        let entry_fee = self.tournament.entry_fee;
+        for (index, wallet) in remaining.iter().enumerate() {
+            require_keys_eq!(
+                wallet.key(),
+                self.tournament.entrants[index].player,
+                CayedError::InvalidRefundAccounts
+            );
+            if entry_fee > 0 {
+                Tournament::pay_out(&self.tournament, wallet, entry_fee)?;
+            }
+        }
+
+        Ok(())
+    }
+}
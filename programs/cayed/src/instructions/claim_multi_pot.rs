@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Config, GameStatus, MultiGame, Vault};
+
+#[derive(Accounts)]
+pub struct ClaimMultiPot<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"multi_game", multi_game.id.to_le_bytes().as_ref()],
+        bump = multi_game.bump,
+        constraint = matches!(multi_game.status, GameStatus::WinnerRevealed { .. }) @ CayedError::InvalidGameStatus,
+    )]
+    pub multi_game: Account<'info, MultiGame>,
+
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+impl<'info> ClaimMultiPot<'info> {
+    pub fn claim_multi_pot(&mut self) -> Result<()> {
+        let GameStatus::WinnerRevealed { winner } = self.multi_game.status else {
+            return err!(CayedError::InvalidGameStatus);
+        };
+        let seat = self
+            .multi_game
+            .seat_of(&self.player.key())
+            .ok_or(CayedError::Unauthorized)?;
+        require!(
+            self.multi_game.on_winning_side(seat, &winner),
+            CayedError::Unauthorized
+        );
+        require!(
+            (self.multi_game.claimed_mask >> seat) & 1 == 0,
+            CayedError::PotAlreadyClaimed
+        );
+        self.multi_game.claimed_mask |= 1 << seat;
+
+        let pot = self
+            .multi_game
+            .wager
+            .checked_mul(self.multi_game.players.len() as u64)
+            .ok_or(CayedError::Overflow)?;
+        // Winning teams split the pot between both members
+        let share = if self.multi_game.teams { pot / 2 } else { pot };
+        let payout = share
+            .checked_sub(self.config.fee_on(share))
+            .ok_or(CayedError::Overflow)?;
+        if payout > 0 {
+            Vault::pay_out(&self.vault, &self.player.to_account_info(), payout)?;
+        }
+
+        Ok(())
+    }
+}
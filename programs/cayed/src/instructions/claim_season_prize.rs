@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::Season;
+
+#[derive(Accounts)]
+pub struct ClaimSeasonPrize<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"season", season.id.to_le_bytes().as_ref()],
+        bump = season.bump,
+        constraint = season.closed @ CayedError::SeasonNotClosed,
+    )]
+    pub season: Account<'info, Season>,
+}
+
+impl<'info> ClaimSeasonPrize<'info> {
+    pub fn claim_season_prize(&mut self) -> Result<()> {
+        let player = self.player.key();
+        let place = self
+            .season
+            .standings
+            .iter()
+            .position(|standing| standing.player == player)
+            .ok_or(CayedError::NoPrize)?;
+        require!(
+            !self.season.standings[place].claimed,
+            CayedError::PrizeAlreadyClaimed
+        );
+        let prize = self.season.prize(place);
+        require!(prize > 0, CayedError::NoPrize);
+        self.season.standings[place].claimed = true;
+
+        Season::pay_out(&self.season, &self.player.to_account_info(), prize)?;
+
+        Ok(())
+    }
+}
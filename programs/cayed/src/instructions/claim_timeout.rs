@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::{anchor::commit, ephem::commit_accounts};
+
+use crate::errors::CayedError;
+use crate::state::{Game, GameStatus};
+
+/// Claims the win when the player on turn has run out of time, or when the opponent let
+/// the coin flip reveal deadline pass without revealing. Runs on the ER; follow up with
+/// `reveal_winner` to settle the boards.
+#[commit]
+#[derive(Accounts)]
+pub struct ClaimTimeout<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump,
+        constraint = matches!(
+            game.status,
+            GameStatus::HidingShips | GameStatus::InProgress
+        ) @ CayedError::InvalidGameStatus,
+    )]
+    pub game: Account<'info, Game>,
+}
+
+impl<'info> ClaimTimeout<'info> {
+    pub fn claim_timeout(&mut self) -> Result<()> {
+        let player = self.player.key();
+        let is_p1 = player == self.game.player_1;
+        require!(
+            is_p1 || Some(player) == self.game.player_2,
+            CayedError::Unauthorized
+        );
+        let now = Clock::get()?.unix_timestamp;
+
+        match &self.game.coin_flip {
+            Some(coin_flip) if !self.game.first_move_decided => {
+                require!(coin_flip.revealed(is_p1), CayedError::CoinFlipNotRevealed);
+                require!(
+                    !coin_flip.revealed(!is_p1) && now > coin_flip.reveal_deadline,
+                    CayedError::RevealWindowOpen
+                );
+            }
+            _ => {
+                // Only the player waiting on their opponent can claim
+                require!(
+                    is_p1 != self.game.next_move_player_1,
+                    CayedError::InvalidTurn
+                );
+
+                let left = self
+                    .game
+                    .time_left(now)
+                    .ok_or(CayedError::ClockNotRunning)?;
+                require!(left <= 0, CayedError::OpponentHasTime);
+
+                if is_p1 {
+                    self.game.time_left_player_2 = 0;
+                } else {
+                    self.game.time_left_player_1 = 0;
+                }
+            }
+        }
+        self.game.status = GameStatus::Forfeited { winner: player };
+
+        self.game.exit(&crate::ID)?;
+
+        commit_accounts(
+            &self.player,
+            vec![&self.game.to_account_info()],
+            &self.magic_context,
+            &self.magic_program,
+        )?;
+
+        Ok(())
+    }
+}
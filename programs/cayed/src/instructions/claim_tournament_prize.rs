@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct ClaimTournamentPrize<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = matches!(tournament.status, TournamentStatus::Completed { .. }) @ CayedError::TournamentNotFinished,
+    )]
+    pub tournament: Account<'info, Tournament>,
+}
+
+impl<'info> ClaimTournamentPrize<'info> {
+    pub fn claim_tournament_prize(&mut self) -> Result<()> {
+        let player = self.player.key();
+        let index = self
+            .tournament
+            .entrants
+            .iter()
+            .position(|entrant| entrant.player == player)
+            .ok_or(CayedError::NoPrize)?;
+        let entrant = &self.tournament.entrants[index];
+        require!(!entrant.claimed, CayedError::PrizeAlreadyClaimed);
+        let prize = self.tournament.prize(entrant);
+        require!(prize > 0, CayedError::NoPrize);
+        self.tournament.entrants[index].claimed = true;
+
+        Tournament::pay_out(&self.tournament, &self.player.to_account_info(), prize)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Config, Season};
+
+/// Freezes a finished season's standings so winners can claim their prizes, and hands
+/// the authority back the part of the pool nobody can claim.
+#[derive(Accounts)]
+pub struct CloseSeason<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"season", season.id.to_le_bytes().as_ref()],
+        bump = season.bump,
+        constraint = !season.closed @ CayedError::SeasonClosed,
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> CloseSeason<'info> {
+    pub fn close_season(&mut self) -> Result<()> {
+        require!(
+            self.authority.key() == self.config.authority,
+            CayedError::Unauthorized
+        );
+        require!(
+            Clock::get()?.slot > self.season.end_slot,
+            CayedError::SeasonNotOver
+        );
+
+        self.season.closed = true;
+        if self.config.season == Some(self.season.id) {
+            self.config.season = None;
+        }
+
+        let unawarded = self.season.unawarded();
+        if unawarded > 0 {
+            Season::pay_out(&self.season, &self.authority.to_account_info(), unawarded)?;
+        }
+
+        Ok(())
+    }
+}
@@ -4,10 +4,21 @@ use anchor_lang::{
 };
 
 use crate::errors::CayedError;
-use crate::state::{Config, Game, GameStatus, PlayerBoard, Vault};
+use crate::events::{GameCreated, GameKind};
+use crate::state::{
+    board_mask, CoinFlip, Config, Game, GameSettings, Invite, Map, OpenGame, OpenGames,
+    PlayerBoard, PlayerGames, Vault,
+};
 
 #[derive(Accounts)]
-#[instruction(id: u64)]
+#[instruction(
+    grid_size: u8,
+    wager: u64,
+    settings: GameSettings,
+    first_move_commitment: Option<[u8; 32]>,
+    invite: Option<Invite>,
+    page: u32,
+)]
 pub struct CreateGame<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
@@ -16,7 +27,7 @@ pub struct CreateGame<'info> {
         init,
         payer = player,
         space = 8 + Game::INIT_SPACE,
-        seeds = [b"game", id.to_le_bytes().as_ref()],
+        seeds = [b"game", config.game_counter.to_le_bytes().as_ref()],
         bump,
     )]
     pub game: Account<'info, Game>,
@@ -24,16 +35,34 @@ pub struct CreateGame<'info> {
         init,
         payer = player,
         space = 8 + PlayerBoard::INIT_SPACE,
-        seeds = [b"player", id.to_le_bytes().as_ref(), player.key().as_ref()],
+        seeds = [b"player", config.game_counter.to_le_bytes().as_ref(), player.key().as_ref()],
         bump,
     )]
     pub player_board: Account<'info, PlayerBoard>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", player.key().as_ref()],
+        bump,
+    )]
+    pub player_games: Account<'info, PlayerGames>,
 
     #[account(
+        mut,
         seeds = [b"config"],
         bump
     )]
     pub config: Account<'info, Config>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + OpenGames::INIT_SPACE,
+        seeds = [b"open_games", page.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub open_games: Account<'info, OpenGames>,
+    pub map: Option<Account<'info, Map>>,
     #[account(
         mut,
         seeds = [b"vault"],
@@ -45,52 +74,84 @@ pub struct CreateGame<'info> {
 }
 
 impl<'info> CreateGame<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn create_game(
         &mut self,
-        id: u64,
         grid_size: u8,
         wager: u64,
+        settings: GameSettings,
+        first_move_commitment: Option<[u8; 32]>,
+        invite: Option<Invite>,
+        page: u32,
         bumps: CreateGameBumps,
-    ) -> Result<()> {
+    ) -> Result<u64> {
         require!(grid_size % 2 == 0 && grid_size > 0, CayedError::GridNotEven);
         require!(
             grid_size <= self.config.max_grid_size,
             CayedError::GridSizeTooLarge
         );
 
+        settings.validate(grid_size)?;
+
+        let (map, blocked_mask) = match &self.map {
+            Some(map) => {
+                require!(map.grid_size == grid_size, CayedError::MapGridMismatch);
+                let open_cells = (board_mask(grid_size) & !map.blocked_mask).count_ones();
+                require!(
+                    settings.cells_needed(grid_size) <= open_cells,
+                    CayedError::MapTooCrowded
+                );
+                (Some(map.key()), map.blocked_mask)
+            }
+            None => (None, 0),
+        };
+
         if wager > 0 {
             require!(wager.ge(&100_000u64), CayedError::MinimumWager);
             self.deposit(wager)?;
         }
-        // Deterministic first-move selection based on game id parity
-        let first_move = id % 2 == 0;
 
-        self.game.set_inner(Game {
+        // Ids come from the program so nobody can squat or pick them
+        let id = self.config.next_game_id()?;
+        let mut game = Game::new(
             id,
             grid_size,
-            player_1: self.player.key(),
-            player_2: None,
-            revealed_ships_player_1: vec![],
-            revealed_ships_player_2: vec![],
-            moves: vec![],
-            next_move_player_1: first_move,
+            self.player.key(),
             wager,
-            status: GameStatus::AwaitingPlayerTwo,
-            bump: bumps.game,
-        });
+            settings,
+            bumps.game,
+        );
+        game.map = map;
+        game.blocked_mask = blocked_mask;
+        game.coin_flip = first_move_commitment.map(CoinFlip::new);
+        game.invite = invite;
+        self.game.set_inner(game);
 
-        self.player_board.set_inner(PlayerBoard {
-            game_id: self.game.id,
-            player: self.player.key(),
-            bump: bumps.player_board,
-            ship_coordinates: vec![],
-            ship_masks: vec![],
-            all_ships_mask: 0,
-            hits_bitmap: 0,
-            sunk_mask: 0,
+        self.config.use_open_games_page(page)?;
+        self.open_games.page = page;
+        self.open_games.bump = bumps.open_games;
+        self.open_games.list(OpenGame {
+            id,
+            creator: self.player.key(),
+            grid_size,
+            wager,
+            invite_only: self.game.invite.is_some(),
+        })?;
+
+        self.player_board
+            .set_inner(PlayerBoard::new(id, self.player.key(), bumps.player_board));
+
+        self.player_games.player = self.player.key();
+        self.player_games.bump = bumps.player_games;
+        self.player_games.track(id, self.config.max_active_games)?;
+
+        emit!(GameCreated {
+            id,
+            creator: self.player.key(),
+            kind: GameKind::Single,
         });
 
-        Ok(())
+        Ok(id)
     }
 
     pub fn deposit(&mut self, wager: u64) -> Result<()> {
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{board_mask, Config, Map};
+
+#[derive(Accounts)]
+pub struct CreateMap<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Map::INIT_SPACE,
+        seeds = [b"map", config.map_counter.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub map: Account<'info, Map>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateMap<'info> {
+    pub fn create_map(
+        &mut self,
+        grid_size: u8,
+        blocked_mask: u64,
+        bumps: CreateMapBumps,
+    ) -> Result<u64> {
+        require!(grid_size % 2 == 0 && grid_size > 0, CayedError::GridNotEven);
+        require!(
+            grid_size <= self.config.max_grid_size,
+            CayedError::GridSizeTooLarge
+        );
+
+        let board = board_mask(grid_size);
+        require!(
+            blocked_mask & !board == 0,
+            CayedError::BlockedCellOutOfBounds
+        );
+        // Leave at least one open cell per ship so a fleet can always be hidden
+        let open_cells = (board & !blocked_mask).count_ones();
+        require!(
+            open_cells >= (grid_size / 2) as u32,
+            CayedError::MapTooCrowded
+        );
+
+        let id = self.config.next_map_id()?;
+        self.map.set_inner(Map {
+            id,
+            creator: self.creator.key(),
+            grid_size,
+            blocked_mask,
+            bump: bumps.map,
+        });
+
+        Ok(id)
+    }
+}
@@ -0,0 +1,103 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::errors::CayedError;
+use crate::state::{Config, GameSettings, Match, MatchStatus, PlayerGames, Vault};
+
+#[derive(Accounts)]
+pub struct CreateMatch<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Match::INIT_SPACE,
+        seeds = [b"match", config.match_counter.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub game_match: Account<'info, Match>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", player.key().as_ref()],
+        bump,
+    )]
+    pub player_games: Account<'info, PlayerGames>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateMatch<'info> {
+    pub fn create_match(
+        &mut self,
+        grid_size: u8,
+        wager: u64,
+        settings: GameSettings,
+        best_of: u8,
+        bumps: CreateMatchBumps,
+    ) -> Result<u64> {
+        require!(grid_size % 2 == 0 && grid_size > 0, CayedError::GridNotEven);
+        require!(
+            grid_size <= self.config.max_grid_size,
+            CayedError::GridSizeTooLarge
+        );
+        settings.validate(grid_size)?;
+        require!(best_of % 2 == 1 && best_of <= 9, CayedError::InvalidBestOf);
+        self.player_games.player = self.player.key();
+        self.player_games.bump = bumps.player_games;
+        self.player_games.check_room(self.config.max_active_games)?;
+
+        if wager > 0 {
+            require!(wager.ge(&100_000u64), CayedError::MinimumWager);
+            self.deposit(wager)?;
+        }
+
+        let id = self.config.next_match_id()?;
+        self.game_match.set_inner(Match {
+            id,
+            player_1: self.player.key(),
+            player_2: None,
+            grid_size,
+            settings,
+            wager,
+            wins_required: best_of / 2 + 1,
+            wins_player_1: 0,
+            wins_player_2: 0,
+            games: vec![],
+            winners: vec![],
+            next_opener_player_1: true,
+            status: MatchStatus::AwaitingPlayerTwo,
+            bump: bumps.game_match,
+        });
+
+        Ok(id)
+    }
+
+    pub fn deposit(&mut self, wager: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, wager)
+    }
+}
@@ -0,0 +1,114 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::errors::CayedError;
+use crate::events::{GameCreated, GameKind};
+use crate::state::{Config, GameStatus, MultiGame, PlayerBoard, Vault};
+
+#[derive(Accounts)]
+pub struct CreateMultiGame<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        init,
+        payer = player,
+        space = 8 + MultiGame::INIT_SPACE,
+        seeds = [b"multi_game", config.game_counter.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub multi_game: Account<'info, MultiGame>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + PlayerBoard::INIT_SPACE,
+        seeds = [b"multi_player", config.game_counter.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub player_board: Account<'info, PlayerBoard>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateMultiGame<'info> {
+    pub fn create_multi_game(
+        &mut self,
+        grid_size: u8,
+        wager: u64,
+        max_players: u8,
+        teams: bool,
+        bumps: CreateMultiGameBumps,
+    ) -> Result<u64> {
+        require!(grid_size % 2 == 0 && grid_size > 0, CayedError::GridNotEven);
+        require!(
+            grid_size <= self.config.max_grid_size,
+            CayedError::GridSizeTooLarge
+        );
+        require!(
+            (3..=4).contains(&max_players),
+            CayedError::InvalidPlayerCount
+        );
+        require!(!teams || max_players == 4, CayedError::InvalidPlayerCount);
+
+        if wager > 0 {
+            require!(wager.ge(&100_000u64), CayedError::MinimumWager);
+            self.deposit(wager)?;
+        }
+
+        let id = self.config.next_game_id()?;
+        self.multi_game.set_inner(MultiGame {
+            id,
+            grid_size,
+            max_players,
+            teams,
+            players: vec![self.player.key()],
+            ships_placed_mask: 0,
+            eliminated_mask: 0,
+            turn: 0,
+            first_move_decided: false,
+            revealed_ships: vec![],
+            moves: vec![],
+            wager,
+            claimed_mask: 0,
+            status: GameStatus::AwaitingPlayerTwo,
+            bump: bumps.multi_game,
+        });
+
+        self.player_board
+            .set_inner(PlayerBoard::new(id, self.player.key(), bumps.player_board));
+
+        emit!(GameCreated {
+            id,
+            creator: self.player.key(),
+            kind: GameKind::Multi,
+        });
+
+        Ok(id)
+    }
+
+    pub fn deposit(&mut self, wager: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, wager)
+    }
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::CayedError;
+use crate::state::{Config, Season, SeasonRanking};
+
+/// Opens a season and escrows its prize pool in the season account, so prizes never come
+/// out of the players' stakes in the vault.
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateSeason<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Season::INIT_SPACE,
+        seeds = [b"season", id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateSeason<'info> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_season(
+        &mut self,
+        id: u64,
+        start_slot: u64,
+        end_slot: u64,
+        ranking: SeasonRanking,
+        prize_pool: u64,
+        prize_split: Vec<u16>,
+        bumps: CreateSeasonBumps,
+    ) -> Result<()> {
+        require!(
+            self.authority.key() == self.config.authority,
+            CayedError::Unauthorized
+        );
+        require!(self.config.season.is_none(), CayedError::SeasonInProgress);
+        require!(start_slot < end_slot, CayedError::InvalidSeasonSlots);
+        require!(
+            prize_split.len() <= Season::MAX_STANDINGS
+                && prize_split.iter().map(|share| *share as u32).sum::<u32>() <= 10_000,
+            CayedError::InvalidPrizeSplit
+        );
+
+        self.season.set_inner(Season {
+            id,
+            start_slot,
+            end_slot,
+            ranking,
+            prize_pool,
+            prize_split,
+            standings: vec![],
+            closed: false,
+            bump: bumps.season,
+        });
+        self.config.season = Some(id);
+
+        if prize_pool > 0 {
+            self.deposit(prize_pool)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn deposit(&mut self, prize_pool: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.authority.to_account_info(),
+            to: self.season.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, prize_pool)
+    }
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Config, GameSettings, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct CreateTournament<'info> {
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + Tournament::INIT_SPACE,
+        seeds = [b"tournament", config.tournament_counter.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateTournament<'info> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_tournament(
+        &mut self,
+        grid_size: u8,
+        settings: GameSettings,
+        entry_fee: u64,
+        max_players: u8,
+        prize_split: Vec<u16>,
+        round_secs: u32,
+        bumps: CreateTournamentBumps,
+    ) -> Result<u64> {
+        require!(grid_size % 2 == 0 && grid_size > 0, CayedError::GridNotEven);
+        require!(
+            grid_size <= self.config.max_grid_size,
+            CayedError::GridSizeTooLarge
+        );
+        settings.validate(grid_size)?;
+        // A clock and a round deadline keep any one player from stalling the bracket
+        require!(
+            settings.time_bank_secs > 0 && round_secs > 0,
+            CayedError::InvalidTimeControl
+        );
+        require!(
+            max_players.is_power_of_two() && (2..=Tournament::MAX_PLAYERS).contains(&max_players),
+            CayedError::InvalidTournamentSize
+        );
+        Tournament::validate_prize_split(&prize_split, max_players)?;
+
+        let id = self.config.next_tournament_id()?;
+        self.tournament.set_inner(Tournament {
+            id,
+            organizer: self.organizer.key(),
+            grid_size,
+            settings,
+            entry_fee,
+            round_secs,
+            max_players,
+            prize_split,
+            entrants: vec![],
+            bracket: vec![],
+            pairings: vec![],
+            round: 0,
+            rounds: 0,
+            status: TournamentStatus::Registration,
+            bump: bumps.tournament,
+        });
+
+        Ok(id)
+    }
+}
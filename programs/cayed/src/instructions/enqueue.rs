@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::CayedError;
+use crate::state::{Config, PlayerGames, Queue, Vault};
+
+#[derive(Accounts)]
+#[instruction(grid_size: u8, bucket: u8)]
+pub struct Enqueue<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + Queue::INIT_SPACE,
+        seeds = [b"queue", grid_size.to_le_bytes().as_ref(), bucket.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub queue: Account<'info, Queue>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", player.key().as_ref()],
+        bump,
+    )]
+    pub player_games: Account<'info, PlayerGames>,
+
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Enqueue<'info> {
+    pub fn enqueue(&mut self, grid_size: u8, bucket: u8, bumps: EnqueueBumps) -> Result<()> {
+        require!(grid_size % 2 == 0 && grid_size > 0, CayedError::GridNotEven);
+        require!(
+            grid_size <= self.config.max_grid_size,
+            CayedError::GridSizeTooLarge
+        );
+        let wager = Queue::wager(bucket)?;
+
+        let player = self.player.key();
+        require!(
+            !self.queue.entries.contains(&player),
+            CayedError::AlreadyQueued
+        );
+        require!(
+            self.queue.entries.len() < Queue::MAX_ENTRIES,
+            CayedError::QueueFull
+        );
+        self.player_games.player = player;
+        self.player_games.bump = bumps.player_games;
+        self.player_games.check_room(self.config.max_active_games)?;
+
+        // First player in opens the queue
+        if self.queue.entries.is_empty() {
+            self.queue.grid_size = grid_size;
+            self.queue.bucket = bucket;
+            self.queue.bump = bumps.queue;
+        }
+        self.queue.entries.push(player);
+
+        if wager > 0 {
+            self.deposit(wager)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn deposit(&mut self, wager: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, wager)
+    }
+}
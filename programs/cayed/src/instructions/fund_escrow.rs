@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::state::Escrow;
+
+#[derive(Accounts)]
+pub struct FundEscrow<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + Escrow::INIT_SPACE,
+        seeds = [b"escrow", player.key().as_ref()],
+        bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundEscrow<'info> {
+    pub fn fund_escrow(&mut self, amount: u64, bumps: FundEscrowBumps) -> Result<()> {
+        self.escrow.set_inner(Escrow {
+            player: self.player.key(),
+            bump: bumps.escrow,
+        });
+
+        self.deposit(amount)
+    }
+
+    pub fn deposit(&mut self, amount: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player.to_account_info(),
+            to: self.escrow.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, amount)
+    }
+}
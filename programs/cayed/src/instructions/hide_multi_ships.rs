@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{fleet_masks, GameStatus, MultiGame, PlayerBoard, ShipCoordinates};
+
+#[derive(Accounts)]
+pub struct HideMultiShips<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"multi_game", multi_game.id.to_le_bytes().as_ref()],
+        bump = multi_game.bump,
+        constraint = matches!(multi_game.status, GameStatus::HidingShips) @ CayedError::InvalidGameStatus,
+    )]
+    pub multi_game: Account<'info, MultiGame>,
+
+    #[account(
+        mut,
+        seeds = [b"multi_player", multi_game.id.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub player_board: Account<'info, PlayerBoard>,
+}
+
+impl<'info> HideMultiShips<'info> {
+    pub fn hide_multi_ships(&mut self, ships: Vec<ShipCoordinates>) -> Result<()> {
+        require!(
+            self.player_board.ship_coordinates.is_empty(),
+            CayedError::ShipsAlreadyPlaced
+        );
+        let seat = self
+            .multi_game
+            .seat_of(&self.player.key())
+            .ok_or(CayedError::Unauthorized)?;
+
+        let grid_size = self.multi_game.grid_size;
+        require!(
+            ships.len() == (grid_size / 2) as usize,
+            CayedError::IncorrectShipsLen,
+        );
+
+        let (ship_masks, all_ships_mask) = fleet_masks(&ships, grid_size, 0)?;
+
+        self.player_board.ship_coordinates = ships;
+        self.player_board.ship_masks = ship_masks;
+        self.player_board.all_ships_mask = all_ships_mask;
+        self.multi_game.ships_placed_mask |= 1 << seat;
+
+        Ok(())
+    }
+}
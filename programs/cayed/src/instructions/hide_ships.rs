@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::CayedError;
-use crate::state::{cell_bit, Game, GameStatus, PlayerBoard, ShipCoordinates};
+use crate::state::{cell_bit, fleet_masks, Cell, Game, GameStatus, PlayerBoard, ShipCoordinates};
 
 #[derive(Accounts)]
 pub struct HideShips<'info> {
@@ -9,6 +9,7 @@ pub struct HideShips<'info> {
     pub player: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"game", game.id.to_le_bytes().as_ref()],
         bump,
         constraint = matches!(game.status, GameStatus::HidingShips) @ CayedError::InvalidGameStatus,
@@ -24,59 +25,72 @@ pub struct HideShips<'info> {
 }
 
 impl<'info> HideShips<'info> {
-    pub fn hide_ships(&mut self, ships: Vec<ShipCoordinates>) -> Result<()> {
+    pub fn hide_ships(
+        &mut self,
+        ships: Vec<ShipCoordinates>,
+        mines: Vec<Cell>,
+        decoys: Vec<Cell>,
+    ) -> Result<()> {
         require!(
             self.player_board.ship_coordinates.is_empty(),
             CayedError::ShipsAlreadyPlaced
         );
 
-        let required_ships = (self.game.grid_size / 2) as usize;
+        let is_p1 = self.player.key() == self.game.player_1;
+        let extra_ships = self.game.settings.handicap.extra_ships(is_p1) as usize;
+        let required_ships = (self.game.grid_size / 2) as usize + extra_ships;
         require!(
             ships.len().eq(&required_ships),
             CayedError::IncorrectShipsLen,
         );
+        // A handicap's extra ships come last and take a single cell each
+        require!(
+            ships[ships.len() - extra_ships..]
+                .iter()
+                .all(|ship| ship.start_x == ship.end_x && ship.start_y == ship.end_y),
+            CayedError::ExtraShipTooLong
+        );
 
         let grid_size = self.game.grid_size;
         let half = grid_size / 2;
 
-        let mut ship_masks: Vec<u64> = Vec::with_capacity(ships.len());
-        let mut all_ships_mask: u64 = 0;
-
-        for ship in &ships {
-            // Ships must have start <= end
-            require!(
-                ship.start_x <= ship.end_x && ship.start_y <= ship.end_y,
-                CayedError::ShipCoordsReversed
-            );
+        let (ship_masks, all_ships_mask) = fleet_masks(&ships, grid_size, self.game.blocked_mask)?;
 
-            // Ships must be linear (horizontal or vertical, not rectangular)
-            require!(
-                ship.start_x == ship.end_x || ship.start_y == ship.end_y,
-                CayedError::ShipNotLinear
-            );
+        let settings = &self.game.settings;
+        require!(
+            mines.len() == settings.mines as usize && decoys.len() == settings.decoys as usize,
+            CayedError::IncorrectTrapsLen
+        );
 
-            // Bounds check (since start <= end, only need to check end)
+        // Mines and decoys each take a single open cell
+        let mut occupied = all_ships_mask | self.game.blocked_mask;
+        let mut mines_mask: u64 = 0;
+        let mut decoys_mask: u64 = 0;
+        for (cell, is_mine) in mines
+            .iter()
+            .map(|c| (c, true))
+            .chain(decoys.iter().map(|c| (c, false)))
+        {
             require!(
-                ship.end_x < grid_size && ship.end_y < half,
-                CayedError::InvalidShipPlacement
+                cell.x < grid_size && cell.y < half,
+                CayedError::InvalidTrapPlacement
             );
-
-            // Compute bitmask for this ship and check for overlaps
-            let mut mask: u64 = 0;
-            for x in ship.start_x..=ship.end_x {
-                for y in ship.start_y..=ship.end_y {
-                    let bit = cell_bit(x, y, grid_size);
-                    require!((all_ships_mask & bit) == 0, CayedError::ShipOverlap);
-                    mask |= bit;
-                }
+            let bit = cell_bit(cell.x, cell.y, grid_size);
+            require!((occupied & bit) == 0, CayedError::InvalidTrapPlacement);
+            occupied |= bit;
+            if is_mine {
+                mines_mask |= bit;
+            } else {
+                decoys_mask |= bit;
             }
-            all_ships_mask |= mask;
-            ship_masks.push(mask);
         }
 
         self.player_board.ship_coordinates = ships;
         self.player_board.ship_masks = ship_masks;
         self.player_board.all_ships_mask = all_ships_mask;
+        self.player_board.mines_mask = mines_mask;
+        self.player_board.decoys_mask = decoys_mask;
+        self.game.ships_hidden(is_p1, Clock::get()?.unix_timestamp);
 
         Ok(())
     }
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::CayedError;
-use crate::state::{config::Config, Vault};
+use crate::state::{config::Config, PlayerGames, Vault};
 
 #[derive(Accounts)]
 pub struct InitConfig<'info> {
@@ -34,6 +34,7 @@ impl<'info> InitConfig<'info> {
         &mut self,
         max_grid_size: u8,
         fee: u16,
+        max_active_games: u8,
         bumps: InitConfigBumps,
     ) -> Result<()> {
         // If already initialized, only the existing authority can reconfigure
@@ -49,6 +50,11 @@ impl<'info> InitConfig<'info> {
             max_grid_size > 0 && max_grid_size % 2 == 0,
             CayedError::GridNotEven
         );
+        require!(fee <= 10_000, CayedError::InvalidFee);
+        require!(
+            max_active_games > 0 && max_active_games <= PlayerGames::MAX_GAMES,
+            CayedError::InvalidActiveGamesCap
+        );
 
         self.vault.set_inner(Vault {
             authority: self.authority.key(),
@@ -59,6 +65,14 @@ impl<'info> InitConfig<'info> {
             vault: self.vault.key(),
             max_grid_size,
             fee,
+            // Reconfiguring must never hand out ids again
+            game_counter: self.config.game_counter,
+            map_counter: self.config.map_counter,
+            match_counter: self.config.match_counter,
+            tournament_counter: self.config.tournament_counter,
+            open_games_pages: self.config.open_games_pages,
+            max_active_games,
+            season: self.config.season,
             bump: bumps.config,
         });
 
@@ -2,7 +2,9 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
 
 use crate::errors::CayedError;
-use crate::state::{Game, PlayerBoard, Vault};
+use crate::state::{
+    Config, Game, GameStatus, OpenGames, PlayerBoard, PlayerGames, Vault, COIN_FLIP_REVEAL_SECS,
+};
 
 #[derive(Accounts)]
 pub struct JoinGame<'info> {
@@ -23,7 +25,26 @@ pub struct JoinGame<'info> {
         bump,
     )]
     pub player_board: Account<'info, PlayerBoard>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", player.key().as_ref()],
+        bump,
+    )]
+    pub player_games: Account<'info, PlayerGames>,
+    #[account(
+        mut,
+        seeds = [b"open_games", open_games.page.to_le_bytes().as_ref()],
+        bump = open_games.bump,
+    )]
+    pub open_games: Account<'info, OpenGames>,
 
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
     #[account(
         mut,
         seeds = [b"vault"],
@@ -35,7 +56,12 @@ pub struct JoinGame<'info> {
 }
 
 impl<'info> JoinGame<'info> {
-    pub fn join_game(&mut self, bumps: JoinGameBumps) -> Result<()> {
+    pub fn join_game(
+        &mut self,
+        first_move_commitment: Option<[u8; 32]>,
+        invite_code: Option<Vec<u8>>,
+        bumps: JoinGameBumps,
+    ) -> Result<()> {
         let wager = self.game.wager;
         let player_1 = self.game.player_1;
         require!(
@@ -43,24 +69,44 @@ impl<'info> JoinGame<'info> {
             CayedError::CannotJoinSelfGame
         );
         require!(self.game.player_2.is_none(), CayedError::GameFull);
+        require!(
+            self.game.status == GameStatus::AwaitingPlayerTwo,
+            CayedError::InvalidGameStatus
+        );
+        if let Some(invite) = &self.game.invite {
+            require!(
+                invite.admits(&self.player.key(), invite_code.as_deref()),
+                CayedError::NotInvited
+            );
+        }
+
+        match (&mut self.game.coin_flip, first_move_commitment) {
+            (Some(coin_flip), Some(commitment)) => {
+                coin_flip.commitment_player_2 = commitment;
+                coin_flip.reveal_deadline = Clock::get()?.unix_timestamp + COIN_FLIP_REVEAL_SECS;
+            }
+            (None, None) => {}
+            _ => return err!(CayedError::CoinFlipMismatch),
+        }
 
         if wager > 0 {
             self.deposit(wager)?;
         }
 
         self.game.player_2 = Some(self.player.key());
-        self.game.status = crate::state::GameStatus::HidingShips;
+        self.game.status = GameStatus::HidingShips;
+        self.open_games.unlist(self.game.id)?;
+
+        self.player_board.set_inner(PlayerBoard::new(
+            self.game.id,
+            self.player.key(),
+            bumps.player_board,
+        ));
 
-        self.player_board.set_inner(PlayerBoard {
-            game_id: self.game.id,
-            player: self.player.key(),
-            bump: bumps.player_board,
-            ship_coordinates: vec![],
-            ship_masks: vec![],
-            all_ships_mask: 0,
-            hits_bitmap: 0,
-            sunk_mask: 0,
-        });
+        self.player_games.player = self.player.key();
+        self.player_games.bump = bumps.player_games;
+        self.player_games
+            .track(self.game.id, self.config.max_active_games)?;
 
         Ok(())
     }
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::CayedError;
+use crate::state::{Config, Match, MatchStatus, PlayerGames, Vault};
+
+#[derive(Accounts)]
+pub struct JoinMatch<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"match", game_match.id.to_le_bytes().as_ref()],
+        bump = game_match.bump,
+    )]
+    pub game_match: Account<'info, Match>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", player.key().as_ref()],
+        bump,
+    )]
+    pub player_games: Account<'info, PlayerGames>,
+
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> JoinMatch<'info> {
+    pub fn join_match(&mut self, bumps: JoinMatchBumps) -> Result<()> {
+        require!(
+            self.game_match.player_1 != self.player.key(),
+            CayedError::CannotJoinSelfGame
+        );
+        require!(self.game_match.player_2.is_none(), CayedError::GameFull);
+        require!(
+            self.game_match.status == MatchStatus::AwaitingPlayerTwo,
+            CayedError::InvalidGameStatus
+        );
+        self.player_games.player = self.player.key();
+        self.player_games.bump = bumps.player_games;
+        self.player_games.check_room(self.config.max_active_games)?;
+
+        let wager = self.game_match.wager;
+        if wager > 0 {
+            self.deposit(wager)?;
+        }
+
+        self.game_match.player_2 = Some(self.player.key());
+        self.game_match.status = MatchStatus::InProgress;
+
+        Ok(())
+    }
+
+    pub fn deposit(&mut self, wager: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, wager)
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::CayedError;
+use crate::state::{GameStatus, MultiGame, PlayerBoard, Vault};
+
+#[derive(Accounts)]
+pub struct JoinMultiGame<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"multi_game", multi_game.id.to_le_bytes().as_ref()],
+        bump = multi_game.bump,
+    )]
+    pub multi_game: Account<'info, MultiGame>,
+    #[account(
+        init,
+        payer = player,
+        space = 8 + PlayerBoard::INIT_SPACE,
+        seeds = [b"multi_player", multi_game.id.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub player_board: Account<'info, PlayerBoard>,
+
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> JoinMultiGame<'info> {
+    pub fn join_multi_game(&mut self, bumps: JoinMultiGameBumps) -> Result<()> {
+        let player = self.player.key();
+        require!(
+            self.multi_game.seat_of(&player).is_none(),
+            CayedError::CannotJoinSelfGame
+        );
+        require!(
+            self.multi_game.players.len() < self.multi_game.max_players as usize,
+            CayedError::GameFull
+        );
+        require!(
+            self.multi_game.status == GameStatus::AwaitingPlayerTwo,
+            CayedError::InvalidGameStatus
+        );
+
+        let wager = self.multi_game.wager;
+        if wager > 0 {
+            self.deposit(wager)?;
+        }
+
+        self.multi_game.players.push(player);
+        if self.multi_game.players.len() == self.multi_game.max_players as usize {
+            // The opening seat is drawn by `request_multi_first_move`
+            self.multi_game.status = GameStatus::HidingShips;
+        }
+
+        self.player_board.set_inner(PlayerBoard::new(
+            self.multi_game.id,
+            player,
+            bumps.player_board,
+        ));
+
+        Ok(())
+    }
+
+    pub fn deposit(&mut self, wager: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, wager)
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Queue, Vault};
+
+#[derive(Accounts)]
+pub struct LeaveQueue<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"queue", queue.grid_size.to_le_bytes().as_ref(), queue.bucket.to_le_bytes().as_ref()],
+        bump = queue.bump,
+    )]
+    pub queue: Account<'info, Queue>,
+
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+impl<'info> LeaveQueue<'info> {
+    pub fn leave_queue(&mut self) -> Result<()> {
+        let player = self.player.key();
+        let position = self
+            .queue
+            .entries
+            .iter()
+            .position(|entry| *entry == player)
+            .ok_or(CayedError::NotQueued)?;
+        // Keep the order for everyone still waiting
+        self.queue.entries.remove(position);
+
+        // Refund the stake taken on entry
+        let wager = Queue::wager(self.queue.bucket)?;
+        if wager > 0 {
+            Vault::pay_out(&self.vault, &self.player.to_account_info(), wager)?;
+        }
+
+        Ok(())
+    }
+}
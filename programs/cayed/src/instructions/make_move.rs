@@ -1,9 +1,11 @@
+use std::cmp::Ordering;
+
 use anchor_lang::prelude::*;
 use ephemeral_rollups_sdk::{anchor::commit, ephem::commit_accounts};
 
 use crate::{
     errors::CayedError,
-    state::{cell_bit, Game, GameStatus, PlayerBoard},
+    state::{cell_bit, Game, GameStatus, MoveOutcome, MoveResult, PlayerBoard, ShotResult},
 };
 
 #[commit]
@@ -41,6 +43,32 @@ pub struct MakeMove<'info> {
 
 impl<'info> MakeMove<'info> {
     pub fn make_move(&mut self, x: u8, y: u8) -> Result<()> {
+        let is_player1_turn = self.start_turn()?;
+
+        // Grid bounds validation - each player's board is grid_size x (grid_size / 2)
+        let grid_size = self.game.grid_size;
+        let half = grid_size / 2;
+        require!(x < grid_size && y < half, CayedError::AttackOutOfBounds);
+
+        // Blocked and duplicate checks via bitmap
+        let bit = cell_bit(x, y, grid_size);
+        require!(
+            (self.game.blocked_mask & bit) == 0,
+            CayedError::AttackOnBlockedCell
+        );
+        require!(
+            (self.opponent_board.hits_bitmap & bit) == 0,
+            CayedError::CellAlreadyAttacked
+        );
+
+        let result = self.strike(bit, is_player1_turn);
+        self.game.spend_shot(is_player1_turn);
+
+        self.end_turn(is_player1_turn, x, y, MoveOutcome::Shot { result })
+    }
+
+    /// Shared validation for every turn-consuming action. Returns whether it is player 1's turn.
+    pub(crate) fn start_turn(&mut self) -> Result<bool> {
         // Validate both players have placed ships
         require!(
             !self.player_board.ship_coordinates.is_empty()
@@ -52,15 +80,28 @@ impl<'info> MakeMove<'info> {
             CayedError::AllShipsSunk
         );
 
+        require!(
+            self.game.first_move_decided,
+            CayedError::FirstMoveNotDecided
+        );
+
+        // A flagged player can only lose on time, not keep playing
+        if let Some(left) = self.game.time_left(Clock::get()?.unix_timestamp) {
+            require!(left > 0, CayedError::TimeBankExpired);
+        }
+
+        require!(
+            !self.game.out_of_ammo(self.game.next_move_player_1),
+            CayedError::NoAmmoLeft
+        );
+
         // Transition from HidingShips → InProgress on first valid move
         if matches!(self.game.status, GameStatus::HidingShips) {
             self.game.status = GameStatus::InProgress;
         }
 
-        // Turn validation via hit count
-        let total_moves = (self.player_board.hits_bitmap.count_ones()
-            + self.opponent_board.hits_bitmap.count_ones()) as usize;
-        let is_player1_turn = (total_moves % 2 == 0) == self.game.next_move_player_1;
+        // Turn validation
+        let is_player1_turn = self.game.next_move_player_1;
 
         let player_key = self.player.key();
         let p2_key = self.game.player_2.unwrap();
@@ -77,62 +118,80 @@ impl<'info> MakeMove<'info> {
             || (player_key == p2_key && opponent_key == self.game.player_1);
         require!(valid_opponent, CayedError::InvalidOpponent);
 
-        // Grid bounds validation - each player's board is grid_size x (grid_size / 2)
-        let grid_size = self.game.grid_size;
-        let half = grid_size / 2;
-        require!(x < grid_size && y < half, CayedError::AttackOutOfBounds);
-
-        // Duplicate check via bitmap
-        let bit = cell_bit(x, y, grid_size);
-        require!(
-            (self.opponent_board.hits_bitmap & bit) == 0,
-            CayedError::CellAlreadyAttacked
-        );
+        Ok(is_player1_turn)
+    }
 
-        // Record the attack
+    /// Record an attack on an unattacked opponent cell and apply any mine penalty.
+    pub(crate) fn strike(&mut self, bit: u64, is_player1_turn: bool) -> ShotResult {
         self.opponent_board.hits_bitmap |= bit;
 
-        // Was this a hit? (did the attacked cell have a ship?)
-        let is_hit = (self.opponent_board.all_ships_mask & bit) != 0;
+        // Ship, decoy, mine or open water
+        let result = self.opponent_board.shot_result(bit);
+        if result == ShotResult::Mine {
+            if is_player1_turn {
+                self.game.skip_turn_player_1 = true;
+            } else {
+                self.game.skip_turn_player_2 = true;
+            }
+        }
+        result
+    }
 
+    /// Log the move, reveal newly sunk ships, check for completion and hand the turn over.
+    pub(crate) fn end_turn(
+        &mut self,
+        is_player1_turn: bool,
+        x: u8,
+        y: u8,
+        outcome: MoveOutcome,
+    ) -> Result<()> {
         // O(n_ships) sunk detection using pre-computed masks
-        // Clone masks locally to release the immutable borrow on opponent_board.
-        let ship_masks: Vec<u64> = self.opponent_board.ship_masks.clone();
-        let mut any_newly_sunk = false;
-        for (i, ship_mask) in ship_masks.iter().enumerate() {
-            if *ship_mask == 0 {
-                continue;
-            }
-            // Skip already-sunk ships
-            if (self.opponent_board.sunk_mask >> i) & 1 == 1 {
-                continue;
-            }
-            // Check if this ship is now fully sunk
-            if (self.opponent_board.hits_bitmap & ship_mask) == *ship_mask {
-                self.opponent_board.sunk_mask |= 1u8 << i;
-                let ship = self.opponent_board.ship_coordinates[i].clone();
-                if is_player1_turn {
-                    self.game.revealed_ships_player_1.push(ship);
-                } else {
-                    self.game.revealed_ships_player_2.push(ship);
-                }
-                any_newly_sunk = true;
+        let newly_sunk = self.opponent_board.mark_sunk_ships();
+
+        // Record move result on the public Game account so clients can poll it.
+        // A move strikes at most one cell, so it sinks at most one ship.
+        let clock = Clock::get()?;
+        self.game.moves.push(MoveResult {
+            shooter: self.player.key(),
+            x,
+            y,
+            outcome,
+            sunk_ship: newly_sunk.first().copied(),
+            slot: clock.slot,
+            unix_timestamp: clock.unix_timestamp,
+        });
+
+        for &i in &newly_sunk {
+            let ship = self.opponent_board.ship_coordinates[i as usize].clone();
+            if is_player1_turn {
+                self.game.revealed_ships_player_1.push(ship);
+            } else {
+                self.game.revealed_ships_player_2.push(ship);
             }
         }
 
-        // Record move result on the public Game account so clients can poll it
-        self.game
-            .moves
-            .push(crate::state::MoveResult { x, y, is_hit });
-
-        if any_newly_sunk {
-            // Game completion check
-            if self.opponent_board.all_ships_sunk() {
-                self.game.status = GameStatus::Completed {
-                    winner: self.player.key(),
-                };
-            }
+        self.game.end_turn(is_player1_turn, clock.unix_timestamp);
+
+        // Game completion check
+        if !newly_sunk.is_empty() && self.opponent_board.all_ships_sunk() {
+            self.game.status = GameStatus::Completed {
+                winner: self.player.key(),
+            };
+        } else if self.game.ammo_spent() {
+            // Most hits wins, then most ships sunk
+            let winner = match self
+                .opponent_board
+                .damage()
+                .cmp(&self.player_board.damage())
+            {
+                Ordering::Greater => Some(self.player.key()),
+                Ordering::Less => Some(self.opponent.key()),
+                Ordering::Equal => None,
+            };
+            self.game.status = GameStatus::OutOfAmmo { winner };
+        }
 
+        if !newly_sunk.is_empty() || !matches!(self.game.status, GameStatus::InProgress) {
             self.game.exit(&crate::ID)?;
 
             commit_accounts(
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CayedError,
+    instructions::MakeMove,
+    state::{Heading, MoveOutcome},
+};
+
+// Maneuvers reuse the `MakeMove` accounts: the mover's own board is already writable.
+impl<'info> MakeMove<'info> {
+    pub fn maneuver_ship(&mut self, ship_index: u8, heading: Heading) -> Result<()> {
+        require!(self.game.settings.maneuvers, CayedError::ManeuversDisabled);
+        let is_player1_turn = self.start_turn()?;
+
+        let charges = self.charges(is_player1_turn);
+        require!(charges.maneuver > 0, CayedError::NoManeuversLeft);
+        charges.maneuver -= 1;
+
+        let grid_size = self.game.grid_size;
+        let blocked_mask = self.game.blocked_mask;
+        self.player_board
+            .move_ship(ship_index, heading, grid_size, blocked_mask)?;
+
+        self.end_turn(is_player1_turn, 0, 0, MoveOutcome::Maneuver)
+    }
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::events::{GameCreated, GameKind};
+use crate::state::{Config, Game, GameSettings, GameStatus, PlayerBoard, PlayerGames, Queue};
+
+/// Pairs the two longest-waiting players in a queue into a new game. Anyone can call it;
+/// the caller pays rent for the new accounts.
+#[derive(Accounts)]
+pub struct MatchPlayers<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"queue", queue.grid_size.to_le_bytes().as_ref(), queue.bucket.to_le_bytes().as_ref()],
+        bump = queue.bump,
+        constraint = queue.entries.len() >= 2 @ CayedError::NotEnoughPlayers,
+    )]
+    pub queue: Account<'info, Queue>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Game::INIT_SPACE,
+        seeds = [b"game", config.game_counter.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PlayerBoard::INIT_SPACE,
+        seeds = [b"player", config.game_counter.to_le_bytes().as_ref(), queue.waiting(0).as_ref()],
+        bump,
+    )]
+    pub player1_board: Account<'info, PlayerBoard>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PlayerBoard::INIT_SPACE,
+        seeds = [b"player", config.game_counter.to_le_bytes().as_ref(), queue.waiting(1).as_ref()],
+        bump,
+    )]
+    pub player2_board: Account<'info, PlayerBoard>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", queue.waiting(0).as_ref()],
+        bump,
+    )]
+    pub player1_games: Account<'info, PlayerGames>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", queue.waiting(1).as_ref()],
+        bump,
+    )]
+    pub player2_games: Account<'info, PlayerGames>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MatchPlayers<'info> {
+    pub fn match_players(&mut self, bumps: MatchPlayersBumps) -> Result<u64> {
+        let player_1 = self.queue.entries.remove(0);
+        let player_2 = self.queue.entries.remove(0);
+
+        // Both stakes were taken when the players queued
+        let id = self.config.next_game_id()?;
+        let mut game = Game::new(
+            id,
+            self.queue.grid_size,
+            player_1,
+            Queue::wager(self.queue.bucket)?,
+            GameSettings::default(),
+            bumps.game,
+        );
+        game.player_2 = Some(player_2);
+        game.status = GameStatus::HidingShips;
+        self.game.set_inner(game);
+
+        self.player1_board
+            .set_inner(PlayerBoard::new(id, player_1, bumps.player1_board));
+        self.player2_board
+            .set_inner(PlayerBoard::new(id, player_2, bumps.player2_board));
+
+        self.player1_games.player = player_1;
+        self.player1_games.bump = bumps.player1_games;
+        self.player1_games.track_paired(id);
+        self.player2_games.player = player_2;
+        self.player2_games.bump = bumps.player2_games;
+        self.player2_games.track_paired(id);
+
+        emit!(GameCreated {
+            id,
+            creator: player_1,
+            kind: GameKind::Single,
+        });
+
+        Ok(id)
+    }
+}
@@ -1,17 +1,107 @@
+pub mod accept_challenge;
+pub mod accept_rematch;
+pub mod auto_place_ships;
+pub mod callback_auto_place_ships;
+pub mod callback_first_move;
+pub mod callback_multi_first_move;
+pub mod cancel_game;
+pub mod cancel_match;
+pub mod cancel_multi_game;
+pub mod cancel_tournament;
+pub mod claim_multi_pot;
+pub mod claim_season_prize;
+pub mod claim_timeout;
+pub mod claim_tournament_prize;
+pub mod close_season;
 pub mod create_game;
+pub mod create_map;
+pub mod create_match;
+pub mod create_multi_game;
 pub mod create_permission;
+pub mod create_season;
+pub mod create_tournament;
 pub mod delegate_pda;
+pub mod enqueue;
+pub mod fund_escrow;
+pub mod hide_multi_ships;
 pub mod hide_ships;
 pub mod init_config;
 pub mod join_game;
+pub mod join_match;
+pub mod join_multi_game;
+pub mod leave_queue;
 pub mod make_move;
+pub mod maneuvers;
+pub mod match_players;
+pub mod multi_make_move;
+pub mod offer_rematch;
+pub mod recall_tournament_game;
+pub mod record_match_game;
+pub mod record_tournament_game;
+pub mod register_tournament;
+pub mod request_first_move;
+pub mod request_multi_first_move;
+pub mod reveal_coin_flip;
+pub mod reveal_multi_winner;
 pub mod reveal_winner;
+pub mod settle_game;
+pub mod share_board_with_team;
+pub mod special_weapons;
+pub mod start_match_game;
+pub mod start_tournament;
+pub mod start_tournament_game;
+pub mod sweep_tournament;
+pub mod withdraw_escrow;
 
+pub use accept_challenge::*;
+pub use accept_rematch::*;
+pub use auto_place_ships::*;
+pub use callback_auto_place_ships::*;
+pub use callback_first_move::*;
+pub use callback_multi_first_move::*;
+pub use cancel_game::*;
+pub use cancel_match::*;
+pub use cancel_multi_game::*;
+pub use cancel_tournament::*;
+pub use claim_multi_pot::*;
+pub use claim_season_prize::*;
+pub use claim_timeout::*;
+pub use claim_tournament_prize::*;
+pub use close_season::*;
 pub use create_game::*;
+pub use create_map::*;
+pub use create_match::*;
+pub use create_multi_game::*;
 pub use create_permission::*;
+pub use create_season::*;
+pub use create_tournament::*;
 pub use delegate_pda::*;
+pub use enqueue::*;
+pub use fund_escrow::*;
+pub use hide_multi_ships::*;
 pub use hide_ships::*;
 pub use init_config::*;
 pub use join_game::*;
+pub use join_match::*;
+pub use join_multi_game::*;
+pub use leave_queue::*;
 pub use make_move::*;
+pub use match_players::*;
+pub use multi_make_move::*;
+pub use offer_rematch::*;
+pub use recall_tournament_game::*;
+pub use record_match_game::*;
+pub use record_tournament_game::*;
+pub use register_tournament::*;
+pub use request_first_move::*;
+pub use request_multi_first_move::*;
+pub use reveal_coin_flip::*;
+pub use reveal_multi_winner::*;
 pub use reveal_winner::*;
+pub use settle_game::*;
+pub use share_board_with_team::*;
+pub use start_match_game::*;
+pub use start_tournament::*;
+pub use start_tournament_game::*;
+pub use sweep_tournament::*;
+pub use withdraw_escrow::*;
@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::{anchor::commit, ephem::commit_accounts};
+
+use crate::{
+    errors::CayedError,
+    state::{cell_bit, GameStatus, MultiGame, MultiMoveResult, PlayerBoard, RevealedShip},
+};
+
+#[commit]
+#[derive(Accounts)]
+pub struct MultiMakeMove<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// CHECK: We verify this is a seated, afloat opponent via the multi game account
+    pub target: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"multi_game", multi_game.id.to_le_bytes().as_ref()],
+        bump = multi_game.bump,
+        constraint = matches!(
+            multi_game.status,
+            GameStatus::HidingShips | GameStatus::InProgress
+        ) @ CayedError::InvalidGameStatus,
+    )]
+    pub multi_game: Account<'info, MultiGame>,
+    #[account(
+        mut,
+        seeds = [b"multi_player", multi_game.id.to_le_bytes().as_ref(), target.key().as_ref()],
+        bump,
+    )]
+    pub target_board: Account<'info, PlayerBoard>,
+}
+
+impl<'info> MultiMakeMove<'info> {
+    pub fn multi_make_move(&mut self, x: u8, y: u8) -> Result<()> {
+        // Every seat must have hidden a fleet before the first shot
+        let all_seats = (1u8 << self.multi_game.players.len()) - 1;
+        require!(
+            self.multi_game.ships_placed_mask == all_seats,
+            CayedError::ShipsNotPlaced
+        );
+        require!(
+            self.multi_game.first_move_decided,
+            CayedError::FirstMoveNotDecided
+        );
+
+        // Transition from HidingShips → InProgress on first valid move
+        if matches!(self.multi_game.status, GameStatus::HidingShips) {
+            self.multi_game.status = GameStatus::InProgress;
+        }
+
+        // Turn validation - eliminated seats are skipped by `advance_turn`
+        let shooter = self
+            .multi_game
+            .seat_of(&self.player.key())
+            .ok_or(CayedError::InvalidTurn)?;
+        require!(shooter == self.multi_game.turn, CayedError::InvalidTurn);
+
+        // Target validation
+        let target = self
+            .multi_game
+            .seat_of(&self.target.key())
+            .ok_or(CayedError::InvalidOpponent)?;
+        require!(
+            target != shooter && !self.multi_game.is_eliminated(target),
+            CayedError::InvalidOpponent
+        );
+        // Teammates share an ocean and never fire on each other
+        require!(
+            !self.multi_game.teams || MultiGame::team_of(target) != MultiGame::team_of(shooter),
+            CayedError::InvalidOpponent
+        );
+
+        let grid_size = self.multi_game.grid_size;
+        require!(
+            x < grid_size && y < grid_size / 2,
+            CayedError::AttackOutOfBounds
+        );
+
+        let bit = cell_bit(x, y, grid_size);
+        require!(
+            (self.target_board.hits_bitmap & bit) == 0,
+            CayedError::CellAlreadyAttacked
+        );
+        self.target_board.hits_bitmap |= bit;
+        let is_hit = (self.target_board.all_ships_mask & bit) != 0;
+
+        self.multi_game.moves.push(MultiMoveResult {
+            shooter,
+            target,
+            x,
+            y,
+            is_hit,
+        });
+
+        let newly_sunk = self.target_board.mark_sunk_ships();
+        for &i in &newly_sunk {
+            let ship = self.target_board.ship_coordinates[i as usize].clone();
+            self.multi_game
+                .revealed_ships
+                .push(RevealedShip { seat: target, ship });
+        }
+
+        if self.target_board.all_ships_sunk() {
+            self.multi_game.eliminated_mask |= 1 << target;
+            // Last fleet (or team) afloat wins
+            if self.multi_game.is_decided() {
+                self.multi_game.status = GameStatus::Completed {
+                    winner: self.player.key(),
+                };
+            }
+        }
+
+        self.multi_game.advance_turn();
+
+        if !newly_sunk.is_empty() {
+            self.multi_game.exit(&crate::ID)?;
+
+            commit_accounts(
+                &self.player,
+                vec![&self.multi_game.to_account_info()],
+                &self.magic_context,
+                &self.magic_program,
+            )?;
+        }
+
+        Ok(())
+    }
+}
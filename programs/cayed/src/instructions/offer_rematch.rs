@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::CayedError;
+use crate::state::{Config, Game, GameStatus, RematchOffer, Vault};
+
+#[derive(Accounts)]
+pub struct OfferRematch<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump = game.bump,
+        constraint = game.match_id.is_none() @ CayedError::InvalidGameStatus,
+        constraint = game.tournament_id.is_none() @ CayedError::InvalidGameStatus,
+        constraint = matches!(
+            game.status,
+            GameStatus::WinnerRevealed { .. } | GameStatus::Drawn
+        ) @ CayedError::InvalidGameStatus,
+        constraint = !game.settled @ CayedError::GameAlreadySettled,
+    )]
+    pub game: Account<'info, Game>,
+
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OfferRematch<'info> {
+    pub fn offer_rematch(&mut self, double_or_nothing: bool) -> Result<()> {
+        let player = self.player.key();
+        require!(
+            player == self.game.player_1 || Some(player) == self.game.player_2,
+            CayedError::Unauthorized
+        );
+        require!(
+            self.game.rematch.is_none(),
+            CayedError::RematchAlreadyOffered
+        );
+        let winner = self.game.winner();
+        require!(
+            !double_or_nothing || winner.is_some(),
+            CayedError::RematchNeedsWinner
+        );
+
+        // A winner going double or nothing already has their stake in the vault
+        let stake = self.game.rematch_wager(double_or_nothing, &self.config)?;
+        let deposit = if double_or_nothing && winner == Some(player) {
+            0
+        } else {
+            stake
+        };
+        if deposit > 0 {
+            self.deposit(deposit)?;
+        }
+
+        self.game.rematch = Some(RematchOffer {
+            from: player,
+            double_or_nothing,
+            deposit,
+        });
+
+        Ok(())
+    }
+
+    pub fn deposit(&mut self, wager: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, wager)
+    }
+}
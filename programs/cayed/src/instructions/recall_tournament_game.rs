@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+
+use crate::errors::CayedError;
+use crate::state::{Game, GameStatus, Tournament};
+
+/// Brings a bracket game nobody started playing back from the ER once its pairing is
+/// overdue, so `record_tournament_game` can decide it. Anyone can call it.
+#[commit]
+#[derive(Accounts)]
+pub struct RecallTournamentGame<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump,
+        constraint = game.tournament_id == Some(tournament.id) @ CayedError::NotTournamentGame,
+        constraint = game.status == GameStatus::HidingShips @ CayedError::InvalidGameStatus,
+    )]
+    pub game: Account<'info, Game>,
+}
+
+impl<'info> RecallTournamentGame<'info> {
+    pub fn recall_tournament_game(&mut self) -> Result<()> {
+        let pair = self.tournament.pairing_of(self.game.id)?;
+        require!(
+            Clock::get()?.unix_timestamp > self.tournament.pairings[pair].deadline,
+            CayedError::PairingNotOverdue
+        );
+        // A game under way stays on the ER to be played out or won on time
+        require!(
+            self.tournament.no_show_winner(pair, &self.game).is_some(),
+            CayedError::PairingUnderway
+        );
+
+        self.game.exit(&crate::ID)?;
+        commit_and_undelegate_accounts(
+            &self.payer,
+            vec![&self.game.to_account_info()],
+            &self.magic_context,
+            &self.magic_program,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{
+    Config, Game, GameStatus, Match, MatchStatus, PlayerGames, PlayerProfile, Season, Vault,
+};
+
+/// Settles a finished series game into both profiles and counts it towards the series,
+/// paying out the series wager once a player has won enough games. Anyone can call it.
+#[derive(Accounts)]
+pub struct RecordMatchGame<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"match", game_match.id.to_le_bytes().as_ref()],
+        bump = game_match.bump,
+        constraint = matches!(game_match.status, MatchStatus::InProgress) @ CayedError::InvalidGameStatus,
+    )]
+    pub game_match: Account<'info, Match>,
+
+    #[account(
+        mut,
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump,
+        constraint = game.match_id == Some(game_match.id) @ CayedError::NotCurrentMatchGame,
+        constraint = !game.settled @ CayedError::GameAlreadySettled,
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: Receives the series payout if player 1 wins it
+    #[account(mut, address = game_match.player_1)]
+    pub player_1: UncheckedAccount<'info>,
+    /// CHECK: Receives the series payout if player 2 wins it
+    #[account(mut, address = game_match.player_2.unwrap())]
+    pub player_2: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"player_games", player_1.key().as_ref()],
+        bump = player1_games.bump,
+    )]
+    pub player1_games: Account<'info, PlayerGames>,
+    #[account(
+        mut,
+        seeds = [b"player_games", player_2.key().as_ref()],
+        bump = player2_games.bump,
+    )]
+    pub player2_games: Account<'info, PlayerGames>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"profile", player_1.key().as_ref()],
+        bump,
+    )]
+    pub player1_profile: Account<'info, PlayerProfile>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"profile", player_2.key().as_ref()],
+        bump,
+    )]
+    pub player2_profile: Account<'info, PlayerProfile>,
+    /// Required while `Config.season` is set
+    #[account(
+        mut,
+        seeds = [b"season", season.id.to_le_bytes().as_ref()],
+        bump = season.bump,
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RecordMatchGame<'info> {
+    pub fn record_match_game(&mut self, bumps: RecordMatchGameBumps) -> Result<()> {
+        require!(
+            self.game_match.game_in_progress()
+                && self.game_match.games.last() == Some(&self.game.id),
+            CayedError::NotCurrentMatchGame
+        );
+        self.player1_games.untrack(self.game.id);
+        self.player2_games.untrack(self.game.id);
+
+        // Series games carry no stake of their own, so only the play is recorded here
+        self.player1_profile
+            .open(self.player_1.key(), bumps.player1_profile);
+        self.player2_profile
+            .open(self.player_2.key(), bumps.player2_profile);
+        self.game.settle(
+            &self.config,
+            &mut self.player1_profile,
+            &mut self.player2_profile,
+            self.season.as_deref_mut(),
+        )?;
+
+        let winner = match self.game.status {
+            GameStatus::WinnerRevealed { winner } => winner,
+            // A drawn game doesn't count; the next one replays it
+            GameStatus::Drawn => {
+                self.game_match.games.pop();
+                return Ok(());
+            }
+            _ => return err!(CayedError::InvalidGameStatus),
+        };
+
+        self.game_match.winners.push(winner);
+        let wins = if winner == self.game_match.player_1 {
+            self.game_match.wins_player_1 += 1;
+            self.game_match.wins_player_1
+        } else {
+            self.game_match.wins_player_2 += 1;
+            self.game_match.wins_player_2
+        };
+
+        if wins < self.game_match.wins_required {
+            return Ok(());
+        }
+
+        // Series decided - settle the pot once, minus the protocol fee kept in the vault
+        self.game_match.status = MatchStatus::Completed { winner };
+        let pot = self
+            .game_match
+            .wager
+            .checked_mul(2)
+            .ok_or(CayedError::Overflow)?;
+        let payout = pot
+            .checked_sub(self.config.fee_on(pot))
+            .ok_or(CayedError::Overflow)?;
+        if payout > 0 {
+            let to = if winner == self.game_match.player_1 {
+                self.player_1.to_account_info()
+            } else {
+                self.player_2.to_account_info()
+            };
+            Vault::pay_out(&self.vault, &to, payout)?;
+        }
+
+        Ok(())
+    }
+}
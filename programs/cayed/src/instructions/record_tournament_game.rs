@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{
+    Config, Game, GameStatus, PlayerGames, PlayerProfile, Season, Tournament, TournamentStatus,
+};
+
+/// Settles a finished bracket game into both profiles and moves its winner on. This is
+/// the only way a bracket game is settled. Anyone can call it, and once the pairing's
+/// deadline has passed it also decides a game nobody started playing.
+#[derive(Accounts)]
+pub struct RecordTournamentGame<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::InProgress @ CayedError::TournamentNotInProgress,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump,
+        constraint = game.tournament_id == Some(tournament.id) @ CayedError::NotTournamentGame,
+        constraint = !game.settled @ CayedError::GameAlreadySettled,
+    )]
+    pub game: Account<'info, Game>,
+
+    #[account(
+        mut,
+        seeds = [b"player_games", game.player_1.as_ref()],
+        bump = player1_games.bump,
+    )]
+    pub player1_games: Account<'info, PlayerGames>,
+    #[account(
+        mut,
+        seeds = [b"player_games", game.player_2.unwrap_or_default().as_ref()],
+        bump = player2_games.bump,
+    )]
+    pub player2_games: Account<'info, PlayerGames>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"profile", game.player_1.as_ref()],
+        bump,
+    )]
+    pub player1_profile: Account<'info, PlayerProfile>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"profile", game.player_2.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub player2_profile: Account<'info, PlayerProfile>,
+    /// Required while `Config.season` is set
+    #[account(
+        mut,
+        seeds = [b"season", season.id.to_le_bytes().as_ref()],
+        bump = season.bump,
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RecordTournamentGame<'info> {
+    pub fn record_tournament_game(&mut self, bumps: RecordTournamentGameBumps) -> Result<()> {
+        let pair = self.tournament.pairing_of(self.game.id)?;
+        let now = Clock::get()?.unix_timestamp;
+
+        if self.game.status == GameStatus::HidingShips {
+            require!(
+                now > self.tournament.pairings[pair].deadline,
+                CayedError::PairingNotOverdue
+            );
+            let winner = self
+                .tournament
+                .no_show_winner(pair, &self.game)
+                .ok_or(CayedError::PairingUnderway)?;
+            self.game.status = GameStatus::WinnerRevealed { winner };
+            self.game.forfeit = true;
+        }
+
+        self.player1_games.untrack(self.game.id);
+        self.player2_games.untrack(self.game.id);
+
+        self.player1_profile
+            .open(self.game.player_1, bumps.player1_profile);
+        self.player2_profile.open(
+            self.game.player_2.unwrap_or_default(),
+            bumps.player2_profile,
+        );
+        self.game.settle(
+            &self.config,
+            &mut self.player1_profile,
+            &mut self.player2_profile,
+            self.season.as_deref_mut(),
+        )?;
+
+        match self.game.status {
+            GameStatus::WinnerRevealed { winner } => self.tournament.advance(pair, winner, now),
+            // Someone has to go through; a drawn pairing is played again
+            GameStatus::Drawn => {
+                self.tournament.replay(pair, now);
+                Ok(())
+            }
+            _ => err!(CayedError::InvalidGameStatus),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::CayedError;
+use crate::state::{Config, Entrant, PlayerGames, PlayerProfile, Tournament, TournamentStatus};
+
+#[derive(Accounts)]
+pub struct RegisterTournament<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Registration @ CayedError::RegistrationClosed,
+    )]
+    pub tournament: Account<'info, Tournament>,
+    /// Seeds the player by rating; unrated players start from the default
+    #[account(
+        seeds = [b"profile", player.key().as_ref()],
+        bump = profile.bump,
+    )]
+    pub profile: Option<Account<'info, PlayerProfile>>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", player.key().as_ref()],
+        bump,
+    )]
+    pub player_games: Account<'info, PlayerGames>,
+
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterTournament<'info> {
+    pub fn register_tournament(&mut self, bumps: RegisterTournamentBumps) -> Result<()> {
+        let player = self.player.key();
+        require!(
+            self.tournament.entrants.len() < self.tournament.max_players as usize,
+            CayedError::TournamentFull
+        );
+        require!(
+            !self.tournament.entrants.iter().any(|e| e.player == player),
+            CayedError::AlreadyRegistered
+        );
+        self.player_games.player = player;
+        self.player_games.bump = bumps.player_games;
+        self.player_games.check_room(self.config.max_active_games)?;
+
+        let rating = self
+            .profile
+            .as_ref()
+            .map_or(PlayerProfile::START_RATING, |profile| profile.rating);
+        self.tournament.entrants.push(Entrant {
+            player,
+            rating,
+            eliminated_in: None,
+            claimed: false,
+        });
+
+        let entry_fee = self.tournament.entry_fee;
+        if entry_fee > 0 {
+            self.deposit(entry_fee)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn deposit(&mut self, entry_fee: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: self.player.to_account_info(),
+            to: self.tournament.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+
+        transfer(cpi_ctx, entry_fee)
+    }
+}
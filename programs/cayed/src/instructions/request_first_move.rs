@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use anchor_lang::Discriminator;
+use ephemeral_vrf_sdk::consts::{DEFAULT_EPHEMERAL_QUEUE, IDENTITY, VRF_PROGRAM_ID};
+use ephemeral_vrf_sdk::instructions::{create_request_randomness_ix, RequestRandomnessParams};
+use ephemeral_vrf_sdk::types::SerializableAccountMeta;
+
+use crate::errors::CayedError;
+use crate::state::{Game, GameStatus};
+
+/// Asks the MagicBlock VRF for the randomness that decides who moves first.
+/// Runs on the ER once both players are seated; the oracle answers through
+/// `callback_first_move`.
+#[derive(Accounts)]
+pub struct RequestFirstMove<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump,
+        constraint = matches!(game.status, GameStatus::HidingShips) @ CayedError::InvalidGameStatus,
+        constraint = !game.first_move_decided @ CayedError::FirstMoveAlreadyDecided,
+        constraint = game.coin_flip.is_none() @ CayedError::CoinFlipInUse,
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: This program's identity PDA, signs the request
+    #[account(seeds = [IDENTITY], bump)]
+    pub program_identity: UncheckedAccount<'info>,
+    /// CHECK: The oracle queue
+    #[account(mut, address = DEFAULT_EPHEMERAL_QUEUE)]
+    pub oracle_queue: UncheckedAccount<'info>,
+    /// CHECK: VRF PROGRAM
+    #[account(address = VRF_PROGRAM_ID)]
+    pub vrf_program: UncheckedAccount<'info>,
+    /// CHECK: Slot hashes sysvar
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RequestFirstMove<'info> {
+    pub fn request_first_move(
+        &mut self,
+        client_seed: u8,
+        bumps: RequestFirstMoveBumps,
+    ) -> Result<()> {
+        let player = self.player.key();
+        require!(
+            player == self.game.player_1 || Some(player) == self.game.player_2,
+            CayedError::Unauthorized
+        );
+
+        let mut caller_seed = [client_seed; 32];
+        caller_seed[..8].copy_from_slice(&self.game.id.to_le_bytes());
+
+        let ix = create_request_randomness_ix(RequestRandomnessParams {
+            payer: player,
+            oracle_queue: self.oracle_queue.key(),
+            callback_program_id: crate::ID,
+            callback_discriminator: crate::instruction::CallbackFirstMove::DISCRIMINATOR.to_vec(),
+            accounts_metas: Some(vec![SerializableAccountMeta {
+                pubkey: self.game.key(),
+                is_signer: false,
+                is_writable: true,
+            }]),
+            caller_seed,
+            ..Default::default()
+        });
+
+        invoke_signed(
+            &ix,
+            &[
+                self.player.to_account_info(),
+                self.program_identity.to_account_info(),
+                self.oracle_queue.to_account_info(),
+                self.system_program.to_account_info(),
+                self.slot_hashes.to_account_info(),
+                self.vrf_program.to_account_info(),
+            ],
+            &[&[IDENTITY, &[bumps.program_identity]]],
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{CoinFlip, Game, GameStatus};
+
+/// Reveals a player's coin flip secret. The second reveal decides who moves first.
+#[derive(Accounts)]
+pub struct RevealCoinFlip<'info> {
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump,
+        constraint = matches!(game.status, GameStatus::HidingShips) @ CayedError::InvalidGameStatus,
+    )]
+    pub game: Account<'info, Game>,
+}
+
+impl<'info> RevealCoinFlip<'info> {
+    pub fn reveal_coin_flip(&mut self, secret: [u8; 32]) -> Result<()> {
+        let player = self.player.key();
+        let is_p1 = player == self.game.player_1;
+        require!(
+            is_p1 || Some(player) == self.game.player_2,
+            CayedError::Unauthorized
+        );
+
+        let coin_flip = self.game.coin_flip.as_mut().ok_or(CayedError::NoCoinFlip)?;
+        let (commitment, revealed) = if is_p1 {
+            (
+                coin_flip.commitment_player_1,
+                &mut coin_flip.secret_player_1,
+            )
+        } else {
+            (
+                coin_flip.commitment_player_2,
+                &mut coin_flip.secret_player_2,
+            )
+        };
+        require!(revealed.is_none(), CayedError::SecretAlreadyRevealed);
+        require!(
+            CoinFlip::commitment(&secret, &player) == commitment,
+            CayedError::InvalidSecret
+        );
+        *revealed = Some(secret);
+
+        if let Some(player_1_first) = coin_flip.player_1_first() {
+            self.game.next_move_player_1 = player_1_first;
+            self.game.first_move_decided = true;
+            self.game.start_clock(Clock::get()?.unix_timestamp);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::access_control::instructions::UpdatePermissionCpiBuilder;
+use ephemeral_rollups_sdk::access_control::structs::MembersArgs;
+use ephemeral_rollups_sdk::anchor::commit;
+use ephemeral_rollups_sdk::consts::PERMISSION_PROGRAM_ID;
+use ephemeral_rollups_sdk::ephem::commit_and_undelegate_accounts;
+
+use crate::errors::CayedError;
+use crate::state::{GameStatus, MultiGame};
+
+/// Boards and their permission accounts are passed as remaining accounts in seat
+/// order: `[board_0, permission_0, board_1, permission_1, ...]`.
+#[commit]
+#[derive(Accounts)]
+pub struct RevealMultiWinner<'info> {
+    #[account(
+        mut,
+        seeds = [b"multi_game", multi_game.id.to_le_bytes().as_ref()],
+        bump = multi_game.bump,
+        constraint = matches!(multi_game.status, GameStatus::Completed { .. }) @ CayedError::InvalidGameStatus,
+    )]
+    pub multi_game: Account<'info, MultiGame>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: PERMISSION PROGRAM
+    #[account(address = PERMISSION_PROGRAM_ID)]
+    pub permission_program: UncheckedAccount<'info>,
+}
+
+impl<'info> RevealMultiWinner<'info> {
+    pub fn reveal_multi_winner(&mut self, remaining: &'info [AccountInfo<'info>]) -> Result<()> {
+        let GameStatus::Completed { winner } = self.multi_game.status else {
+            return err!(CayedError::InvalidGameStatus);
+        };
+        require!(
+            remaining.len() == self.multi_game.players.len() * 2,
+            CayedError::InvalidBoardAccounts
+        );
+
+        self.multi_game.status = GameStatus::WinnerRevealed { winner };
+
+        // Clear permissions so boards are no longer restricted
+        let game_id_bytes = self.multi_game.id.to_le_bytes();
+        let permission_program = self.permission_program.to_account_info();
+        let mut boards = Vec::with_capacity(self.multi_game.players.len());
+
+        for (player, pair) in self.multi_game.players.iter().zip(remaining.chunks(2)) {
+            let (board, permission) = (&pair[0], &pair[1]);
+            let (expected, bump) = Pubkey::find_program_address(
+                &[b"multi_player", game_id_bytes.as_ref(), player.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(board.key(), expected, CayedError::InvalidBoardAccounts);
+
+            UpdatePermissionCpiBuilder::new(&permission_program)
+                .permissioned_account(board, true)
+                .authority(board, false)
+                .permission(permission)
+                .args(MembersArgs { members: None })
+                .invoke_signed(&[&[
+                    b"multi_player",
+                    game_id_bytes.as_ref(),
+                    player.as_ref(),
+                    &[bump],
+                ]])?;
+            boards.push(board);
+        }
+
+        // Exit and commit all game accounts back to base layer
+        self.multi_game.exit(&crate::ID)?;
+
+        let multi_game = self.multi_game.to_account_info();
+        let mut accounts = vec![&multi_game];
+        accounts.extend(boards);
+
+        commit_and_undelegate_accounts(
+            &self.payer,
+            accounts,
+            &self.magic_context.to_account_info(),
+            &self.magic_program.to_account_info(),
+        )?;
+
+        Ok(())
+    }
+}
@@ -17,7 +17,10 @@ pub struct RevealWinner<'info> {
         bump,
         constraint = matches!(
             game.status,
-            GameStatus::InProgress | GameStatus::Completed { .. }
+            GameStatus::InProgress
+                | GameStatus::Completed { .. }
+                | GameStatus::Forfeited { .. }
+                | GameStatus::OutOfAmmo { .. }
         ) @ CayedError::InvalidGameStatus,
     )]
     pub game: Account<'info, Game>,
@@ -54,18 +57,28 @@ pub struct RevealWinner<'info> {
 
 impl<'info> RevealWinner<'info> {
     pub fn reveal_winner(&mut self) -> Result<()> {
-        let p1_sunk = self.player1_board.all_ships_sunk();
-        let p2_sunk = self.player2_board.all_ships_sunk();
-        require!(p1_sunk || p2_sunk, CayedError::NotAllShipsSunk);
-
-        let winner = if p2_sunk {
-            self.game.player_1
-        } else {
-            self.game.player_2.unwrap()
+        self.game.forfeit = matches!(self.game.status, GameStatus::Forfeited { .. });
+        self.game.status = match self.game.status {
+            // Lost on time or out-scored; the boards are revealed as they stand
+            GameStatus::Forfeited { winner }
+            | GameStatus::OutOfAmmo {
+                winner: Some(winner),
+            } => GameStatus::WinnerRevealed { winner },
+            GameStatus::OutOfAmmo { winner: None } => GameStatus::Drawn,
+            _ => {
+                let p1_sunk = self.player1_board.all_ships_sunk();
+                let p2_sunk = self.player2_board.all_ships_sunk();
+                require!(p1_sunk || p2_sunk, CayedError::NotAllShipsSunk);
+
+                let winner = if p2_sunk {
+                    self.game.player_1
+                } else {
+                    self.game.player_2.unwrap()
+                };
+                GameStatus::WinnerRevealed { winner }
+            }
         };
 
-        self.game.status = GameStatus::WinnerRevealed { winner };
-
         // Clear permissions so boards are no longer restricted
         let game_id_bytes = self.game.id.to_le_bytes();
         let permission_program = self.permission_program.to_account_info();
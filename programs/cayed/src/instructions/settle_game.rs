@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Config, Game, GameStatus, PlayerGames, PlayerProfile, Season, Vault};
+
+/// Pays out a finished game once it is back on the base layer, drops it from both
+/// players' active games and adds it to their profiles. Anyone can settle. Series and
+/// bracket games are settled by `record_match_game` and `record_tournament_game`.
+#[derive(Accounts)]
+pub struct SettleGame<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"game", game.id.to_le_bytes().as_ref()],
+        bump = game.bump,
+        constraint = game.match_id.is_none() @ CayedError::InvalidGameStatus,
+        constraint = game.tournament_id.is_none() @ CayedError::InvalidGameStatus,
+        constraint = matches!(
+            game.status,
+            GameStatus::WinnerRevealed { .. } | GameStatus::Drawn
+        ) @ CayedError::InvalidGameStatus,
+        constraint = !game.settled @ CayedError::GameAlreadySettled,
+    )]
+    pub game: Account<'info, Game>,
+
+    /// CHECK: Player 1, paid out here
+    #[account(mut, address = game.player_1)]
+    pub player_1: UncheckedAccount<'info>,
+    /// CHECK: Player 2, paid out here
+    #[account(mut, address = game.player_2.unwrap_or_default())]
+    pub player_2: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_games", player_1.key().as_ref()],
+        bump = player1_games.bump,
+    )]
+    pub player1_games: Account<'info, PlayerGames>,
+    #[account(
+        mut,
+        seeds = [b"player_games", player_2.key().as_ref()],
+        bump = player2_games.bump,
+    )]
+    pub player2_games: Account<'info, PlayerGames>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"profile", player_1.key().as_ref()],
+        bump,
+    )]
+    pub player1_profile: Account<'info, PlayerProfile>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"profile", player_2.key().as_ref()],
+        bump,
+    )]
+    pub player2_profile: Account<'info, PlayerProfile>,
+    /// Required while `Config.season` is set
+    #[account(
+        mut,
+        seeds = [b"season", season.id.to_le_bytes().as_ref()],
+        bump = season.bump,
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [b"vault"],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SettleGame<'info> {
+    pub fn settle_game(&mut self, bumps: SettleGameBumps) -> Result<()> {
+        self.game.pay_out(
+            &self.config,
+            &self.vault,
+            &self.player_1.to_account_info(),
+            &self.player_2.to_account_info(),
+        )?;
+
+        // An unanswered rematch offer hands its stake back
+        if let Some(offer) = self.game.rematch.take() {
+            let to = if offer.from == self.game.player_1 {
+                &self.player_1
+            } else {
+                &self.player_2
+            };
+            if offer.deposit > 0 {
+                Vault::pay_out(&self.vault, &to.to_account_info(), offer.deposit)?;
+            }
+        }
+
+        let id = self.game.id;
+        self.player1_games.untrack(id);
+        self.player2_games.untrack(id);
+
+        self.player1_profile
+            .open(self.player_1.key(), bumps.player1_profile);
+        self.player2_profile
+            .open(self.player_2.key(), bumps.player2_profile);
+        self.game.settle(
+            &self.config,
+            &mut self.player1_profile,
+            &mut self.player2_profile,
+            self.season.as_deref_mut(),
+        )
+    }
+}
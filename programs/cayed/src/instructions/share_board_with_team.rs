@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use ephemeral_rollups_sdk::access_control::instructions::UpdatePermissionCpiBuilder;
+use ephemeral_rollups_sdk::access_control::structs::{
+    Member, MembersArgs, AUTHORITY_FLAG, TX_LOGS_FLAG,
+};
+use ephemeral_rollups_sdk::consts::PERMISSION_PROGRAM_ID;
+
+use crate::errors::CayedError;
+use crate::state::{MultiGame, PlayerBoard};
+
+/// Adds the teammate to the permission of the signer's board so the team shares
+/// one ocean on the ER, while opponents stay locked out.
+#[derive(Accounts)]
+pub struct ShareBoardWithTeam<'info> {
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [b"multi_game", multi_game.id.to_le_bytes().as_ref()],
+        bump = multi_game.bump,
+        constraint = multi_game.teams @ CayedError::NotATeamGame,
+    )]
+    pub multi_game: Account<'info, MultiGame>,
+
+    #[account(
+        seeds = [b"multi_player", multi_game.id.to_le_bytes().as_ref(), player.key().as_ref()],
+        bump = player_board.bump,
+    )]
+    pub player_board: Account<'info, PlayerBoard>,
+
+    /// CHECK: Checked by the permission program
+    #[account(mut)]
+    pub permission: UncheckedAccount<'info>,
+
+    /// CHECK: PERMISSION PROGRAM
+    #[account(address = PERMISSION_PROGRAM_ID)]
+    pub permission_program: UncheckedAccount<'info>,
+}
+
+impl<'info> ShareBoardWithTeam<'info> {
+    pub fn share_board_with_team(&mut self) -> Result<()> {
+        require!(
+            self.multi_game.players.len() == self.multi_game.max_players as usize,
+            CayedError::TeamIncomplete
+        );
+        let player = self.player.key();
+        let seat = self
+            .multi_game
+            .seat_of(&player)
+            .ok_or(CayedError::Unauthorized)?;
+        let teammate = self.multi_game.players[((seat + 2) % 4) as usize];
+
+        let members = vec![
+            Member {
+                flags: AUTHORITY_FLAG | TX_LOGS_FLAG,
+                pubkey: player,
+            },
+            Member {
+                flags: TX_LOGS_FLAG,
+                pubkey: teammate,
+            },
+        ];
+
+        let game_id_bytes = self.multi_game.id.to_le_bytes();
+        let board = self.player_board.to_account_info();
+        UpdatePermissionCpiBuilder::new(&self.permission_program.to_account_info())
+            .permissioned_account(&board, true)
+            .authority(&board, false)
+            .permission(&self.permission.to_account_info())
+            .args(MembersArgs {
+                members: Some(members),
+            })
+            .invoke_signed(&[&[
+                b"multi_player",
+                game_id_bytes.as_ref(),
+                player.as_ref(),
+                &[self.player_board.bump],
+            ]])?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CayedError,
+    instructions::MakeMove,
+    state::{cell_bit, Charges, MoveOutcome, ShotResult},
+};
+
+// Special weapons reuse the `MakeMove` accounts: same players, same boards, same ER commit.
+impl<'info> MakeMove<'info> {
+    pub fn sonar_sweep(&mut self, x: u8, y: u8) -> Result<()> {
+        let is_player1_turn = self.start_turn()?;
+
+        let grid_size = self.game.grid_size;
+        let half = grid_size / 2;
+        require!(x < grid_size && y < half, CayedError::AttackOutOfBounds);
+
+        let charges = self.charges(is_player1_turn);
+        require!(charges.sonar > 0, CayedError::NoChargesLeft);
+        charges.sonar -= 1;
+
+        // 3x3 area around (x, y), clipped to the board
+        let mut area: u64 = 0;
+        for ax in x.saturating_sub(1)..=(x + 1).min(grid_size - 1) {
+            for ay in y.saturating_sub(1)..=(y + 1).min(half - 1) {
+                area |= cell_bit(ax, ay, grid_size);
+            }
+        }
+        // Only the count is made public, never which cells
+        let ship_cells = self.opponent_board.sonar_contacts(area);
+
+        self.end_turn(is_player1_turn, x, y, MoveOutcome::Sonar { ship_cells })
+    }
+
+    pub fn fire_torpedo(&mut self, y: u8, from_east: bool) -> Result<()> {
+        let is_player1_turn = self.start_turn()?;
+
+        let grid_size = self.game.grid_size;
+        require!(y < grid_size / 2, CayedError::AttackOutOfBounds);
+
+        let charges = self.charges(is_player1_turn);
+        require!(charges.torpedo > 0, CayedError::NoChargesLeft);
+        charges.torpedo -= 1;
+        self.game.spend_shot(is_player1_turn);
+
+        // Run along the row, striking open water, until something stops the torpedo.
+        // Already-attacked cells are passed over; blocked cells run it aground.
+        let mut stop_x = 0;
+        let mut result = ShotResult::Miss;
+        for i in 0..grid_size {
+            let x = if from_east { grid_size - 1 - i } else { i };
+            let bit = cell_bit(x, y, grid_size);
+            stop_x = x;
+            if (self.game.blocked_mask & bit) != 0 {
+                break;
+            }
+            if (self.opponent_board.hits_bitmap & bit) != 0 {
+                continue;
+            }
+            result = self.strike(bit, is_player1_turn);
+            if result != ShotResult::Miss {
+                break;
+            }
+        }
+
+        self.end_turn(
+            is_player1_turn,
+            stop_x,
+            y,
+            MoveOutcome::Torpedo { from_east, result },
+        )
+    }
+
+    pub(crate) fn charges(&mut self, is_player1_turn: bool) -> &mut Charges {
+        if is_player1_turn {
+            &mut self.game.charges_player_1
+        } else {
+            &mut self.game.charges_player_2
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::events::{GameCreated, GameKind};
+use crate::state::{Config, Game, GameStatus, Match, MatchStatus, PlayerBoard, PlayerGames};
+
+#[derive(Accounts)]
+pub struct StartMatchGame<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"match", game_match.id.to_le_bytes().as_ref()],
+        bump = game_match.bump,
+        constraint = matches!(game_match.status, MatchStatus::InProgress) @ CayedError::InvalidGameStatus,
+    )]
+    pub game_match: Account<'info, Match>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Game::INIT_SPACE,
+        seeds = [b"game", config.game_counter.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PlayerBoard::INIT_SPACE,
+        seeds = [b"player", config.game_counter.to_le_bytes().as_ref(), game_match.player_1.as_ref()],
+        bump,
+    )]
+    pub player1_board: Account<'info, PlayerBoard>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PlayerBoard::INIT_SPACE,
+        seeds = [b"player", config.game_counter.to_le_bytes().as_ref(), game_match.player_2.unwrap().as_ref()],
+        bump,
+    )]
+    pub player2_board: Account<'info, PlayerBoard>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", game_match.player_1.as_ref()],
+        bump,
+    )]
+    pub player1_games: Account<'info, PlayerGames>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", game_match.player_2.unwrap().as_ref()],
+        bump,
+    )]
+    pub player2_games: Account<'info, PlayerGames>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StartMatchGame<'info> {
+    pub fn start_match_game(&mut self, bumps: StartMatchGameBumps) -> Result<u64> {
+        let payer = self.payer.key();
+        let player_1 = self.game_match.player_1;
+        let player_2 = self.game_match.player_2.unwrap();
+        require!(
+            payer == player_1 || payer == player_2,
+            CayedError::Unauthorized
+        );
+        require!(
+            !self.game_match.game_in_progress(),
+            CayedError::MatchGameInProgress
+        );
+
+        let game_id = self.config.next_game_id()?;
+        let mut game = Game::new(
+            game_id,
+            self.game_match.grid_size,
+            player_1,
+            0,
+            self.game_match.settings.clone(),
+            bumps.game,
+        );
+        game.match_id = Some(self.game_match.id);
+        game.player_2 = Some(player_2);
+        game.status = GameStatus::HidingShips;
+        // Players take turns opening the series' games, replays of draws included
+        game.next_move_player_1 = self.game_match.next_opener_player_1;
+        self.game_match.next_opener_player_1 = !self.game_match.next_opener_player_1;
+        game.first_move_decided = true;
+        self.game.set_inner(game);
+
+        self.player1_board
+            .set_inner(PlayerBoard::new(game_id, player_1, bumps.player1_board));
+        self.player2_board
+            .set_inner(PlayerBoard::new(game_id, player_2, bumps.player2_board));
+
+        self.game_match.games.push(game_id);
+
+        self.player1_games.player = player_1;
+        self.player1_games.bump = bumps.player1_games;
+        self.player1_games.track_paired(game_id);
+        self.player2_games.player = player_2;
+        self.player2_games.bump = bumps.player2_games;
+        self.player2_games.track_paired(game_id);
+
+        emit!(GameCreated {
+            id: game_id,
+            creator: payer,
+            kind: GameKind::Match {
+                match_id: self.game_match.id,
+            },
+        });
+
+        Ok(game_id)
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Tournament, TournamentStatus};
+
+/// Closes registration and seeds the bracket, padding it to a power of two with byes.
+#[derive(Accounts)]
+pub struct StartTournament<'info> {
+    #[account(address = tournament.organizer @ CayedError::Unauthorized)]
+    pub organizer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::Registration @ CayedError::RegistrationClosed,
+    )]
+    pub tournament: Account<'info, Tournament>,
+}
+
+impl<'info> StartTournament<'info> {
+    pub fn start_tournament(&mut self) -> Result<()> {
+        let entrants = self.tournament.entrants.len();
+        require!(entrants >= 2, CayedError::TooFewEntrants);
+
+        self.tournament.seed(Clock::get()?.unix_timestamp);
+        self.tournament.status = TournamentStatus::InProgress;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::events::{GameCreated, GameKind};
+use crate::state::{
+    Config, Game, GameStatus, PlayerBoard, PlayerGames, Tournament, TournamentStatus,
+};
+
+/// Creates the game for one pairing of the current round. Anyone can call it.
+#[derive(Accounts)]
+#[instruction(pair: u8)]
+pub struct StartTournamentGame<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = tournament.status == TournamentStatus::InProgress @ CayedError::TournamentNotInProgress,
+    )]
+    pub tournament: Account<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Game::INIT_SPACE,
+        seeds = [b"game", config.game_counter.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub game: Account<'info, Game>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PlayerBoard::INIT_SPACE,
+        seeds = [b"player", config.game_counter.to_le_bytes().as_ref(), tournament.players(pair).0.as_ref()],
+        bump,
+    )]
+    pub player1_board: Account<'info, PlayerBoard>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PlayerBoard::INIT_SPACE,
+        seeds = [b"player", config.game_counter.to_le_bytes().as_ref(), tournament.players(pair).1.as_ref()],
+        bump,
+    )]
+    pub player2_board: Account<'info, PlayerBoard>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", tournament.players(pair).0.as_ref()],
+        bump,
+    )]
+    pub player1_games: Account<'info, PlayerGames>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PlayerGames::INIT_SPACE,
+        seeds = [b"player_games", tournament.players(pair).1.as_ref()],
+        bump,
+    )]
+    pub player2_games: Account<'info, PlayerGames>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StartTournamentGame<'info> {
+    pub fn start_tournament_game(
+        &mut self,
+        pair: u8,
+        bumps: StartTournamentGameBumps,
+    ) -> Result<u64> {
+        let pairing = self
+            .tournament
+            .pairings
+            .get(pair as usize)
+            .ok_or(CayedError::InvalidPairing)?;
+        require!(
+            pairing.game_id.is_none() && pairing.winner.is_none(),
+            CayedError::PairingStarted
+        );
+
+        let (player_1, player_2) = self.tournament.players(pair);
+        let id = self.config.next_game_id()?;
+        let mut game = Game::new(
+            id,
+            self.tournament.grid_size,
+            player_1,
+            0,
+            self.tournament.settings.clone(),
+            bumps.game,
+        );
+        game.tournament_id = Some(self.tournament.id);
+        game.player_2 = Some(player_2);
+        game.status = GameStatus::HidingShips;
+        self.game.set_inner(game);
+
+        self.player1_board
+            .set_inner(PlayerBoard::new(id, player_1, bumps.player1_board));
+        self.player2_board
+            .set_inner(PlayerBoard::new(id, player_2, bumps.player2_board));
+
+        self.tournament.pairings[pair as usize].game_id = Some(id);
+
+        self.player1_games.player = player_1;
+        self.player1_games.bump = bumps.player1_games;
+        self.player1_games.track_paired(id);
+        self.player2_games.player = player_2;
+        self.player2_games.bump = bumps.player2_games;
+        self.player2_games.track_paired(id);
+
+        emit!(GameCreated {
+            id,
+            creator: self.payer.key(),
+            kind: GameKind::Tournament {
+                tournament_id: self.tournament.id,
+            },
+        });
+
+        Ok(id)
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Tournament, TournamentStatus};
+
+/// Hands the organizer whatever the prize split leaves over, keeping every unclaimed
+/// prize and the account's rent in place.
+#[derive(Accounts)]
+pub struct SweepTournament<'info> {
+    #[account(mut, address = tournament.organizer @ CayedError::Unauthorized)]
+    pub organizer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament.id.to_le_bytes().as_ref()],
+        bump = tournament.bump,
+        constraint = matches!(tournament.status, TournamentStatus::Completed { .. }) @ CayedError::TournamentNotFinished,
+    )]
+    pub tournament: Account<'info, Tournament>,
+}
+
+impl<'info> SweepTournament<'info> {
+    pub fn sweep_tournament(&mut self) -> Result<()> {
+        let rent = Rent::get()?.minimum_balance(8 + Tournament::INIT_SPACE);
+        let held = rent
+            .checked_add(self.tournament.unclaimed())
+            .ok_or(CayedError::Overflow)?;
+        let leftover = self
+            .tournament
+            .to_account_info()
+            .lamports()
+            .saturating_sub(held);
+        if leftover > 0 {
+            Tournament::pay_out(
+                &self.tournament,
+                &self.organizer.to_account_info(),
+                leftover,
+            )?;
+        }
+
+        Ok(())
+    }
+}
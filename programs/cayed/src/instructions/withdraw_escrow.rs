@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Escrow;
+
+#[derive(Accounts)]
+pub struct WithdrawEscrow<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", player.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+}
+
+impl<'info> WithdrawEscrow<'info> {
+    pub fn withdraw_escrow(&mut self, amount: u64) -> Result<()> {
+        Escrow::pay_out(&self.escrow, &self.player.to_account_info(), amount)
+    }
+}
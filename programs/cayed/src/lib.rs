@@ -2,9 +2,10 @@ use anchor_lang::prelude::*;
 use ephemeral_rollups_sdk::access_control::structs::Member;
 use ephemeral_rollups_sdk::anchor::ephemeral;
 
-use state::ShipCoordinates;
+use state::{Cell, Challenge, GameSettings, Heading, Invite, SeasonRanking, ShipCoordinates};
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -18,23 +19,157 @@ declare_id!("6xLHbAHw2ibrmdVEPHm7jDkDmghw3fp3gUCBy511DMKV");
 pub mod cayed {
     use super::*;
 
-    pub fn init_config(ctx: Context<InitConfig>, max_grid_size: u8, fee: u16) -> Result<()> {
-        ctx.accounts.init_config(max_grid_size, fee, ctx.bumps)?;
+    pub fn init_config(
+        ctx: Context<InitConfig>,
+        max_grid_size: u8,
+        fee: u16,
+        max_active_games: u8,
+    ) -> Result<()> {
+        ctx.accounts
+            .init_config(max_grid_size, fee, max_active_games, ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn create_game(
+        ctx: Context<CreateGame>,
+        grid_size: u8,
+        wager: u64,
+        settings: GameSettings,
+        first_move_commitment: Option<[u8; 32]>,
+        invite: Option<Invite>,
+        page: u32,
+    ) -> Result<u64> {
+        ctx.accounts.create_game(
+            grid_size,
+            wager,
+            settings,
+            first_move_commitment,
+            invite,
+            page,
+            ctx.bumps,
+        )
+    }
+
+    pub fn create_map(ctx: Context<CreateMap>, grid_size: u8, blocked_mask: u64) -> Result<u64> {
+        ctx.accounts.create_map(grid_size, blocked_mask, ctx.bumps)
+    }
+
+    pub fn create_match(
+        ctx: Context<CreateMatch>,
+        grid_size: u8,
+        wager: u64,
+        settings: GameSettings,
+        best_of: u8,
+    ) -> Result<u64> {
+        ctx.accounts
+            .create_match(grid_size, wager, settings, best_of, ctx.bumps)
+    }
+
+    pub fn join_match(ctx: Context<JoinMatch>) -> Result<()> {
+        ctx.accounts.join_match(ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn cancel_match(ctx: Context<CancelMatch>) -> Result<()> {
+        ctx.accounts.cancel_match()?;
+        Ok(())
+    }
+
+    pub fn start_match_game(ctx: Context<StartMatchGame>) -> Result<u64> {
+        ctx.accounts.start_match_game(ctx.bumps)
+    }
+
+    pub fn record_match_game(ctx: Context<RecordMatchGame>) -> Result<()> {
+        ctx.accounts.record_match_game(ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn hide_ships(
+        ctx: Context<HideShips>,
+        ships: Vec<ShipCoordinates>,
+        mines: Vec<Cell>,
+        decoys: Vec<Cell>,
+    ) -> Result<()> {
+        ctx.accounts.hide_ships(ships, mines, decoys)?;
+        Ok(())
+    }
+
+    pub fn auto_place_ships(
+        ctx: Context<AutoPlaceShips>,
+        client_seed: u8,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .auto_place_ships(client_seed, salt, ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn callback_auto_place_ships(
+        ctx: Context<CallbackAutoPlaceShips>,
+        randomness: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.callback_auto_place_ships(randomness)?;
+        Ok(())
+    }
+
+    pub fn enqueue(ctx: Context<Enqueue>, grid_size: u8, bucket: u8) -> Result<()> {
+        ctx.accounts.enqueue(grid_size, bucket, ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn leave_queue(ctx: Context<LeaveQueue>) -> Result<()> {
+        ctx.accounts.leave_queue()?;
         Ok(())
     }
 
-    pub fn create_game(ctx: Context<CreateGame>, id: u64, grid_size: u8, wager: u64) -> Result<()> {
-        ctx.accounts.create_game(id, grid_size, wager, ctx.bumps)?;
+    pub fn match_players(ctx: Context<MatchPlayers>) -> Result<u64> {
+        ctx.accounts.match_players(ctx.bumps)
+    }
+
+    pub fn fund_escrow(ctx: Context<FundEscrow>, amount: u64) -> Result<()> {
+        ctx.accounts.fund_escrow(amount, ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn withdraw_escrow(ctx: Context<WithdrawEscrow>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_escrow(amount)?;
+        Ok(())
+    }
+
+    pub fn accept_challenge(ctx: Context<AcceptChallenge>, challenge: Challenge) -> Result<u64> {
+        ctx.accounts.accept_challenge(challenge, ctx.bumps)
+    }
+
+    pub fn join_game(
+        ctx: Context<JoinGame>,
+        first_move_commitment: Option<[u8; 32]>,
+        invite_code: Option<Vec<u8>>,
+    ) -> Result<()> {
+        ctx.accounts
+            .join_game(first_move_commitment, invite_code, ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn cancel_game(ctx: Context<CancelGame>) -> Result<()> {
+        ctx.accounts.cancel_game()?;
         Ok(())
     }
 
-    pub fn hide_ships(ctx: Context<HideShips>, ships: Vec<ShipCoordinates>) -> Result<()> {
-        ctx.accounts.hide_ships(ships)?;
+    pub fn reveal_coin_flip(ctx: Context<RevealCoinFlip>, secret: [u8; 32]) -> Result<()> {
+        ctx.accounts.reveal_coin_flip(secret)?;
         Ok(())
     }
 
-    pub fn join_game(ctx: Context<JoinGame>) -> Result<()> {
-        ctx.accounts.join_game(ctx.bumps)?;
+    pub fn request_first_move(ctx: Context<RequestFirstMove>, client_seed: u8) -> Result<()> {
+        ctx.accounts.request_first_move(client_seed, ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn callback_first_move(
+        ctx: Context<CallbackFirstMove>,
+        randomness: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.callback_first_move(randomness)?;
         Ok(())
     }
 
@@ -43,11 +178,207 @@ pub mod cayed {
         Ok(())
     }
 
+    pub fn sonar_sweep(ctx: Context<MakeMove>, x: u8, y: u8) -> Result<()> {
+        ctx.accounts.sonar_sweep(x, y)?;
+        Ok(())
+    }
+
+    pub fn fire_torpedo(ctx: Context<MakeMove>, y: u8, from_east: bool) -> Result<()> {
+        ctx.accounts.fire_torpedo(y, from_east)?;
+        Ok(())
+    }
+
+    pub fn maneuver_ship(ctx: Context<MakeMove>, ship_index: u8, heading: Heading) -> Result<()> {
+        ctx.accounts.maneuver_ship(ship_index, heading)?;
+        Ok(())
+    }
+
+    pub fn claim_timeout(ctx: Context<ClaimTimeout>) -> Result<()> {
+        ctx.accounts.claim_timeout()?;
+        Ok(())
+    }
+
     pub fn reveal_winner(ctx: Context<RevealWinner>) -> Result<()> {
         ctx.accounts.reveal_winner()?;
         Ok(())
     }
 
+    pub fn settle_game(ctx: Context<SettleGame>) -> Result<()> {
+        ctx.accounts.settle_game(ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn create_season(
+        ctx: Context<CreateSeason>,
+        id: u64,
+        start_slot: u64,
+        end_slot: u64,
+        ranking: SeasonRanking,
+        prize_pool: u64,
+        prize_split: Vec<u16>,
+    ) -> Result<()> {
+        ctx.accounts.create_season(
+            id,
+            start_slot,
+            end_slot,
+            ranking,
+            prize_pool,
+            prize_split,
+            ctx.bumps,
+        )?;
+        Ok(())
+    }
+
+    pub fn close_season(ctx: Context<CloseSeason>) -> Result<()> {
+        ctx.accounts.close_season()?;
+        Ok(())
+    }
+
+    pub fn claim_season_prize(ctx: Context<ClaimSeasonPrize>) -> Result<()> {
+        ctx.accounts.claim_season_prize()?;
+        Ok(())
+    }
+
+    pub fn create_tournament(
+        ctx: Context<CreateTournament>,
+        grid_size: u8,
+        settings: GameSettings,
+        entry_fee: u64,
+        max_players: u8,
+        prize_split: Vec<u16>,
+        round_secs: u32,
+    ) -> Result<u64> {
+        ctx.accounts.create_tournament(
+            grid_size,
+            settings,
+            entry_fee,
+            max_players,
+            prize_split,
+            round_secs,
+            ctx.bumps,
+        )
+    }
+
+    pub fn register_tournament(ctx: Context<RegisterTournament>) -> Result<()> {
+        ctx.accounts.register_tournament(ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn start_tournament(ctx: Context<StartTournament>) -> Result<()> {
+        ctx.accounts.start_tournament()?;
+        Ok(())
+    }
+
+    pub fn start_tournament_game(ctx: Context<StartTournamentGame>, pair: u8) -> Result<u64> {
+        ctx.accounts.start_tournament_game(pair, ctx.bumps)
+    }
+
+    pub fn recall_tournament_game(ctx: Context<RecallTournamentGame>) -> Result<()> {
+        ctx.accounts.recall_tournament_game()?;
+        Ok(())
+    }
+
+    pub fn record_tournament_game(ctx: Context<RecordTournamentGame>) -> Result<()> {
+        ctx.accounts.record_tournament_game(ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn claim_tournament_prize(ctx: Context<ClaimTournamentPrize>) -> Result<()> {
+        ctx.accounts.claim_tournament_prize()?;
+        Ok(())
+    }
+
+    pub fn sweep_tournament(ctx: Context<SweepTournament>) -> Result<()> {
+        ctx.accounts.sweep_tournament()?;
+        Ok(())
+    }
+
+    pub fn cancel_tournament<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelTournament<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.cancel_tournament(ctx.remaining_accounts)?;
+        Ok(())
+    }
+
+    pub fn offer_rematch(ctx: Context<OfferRematch>, double_or_nothing: bool) -> Result<()> {
+        ctx.accounts.offer_rematch(double_or_nothing)?;
+        Ok(())
+    }
+
+    pub fn accept_rematch(ctx: Context<AcceptRematch>) -> Result<u64> {
+        ctx.accounts.accept_rematch(ctx.bumps)
+    }
+
+    pub fn create_multi_game(
+        ctx: Context<CreateMultiGame>,
+        grid_size: u8,
+        wager: u64,
+        max_players: u8,
+        teams: bool,
+    ) -> Result<u64> {
+        ctx.accounts
+            .create_multi_game(grid_size, wager, max_players, teams, ctx.bumps)
+    }
+
+    pub fn join_multi_game(ctx: Context<JoinMultiGame>) -> Result<()> {
+        ctx.accounts.join_multi_game(ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn cancel_multi_game<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelMultiGame<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.cancel_multi_game(ctx.remaining_accounts)?;
+        Ok(())
+    }
+
+    pub fn hide_multi_ships(
+        ctx: Context<HideMultiShips>,
+        ships: Vec<ShipCoordinates>,
+    ) -> Result<()> {
+        ctx.accounts.hide_multi_ships(ships)?;
+        Ok(())
+    }
+
+    pub fn request_multi_first_move(
+        ctx: Context<RequestMultiFirstMove>,
+        client_seed: u8,
+    ) -> Result<()> {
+        ctx.accounts
+            .request_multi_first_move(client_seed, ctx.bumps)?;
+        Ok(())
+    }
+
+    pub fn callback_multi_first_move(
+        ctx: Context<CallbackMultiFirstMove>,
+        randomness: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.callback_multi_first_move(randomness)?;
+        Ok(())
+    }
+
+    pub fn multi_make_move(ctx: Context<MultiMakeMove>, x: u8, y: u8) -> Result<()> {
+        ctx.accounts.multi_make_move(x, y)?;
+        Ok(())
+    }
+
+    pub fn reveal_multi_winner<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RevealMultiWinner<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.reveal_multi_winner(ctx.remaining_accounts)?;
+        Ok(())
+    }
+
+    pub fn claim_multi_pot(ctx: Context<ClaimMultiPot>) -> Result<()> {
+        ctx.accounts.claim_multi_pot()?;
+        Ok(())
+    }
+
+    pub fn share_board_with_team(ctx: Context<ShareBoardWithTeam>) -> Result<()> {
+        ctx.accounts.share_board_with_team()?;
+        Ok(())
+    }
+
     pub fn create_permission(
         ctx: Context<CreatePermission>,
         account_type: AccountType,
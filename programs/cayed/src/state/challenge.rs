@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
+use solana_sdk_ids::ed25519_program;
+
+use crate::errors::CayedError;
+
+/// Prefix of every signed challenge so the signature can't be reused for anything else.
+pub const CHALLENGE_DOMAIN: &[u8] = b"cayed:challenge";
+
+/// Terms player 1 signs off-chain. Player 2 submits them to `accept_challenge`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Challenge {
+    pub challenger: Pubkey,
+    /// Only this player may accept, if set.
+    pub opponent: Option<Pubkey>,
+    pub grid_size: u8,
+    pub wager: u64,
+    /// Unix timestamp after which the challenge can no longer be accepted.
+    pub expiry: i64,
+    /// Lets the challenger issue several challenges with the same terms.
+    pub nonce: u64,
+}
+
+impl Challenge {
+    /// Bytes the challenger signs.
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = [CHALLENGE_DOMAIN, crate::ID.as_ref()].concat();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+
+    /// Check that the instruction right before this one is an Ed25519 verification of
+    /// the challenger's signature over `message()`.
+    pub fn verify_signature(&self, instructions: &AccountInfo) -> Result<()> {
+        let current = load_current_index_checked(instructions)?;
+        require!(current > 0, CayedError::InvalidChallengeSignature);
+        let ix = load_instruction_at_checked(current as usize - 1, instructions)?;
+        require!(
+            ix.program_id == ed25519_program::ID,
+            CayedError::InvalidChallengeSignature
+        );
+
+        // One signature, with the key and message inside the Ed25519 instruction itself
+        let data = &ix.data;
+        require!(
+            data.len() >= 16 && data[0] == 1,
+            CayedError::InvalidChallengeSignature
+        );
+        let read = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+        require!(
+            read(4) == u16::MAX && read(8) == u16::MAX && read(14) == u16::MAX,
+            CayedError::InvalidChallengeSignature
+        );
+        let (key_at, message_at, message_len) =
+            (read(6) as usize, read(10) as usize, read(12) as usize);
+        let key = data.get(key_at..key_at + 32);
+        let message = data.get(message_at..message_at + message_len);
+
+        require!(
+            key == Some(self.challenger.as_ref()) && message == Some(self.message()?.as_slice()),
+            CayedError::InvalidChallengeSignature
+        );
+        Ok(())
+    }
+}
+
+/// Marks a challenge as accepted so it can't be replayed.
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengeReceipt {
+    pub game_id: u64,
+    pub bump: u8,
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::CayedError;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
@@ -7,5 +9,59 @@ pub struct Config {
     pub vault: Pubkey, // Where fee ends up
     pub max_grid_size: u8,
     pub fee: u16, // Basis points (10,000 = 100%)
+    /// Id the next `Game` will be created with.
+    pub game_counter: u64,
+    /// Ids the next `Map`, `Match` and `Tournament` will be created with.
+    pub map_counter: u64,
+    pub match_counter: u64,
+    pub tournament_counter: u64,
+    /// `OpenGames` pages created so far, numbered from zero.
+    pub open_games_pages: u32,
+    /// Unsettled games a wallet may be in at once, up to `PlayerGames::MAX_GAMES`.
+    pub max_active_games: u8,
+    /// Season whose leaderboard settlements must update, until it is closed.
+    pub season: Option<u64>,
     pub bump: u8,
 }
+
+impl Config {
+    /// Hand out the next game id.
+    pub fn next_game_id(&mut self) -> Result<u64> {
+        Self::next_id(&mut self.game_counter)
+    }
+
+    pub fn next_map_id(&mut self) -> Result<u64> {
+        Self::next_id(&mut self.map_counter)
+    }
+
+    pub fn next_match_id(&mut self) -> Result<u64> {
+        Self::next_id(&mut self.match_counter)
+    }
+
+    pub fn next_tournament_id(&mut self) -> Result<u64> {
+        Self::next_id(&mut self.tournament_counter)
+    }
+
+    fn next_id(counter: &mut u64) -> Result<u64> {
+        let id = *counter;
+        *counter = id.checked_add(1).ok_or(CayedError::Overflow)?;
+        Ok(id)
+    }
+
+    /// Check `page` exists or is the next one, counting it in when it is new.
+    pub fn use_open_games_page(&mut self, page: u32) -> Result<()> {
+        require!(
+            page <= self.open_games_pages,
+            CayedError::InvalidOpenGamesPage
+        );
+        if page == self.open_games_pages {
+            self.open_games_pages = page.checked_add(1).ok_or(CayedError::Overflow)?;
+        }
+        Ok(())
+    }
+
+    /// Protocol cut of `amount` at the configured basis points.
+    pub fn fee_on(&self, amount: u64) -> u64 {
+        (amount as u128 * self.fee as u128 / 10_000) as u64
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+
+/// Lamports a player sets aside so off-chain challenges they sign can be accepted
+/// without them. Everything above rent is spendable.
+#[account]
+#[derive(InitSpace)]
+pub struct Escrow {
+    pub player: Pubkey,
+    pub bump: u8,
+}
+
+impl Escrow {
+    /// Lamports available above the rent-exempt minimum.
+    pub fn balance(escrow: &Account<Escrow>) -> Result<u64> {
+        let rent = Rent::get()?.minimum_balance(8 + Escrow::INIT_SPACE);
+        Ok(escrow.to_account_info().lamports().saturating_sub(rent))
+    }
+
+    /// Move `amount` lamports out of the escrow, which this program owns.
+    pub fn pay_out<'info>(
+        escrow: &Account<'info, Escrow>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(
+            Escrow::balance(escrow)? >= amount,
+            CayedError::InsufficientEscrow
+        );
+        let escrow_info = escrow.to_account_info();
+        let to_lamports = to
+            .lamports()
+            .checked_add(amount)
+            .ok_or(CayedError::Overflow)?;
+        **escrow_info.try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? = to_lamports;
+        Ok(())
+    }
+}
@@ -1,24 +1,531 @@
 use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+use crate::errors::CayedError;
+use crate::state::{board_mask, Config, PlayerProfile, Season, Vault};
+
+/// Charges a player can be given of each special weapon, handicaps aside.
+pub const MAX_CHARGES: u8 = 3;
+
+/// Maneuvers each player may make when `GameSettings.maneuvers` is on.
+pub const MAX_MANEUVERS: u8 = 5;
+
+/// Log entries a game can reach: one per cell on both boards, plus every action that
+/// may strike no new cell - each player's sonar sweeps (one more under `FreeSonar`),
+/// torpedoes that pass only over attacked water, and maneuvers.
+pub const MAX_MOVES: usize = 100 + 2 * (2 * MAX_CHARGES as usize + 1 + MAX_MANEUVERS as usize);
 
 #[account]
 #[derive(InitSpace)]
 pub struct Game {
     pub id: u64,
     pub grid_size: u8,
+    /// Map the game is played on, if any.
+    pub map: Option<Pubkey>,
+    /// Copy of the map's blocked cells so ER instructions don't need the `Map` account.
+    pub blocked_mask: u64,
+    /// Best-of-N series this game belongs to, if any. Match games carry no wager of their own.
+    pub match_id: Option<u64>,
+    /// Tournament this game is a bracket pairing of, if any. Carries no wager either.
+    pub tournament_id: Option<u64>,
     pub player_1: Pubkey,
     pub player_2: Option<Pubkey>,
+    /// Who may take the second seat; open to anyone when `None`.
+    pub invite: Option<Invite>,
     #[max_len(5)]
     pub revealed_ships_player_1: Vec<ShipCoordinates>,
     #[max_len(5)]
     pub revealed_ships_player_2: Vec<ShipCoordinates>,
-    #[max_len(100)]
+    #[max_len(MAX_MOVES)]
     pub moves: Vec<MoveResult>,
+    /// Whose turn it is; meaningless until `first_move_decided`, then advanced by every move.
     pub next_move_player_1: bool,
+    /// Set once the opening player has been drawn by VRF or coin flip (or fixed by a match).
+    pub first_move_decided: bool,
+    /// Commit-reveal coin flip for the opening player, if the creator opted in.
+    pub coin_flip: Option<CoinFlip>,
+    /// Set once each player's fleet is on their board.
+    pub ships_hidden_player_1: bool,
+    pub ships_hidden_player_2: bool,
+    /// Set when a player hit a mine and must sit out their next turn.
+    pub skip_turn_player_1: bool,
+    pub skip_turn_player_2: bool,
+    /// Extra shots already fired this turn under a `BonusShots` handicap.
+    pub bonus_shots_fired: u8,
+    /// Shots each player has fired in a limited-ammo game.
+    pub shots_fired_player_1: u8,
+    pub shots_fired_player_2: u8,
+    pub settings: GameSettings,
+    /// Special weapon charges left, seeded from `settings`.
+    pub charges_player_1: Charges,
+    pub charges_player_2: Charges,
+    /// Seconds left on each player's clock when `settings.time_bank_secs` is set.
+    pub time_left_player_1: u32,
+    pub time_left_player_2: u32,
+    /// When the player on turn started thinking; zero until both fleets are hidden and
+    /// the opener is known.
+    pub turn_started_at: i64,
     pub wager: u64,
     pub status: GameStatus,
+    /// Set when the loser ran out of time rather than ships.
+    pub forfeit: bool,
+    /// Set once `settle_game` has paid out the wagers.
+    pub settled: bool,
+    /// A rematch one player has proposed and the other has yet to accept.
+    pub rematch: Option<RematchOffer>,
     pub bump: u8,
 }
 
+impl Game {
+    pub fn new(
+        id: u64,
+        grid_size: u8,
+        player_1: Pubkey,
+        wager: u64,
+        settings: GameSettings,
+        bump: u8,
+    ) -> Self {
+        Self {
+            id,
+            grid_size,
+            map: None,
+            blocked_mask: 0,
+            match_id: None,
+            tournament_id: None,
+            player_1,
+            player_2: None,
+            invite: None,
+            revealed_ships_player_1: vec![],
+            revealed_ships_player_2: vec![],
+            moves: vec![],
+            next_move_player_1: true,
+            first_move_decided: false,
+            coin_flip: None,
+            ships_hidden_player_1: false,
+            ships_hidden_player_2: false,
+            skip_turn_player_1: false,
+            skip_turn_player_2: false,
+            bonus_shots_fired: 0,
+            shots_fired_player_1: 0,
+            shots_fired_player_2: 0,
+            charges_player_1: Charges::new(&settings, true),
+            charges_player_2: Charges::new(&settings, false),
+            time_left_player_1: settings.time_bank_secs,
+            time_left_player_2: settings.time_bank_secs,
+            turn_started_at: 0,
+            settings,
+            wager,
+            status: GameStatus::AwaitingPlayerTwo,
+            forfeit: false,
+            settled: false,
+            rematch: None,
+            bump,
+        }
+    }
+
+    /// Seconds the player on turn has left at `now`, or `None` while no clock is running.
+    pub fn time_left(&self, now: i64) -> Option<i64> {
+        if self.settings.time_bank_secs == 0 || self.turn_started_at == 0 {
+            return None;
+        }
+        let bank = if self.next_move_player_1 {
+            self.time_left_player_1
+        } else {
+            self.time_left_player_2
+        };
+        Some(bank as i64 - (now - self.turn_started_at))
+    }
+
+    /// Start the opener's clock once both fleets are hidden and the opener is known, so an
+    /// opener who never moves can still be flagged.
+    pub fn start_clock(&mut self, now: i64) {
+        if self.settings.time_bank_secs > 0
+            && self.turn_started_at == 0
+            && self.first_move_decided
+            && self.ships_hidden_player_1
+            && self.ships_hidden_player_2
+        {
+            self.turn_started_at = now;
+        }
+    }
+
+    /// Record that a player's fleet is in place and start the clock if it was the last.
+    pub fn ships_hidden(&mut self, player_1: bool, now: i64) {
+        if player_1 {
+            self.ships_hidden_player_1 = true;
+        } else {
+            self.ships_hidden_player_2 = true;
+        }
+        self.start_clock(now);
+    }
+
+    /// Stop the mover's clock and hand the turn to the other player, skipping anyone who
+    /// owes a turn for hitting a mine.
+    pub fn end_turn(&mut self, player_1_moved: bool, now: i64) {
+        if self.settings.time_bank_secs > 0 {
+            let elapsed = if self.turn_started_at == 0 {
+                0
+            } else {
+                (now - self.turn_started_at).clamp(0, u32::MAX as i64) as u32
+            };
+            let bank = if player_1_moved {
+                &mut self.time_left_player_1
+            } else {
+                &mut self.time_left_player_2
+            };
+            *bank = bank
+                .saturating_sub(elapsed)
+                .saturating_add(self.settings.increment_secs);
+            self.turn_started_at = now;
+        }
+
+        // A weaker player with bonus shots keeps the turn until they are spent
+        if self.bonus_shots_fired < self.settings.handicap.bonus_shots(player_1_moved) {
+            self.bonus_shots_fired += 1;
+        } else {
+            self.bonus_shots_fired = 0;
+            self.next_move_player_1 = !player_1_moved;
+            // At most two hops: if both players owe a turn the debts cancel out.
+            for _ in 0..2 {
+                let skip = if self.next_move_player_1 {
+                    &mut self.skip_turn_player_1
+                } else {
+                    &mut self.skip_turn_player_2
+                };
+                if !*skip {
+                    break;
+                }
+                *skip = false;
+                self.next_move_player_1 = !self.next_move_player_1;
+            }
+        }
+
+        // Nobody waits on a player who has fired their last shot
+        if self.out_of_ammo(self.next_move_player_1) {
+            self.next_move_player_1 = !self.next_move_player_1;
+            self.bonus_shots_fired = 0;
+        }
+    }
+
+    /// Count a shot against the attacker's ammo. Only shots and torpedoes use ammo; sonar
+    /// sweeps and maneuvers don't.
+    pub fn spend_shot(&mut self, player_1: bool) {
+        if self.settings.ammo > 0 {
+            if player_1 {
+                self.shots_fired_player_1 += 1;
+            } else {
+                self.shots_fired_player_2 += 1;
+            }
+        }
+    }
+
+    /// Whether a player has fired every shot a limited-ammo game allows.
+    pub fn out_of_ammo(&self, player_1: bool) -> bool {
+        let fired = if player_1 {
+            self.shots_fired_player_1
+        } else {
+            self.shots_fired_player_2
+        };
+        self.settings.ammo > 0 && fired >= self.settings.ammo
+    }
+
+    /// Limited-ammo games end once both players have fired everything.
+    pub fn ammo_spent(&self) -> bool {
+        self.out_of_ammo(true) && self.out_of_ammo(false)
+    }
+
+    /// Whether player 1 took the opening turn.
+    pub fn player_1_opened(&self) -> bool {
+        self.moves.first().map_or(self.next_move_player_1, |first| {
+            first.shooter == self.player_1
+        })
+    }
+
+    /// Winner of a finished game; `None` for a draw.
+    pub fn winner(&self) -> Option<Pubkey> {
+        match self.status {
+            GameStatus::WinnerRevealed { winner } => Some(winner),
+            _ => None,
+        }
+    }
+
+    /// Lamports owed to player 1 and player 2 at settlement: the winner takes both stakes
+    /// less the protocol fee, a draw hands each stake back.
+    pub fn payouts(&self, config: &Config) -> Result<(u64, u64)> {
+        match self.winner() {
+            Some(winner) => {
+                let pot = self.wager.checked_mul(2).ok_or(CayedError::Overflow)?;
+                let payout = pot
+                    .checked_sub(config.fee_on(pot))
+                    .ok_or(CayedError::Overflow)?;
+                if winner == self.player_1 {
+                    Ok((payout, 0))
+                } else {
+                    Ok((0, payout))
+                }
+            }
+            None => Ok((self.wager, self.wager)),
+        }
+    }
+
+    /// Pay both players their `payouts` from the vault.
+    pub fn pay_out<'info>(
+        &self,
+        config: &Config,
+        vault: &Account<'info, Vault>,
+        player_1: &AccountInfo<'info>,
+        player_2: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let (to_player_1, to_player_2) = self.payouts(config)?;
+        if to_player_1 > 0 {
+            Vault::pay_out(vault, player_1, to_player_1)?;
+        }
+        if to_player_2 > 0 {
+            Vault::pay_out(vault, player_2, to_player_2)?;
+        }
+        Ok(())
+    }
+
+    /// Mark the game settled and fold it into both profiles, their ratings and the
+    /// running season, which must be passed while `Config.season` is set.
+    pub fn settle(
+        &mut self,
+        config: &Config,
+        profile_1: &mut PlayerProfile,
+        profile_2: &mut PlayerProfile,
+        season: Option<&mut Season>,
+    ) -> Result<()> {
+        require!(!self.settled, CayedError::GameAlreadySettled);
+        self.settled = true;
+
+        profile_1.record(self, config)?;
+        profile_2.record(self, config)?;
+        PlayerProfile::rate(profile_1, profile_2, self);
+
+        if let Some(id) = config.season {
+            let season = season.ok_or(CayedError::SeasonRequired)?;
+            require!(season.id == id, CayedError::SeasonRequired);
+            season.record(profile_1, profile_2, self, Clock::get()?.slot);
+        }
+
+        Ok(())
+    }
+
+    /// Wager for a rematch: the same stake, or the winner's whole payout when playing
+    /// double or nothing.
+    pub fn rematch_wager(&self, double_or_nothing: bool, config: &Config) -> Result<u64> {
+        if !double_or_nothing {
+            return Ok(self.wager);
+        }
+        let (to_player_1, to_player_2) = self.payouts(config)?;
+        Ok(to_player_1.max(to_player_2))
+    }
+}
+
+/// Terms of a proposed rematch. `deposit` is what the proposer staked up front and gets
+/// back if the game is settled instead.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub struct RematchOffer {
+    pub from: Pubkey,
+    /// Stake the winner's payout instead of taking it.
+    pub double_or_nothing: bool,
+    pub deposit: u64,
+}
+
+/// Seconds both players get to reveal their coin flip secret once the second one is seated.
+pub const COIN_FLIP_REVEAL_SECS: i64 = 600;
+
+/// Restricts who can join a game.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub enum Invite {
+    Player(Pubkey),
+    /// sha256 of a code shared out of band.
+    CodeHash([u8; 32]),
+}
+
+impl Invite {
+    pub fn admits(&self, player: &Pubkey, code: Option<&[u8]>) -> bool {
+        match self {
+            Self::Player(invited) => invited == player,
+            Self::CodeHash(hash) => code.is_some_and(|code| hashv(&[code]).to_bytes() == *hash),
+        }
+    }
+}
+
+/// Both players commit to `sha256(secret || player)` when seating themselves; once both
+/// secrets are revealed their XOR picks who moves first.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub struct CoinFlip {
+    pub commitment_player_1: [u8; 32],
+    pub commitment_player_2: [u8; 32],
+    pub secret_player_1: Option<[u8; 32]>,
+    pub secret_player_2: Option<[u8; 32]>,
+    /// After this a player who revealed can claim the game from one who hasn't, so the
+    /// second revealer can't sit on a lost flip.
+    pub reveal_deadline: i64,
+}
+
+impl CoinFlip {
+    pub fn new(commitment_player_1: [u8; 32]) -> Self {
+        Self {
+            commitment_player_1,
+            commitment_player_2: [0; 32],
+            secret_player_1: None,
+            secret_player_2: None,
+            reveal_deadline: 0,
+        }
+    }
+
+    // The player's key is hashed in so nobody can copy their opponent's commitment
+    pub fn commitment(secret: &[u8; 32], player: &Pubkey) -> [u8; 32] {
+        hashv(&[secret, player.as_ref()]).to_bytes()
+    }
+
+    /// Whether a player has revealed their secret.
+    pub fn revealed(&self, player_1: bool) -> bool {
+        if player_1 {
+            self.secret_player_1.is_some()
+        } else {
+            self.secret_player_2.is_some()
+        }
+    }
+
+    /// Whether player 1 moves first, once both secrets are in.
+    pub fn player_1_first(&self) -> Option<bool> {
+        match (self.secret_player_1, self.secret_player_2) {
+            (Some(s1), Some(s2)) => Some((s1[0] ^ s2[0]) & 1 == 0),
+            _ => None,
+        }
+    }
+}
+
+/// Optional rules chosen at creation.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Default)]
+pub struct GameSettings {
+    /// Mine cells each player hides; hitting one costs the attacker their next turn.
+    pub mines: u8,
+    /// Decoy cells each player hides; they report a hit but never sink anything.
+    pub decoys: u8,
+    /// Sonar sweeps per player; each reveals how many ship cells lie in a 3x3 area, decoys included.
+    pub sonar_charges: u8,
+    /// Torpedoes per player; each runs along a row until it strikes something.
+    pub torpedo_charges: u8,
+    /// Chess clock: seconds each player gets for the whole game; zero plays untimed.
+    pub time_bank_secs: u32,
+    /// Seconds added to a player's clock after each of their moves.
+    pub increment_secs: u32,
+    pub handicap: Handicap,
+    /// Limited-ammo mode: shots per player, after which the most hits wins; zero plays to the last ship.
+    pub ammo: u8,
+    /// Naval maneuvers: a player may spend a turn moving an undamaged ship one cell, up to
+    /// `MAX_MANEUVERS` times.
+    pub maneuvers: bool,
+    /// Rated game: settling it moves both players' Elo ratings.
+    pub ranked: bool,
+}
+
+/// Evens out a mentoring game. `player_1` names the seat the handicap applies to.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Default)]
+pub enum Handicap {
+    #[default]
+    None,
+    /// The stronger player hides one extra single-cell ship.
+    ExtraShip { player_1: bool },
+    /// The weaker player fires `shots` extra shots every turn.
+    BonusShots { player_1: bool, shots: u8 },
+    /// The weaker player gets one extra sonar sweep.
+    FreeSonar { player_1: bool },
+}
+
+impl Handicap {
+    pub fn extra_ships(&self, player_1: bool) -> u8 {
+        match self {
+            Self::ExtraShip { player_1: p } if *p == player_1 => 1,
+            _ => 0,
+        }
+    }
+
+    pub fn bonus_shots(&self, player_1: bool) -> u8 {
+        match self {
+            Self::BonusShots { player_1: p, shots } if *p == player_1 => *shots,
+            _ => 0,
+        }
+    }
+
+    pub fn free_sonars(&self, player_1: bool) -> u8 {
+        match self {
+            Self::FreeSonar { player_1: p } if *p == player_1 => 1,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub struct Charges {
+    pub sonar: u8,
+    pub torpedo: u8,
+    pub maneuver: u8,
+}
+
+impl GameSettings {
+    pub fn validate(&self, grid_size: u8) -> Result<()> {
+        // Traps share the board with the fleet, so keep them to a handful
+        require!(
+            self.mines <= grid_size / 2 && self.decoys <= grid_size / 2,
+            CayedError::TooManyTraps
+        );
+        require!(
+            self.cells_needed(grid_size) <= board_mask(grid_size).count_ones(),
+            CayedError::BoardTooCrowded
+        );
+        require!(
+            self.sonar_charges <= MAX_CHARGES && self.torpedo_charges <= MAX_CHARGES,
+            CayedError::TooManyCharges
+        );
+        require!(
+            self.time_bank_secs > 0 || self.increment_secs == 0,
+            CayedError::InvalidTimeControl
+        );
+        let valid_handicap = match self.handicap {
+            // A fleet tops out at 5 ships
+            Handicap::ExtraShip { .. } => grid_size / 2 < 5,
+            Handicap::BonusShots { shots, .. } => (1..=3).contains(&shots),
+            Handicap::None | Handicap::FreeSonar { .. } => true,
+        };
+        require!(valid_handicap, CayedError::InvalidHandicap);
+        require!(
+            !self.ranked || self.handicap == Handicap::None,
+            CayedError::RankedHandicap
+        );
+        require!(
+            self.ammo as u32 <= grid_size as u32 * (grid_size / 2) as u32,
+            CayedError::TooMuchAmmo
+        );
+        Ok(())
+    }
+
+    /// Open cells a player's board needs at the least: one per ship, a handicap's extra
+    /// ship included, plus one per mine and decoy.
+    pub fn cells_needed(&self, grid_size: u8) -> u32 {
+        let ships = grid_size / 2
+            + self
+                .handicap
+                .extra_ships(true)
+                .max(self.handicap.extra_ships(false));
+        ships as u32 + self.mines as u32 + self.decoys as u32
+    }
+}
+
+impl Charges {
+    pub fn new(settings: &GameSettings, player_1: bool) -> Self {
+        Self {
+            sonar: settings.sonar_charges + settings.handicap.free_sonars(player_1),
+            torpedo: settings.torpedo_charges,
+            maneuver: if settings.maneuvers { MAX_MANEUVERS } else { 0 },
+        }
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
 pub struct ShipCoordinates {
     pub start_x: u8,
@@ -27,12 +534,60 @@ pub struct ShipCoordinates {
     pub end_y: u8,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub struct Cell {
+    pub x: u8,
+    pub y: u8,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
 pub struct MoveResult {
+    pub shooter: Pubkey,
     pub x: u8,
     pub y: u8,
-    pub is_hit: bool,
-    // pub made_by_player1: bool,
+    pub outcome: MoveOutcome,
+    /// Index into the opponent's fleet of the ship this move sank, if any.
+    pub sunk_ship: Option<u8>,
+    pub slot: u64,
+    pub unix_timestamp: i64,
+}
+
+/// What a turn did. `x`/`y` on the `MoveResult` are the cell fired at, the sonar
+/// centre, or the cell where a torpedo stopped.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub enum MoveOutcome {
+    Shot {
+        result: ShotResult,
+    },
+    Sonar {
+        /// Ship cells in the swept area, decoys included.
+        ship_cells: u8,
+    },
+    /// Torpedo launched along row `y`; every open cell before `x` was struck as a miss.
+    Torpedo {
+        from_east: bool,
+        result: ShotResult,
+    },
+    /// A ship moved one cell. Which ship and where stays private, so `x`/`y` are zero.
+    Maneuver,
+}
+
+/// Direction a ship moves in a maneuver. North is towards row 0.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum Heading {
+    North,
+    South,
+    East,
+    West,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub enum ShotResult {
+    Miss,
+    /// A ship or a decoy was hit; the two are indistinguishable to the attacker.
+    Hit,
+    /// A mine was hit; the attacker loses their next turn.
+    Mine,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
@@ -41,7 +596,167 @@ pub enum GameStatus {
     HidingShips,
     InProgress,
     Cancelled,
-    Completed { winner: Pubkey },
-    Forfeited { winner: Pubkey },
-    WinnerRevealed { winner: Pubkey },
+    Completed {
+        winner: Pubkey,
+    },
+    Forfeited {
+        winner: Pubkey,
+    },
+    /// Both players fired all their ammunition; `None` is a draw.
+    OutOfAmmo {
+        winner: Option<Pubkey>,
+    },
+    /// A limited-ammo game ended level, with the boards revealed.
+    Drawn,
+    WinnerRevealed {
+        winner: Pubkey,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timed_game(time_bank_secs: u32, increment_secs: u32) -> Game {
+        let mut game = Game::new(
+            0,
+            4,
+            Pubkey::new_unique(),
+            0,
+            GameSettings {
+                time_bank_secs,
+                increment_secs,
+                ..GameSettings::default()
+            },
+            0,
+        );
+        game.player_2 = Some(Pubkey::new_unique());
+        game.first_move_decided = true;
+        game
+    }
+
+    #[test]
+    fn traps_must_fit_beside_the_fleet() {
+        let settings = GameSettings {
+            mines: 1,
+            decoys: 1,
+            ..GameSettings::default()
+        };
+        assert!(settings.validate(2).is_err());
+        assert!(settings.validate(4).is_ok());
+        assert_eq!(settings.cells_needed(4), 4);
+    }
+
+    #[test]
+    fn the_clock_waits_for_both_fleets() {
+        let mut game = timed_game(60, 0);
+        game.ships_hidden(true, 100);
+        assert_eq!(game.time_left(130), None);
+
+        game.ships_hidden(false, 110);
+        assert_eq!(game.turn_started_at, 110);
+        assert_eq!(game.time_left(130), Some(40));
+    }
+
+    #[test]
+    fn an_untimed_game_has_no_clock() {
+        let mut game = timed_game(0, 5);
+        game.ships_hidden(true, 100);
+        game.ships_hidden(false, 100);
+        assert_eq!(game.time_left(200), None);
+
+        game.end_turn(true, 200);
+        assert_eq!(game.time_left_player_1, 0);
+        assert_eq!(game.turn_started_at, 0);
+    }
+
+    #[test]
+    fn a_move_spends_the_bank_and_earns_the_increment() {
+        let mut game = timed_game(60, 5);
+        game.ships_hidden(true, 100);
+        game.ships_hidden(false, 100);
+
+        game.end_turn(true, 120);
+        assert_eq!(game.time_left_player_1, 45);
+        assert_eq!(game.time_left_player_2, 60);
+        assert!(!game.next_move_player_1);
+        // The opponent's clock starts from the move
+        assert_eq!(game.time_left(150), Some(30));
+    }
+
+    #[test]
+    fn a_bank_runs_out_but_never_below_zero() {
+        let mut game = timed_game(60, 5);
+        game.ships_hidden(true, 100);
+        game.ships_hidden(false, 100);
+        assert_eq!(game.time_left(190), Some(-30));
+
+        // A move made on a spent bank leaves only the increment
+        game.end_turn(true, 190);
+        assert_eq!(game.time_left_player_1, 5);
+    }
+
+    #[test]
+    fn bonus_shots_keep_the_turn() {
+        let mut game = timed_game(0, 0);
+        game.settings.handicap = Handicap::BonusShots {
+            player_1: true,
+            shots: 2,
+        };
+        game.next_move_player_1 = true;
+
+        game.end_turn(true, 0);
+        game.end_turn(true, 0);
+        assert!(game.next_move_player_1);
+
+        game.end_turn(true, 0);
+        assert!(!game.next_move_player_1);
+        // The stronger player gets a single shot
+        game.end_turn(false, 0);
+        assert!(game.next_move_player_1);
+    }
+
+    #[test]
+    fn a_free_sonar_goes_to_the_weaker_player_only() {
+        let settings = GameSettings {
+            sonar_charges: 1,
+            handicap: Handicap::FreeSonar { player_1: false },
+            ..GameSettings::default()
+        };
+        let game = Game::new(0, 4, Pubkey::new_unique(), 0, settings, 0);
+        assert_eq!(game.charges_player_1.sonar, 1);
+        assert_eq!(game.charges_player_2.sonar, 2);
+    }
+
+    #[test]
+    fn shots_run_out_and_the_turn_skips_an_empty_magazine() {
+        let mut game = timed_game(0, 0);
+        game.settings.ammo = 2;
+        game.next_move_player_1 = true;
+
+        game.spend_shot(true);
+        game.end_turn(true, 0);
+        game.spend_shot(false);
+        game.end_turn(false, 0);
+        game.spend_shot(true);
+        game.end_turn(true, 0);
+        assert!(game.out_of_ammo(true));
+        assert!(!game.ammo_spent());
+
+        // Player 1 has nothing left to fire, so player 2 keeps the turn
+        game.spend_shot(false);
+        assert!(game.ammo_spent());
+        game.end_turn(false, 0);
+        assert!(!game.next_move_player_1);
+    }
+
+    #[test]
+    fn unlimited_ammo_never_runs_out() {
+        let mut game = timed_game(0, 0);
+        for _ in 0..100 {
+            game.spend_shot(true);
+        }
+        assert_eq!(game.shots_fired_player_1, 0);
+        assert!(!game.out_of_ammo(true));
+    }
 }
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state::GameSettings;
+
+/// A best-of-N series of `Game`s between the same two players. The wager is held
+/// once for the whole series and paid out when one player reaches `wins_required`.
+#[account]
+#[derive(InitSpace)]
+pub struct Match {
+    pub id: u64,
+    pub player_1: Pubkey,
+    pub player_2: Option<Pubkey>,
+    pub grid_size: u8,
+    pub settings: GameSettings,
+    /// Per-player stake for the whole series; individual games carry no wager.
+    pub wager: u64,
+    pub wins_required: u8,
+    pub wins_player_1: u8,
+    pub wins_player_2: u8,
+    /// Ids of the series' games in play order.
+    #[max_len(9)]
+    pub games: Vec<u64>,
+    /// Winner of each finished game, index-aligned with `games`.
+    #[max_len(9)]
+    pub winners: Vec<Pubkey>,
+    /// Who opens the next game; flips with every game started, replays included.
+    pub next_opener_player_1: bool,
+    pub status: MatchStatus,
+    pub bump: u8,
+}
+
+impl Match {
+    /// True while the latest game has been started but its result not yet recorded.
+    #[inline]
+    pub fn game_in_progress(&self) -> bool {
+        self.games.len() > self.winners.len()
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub enum MatchStatus {
+    AwaitingPlayerTwo,
+    InProgress,
+    Completed { winner: Pubkey },
+    Cancelled,
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// A shareable board layout. Blocked cells are terrain (islands, reefs) where
+/// ships cannot be placed and attacks are rejected.
+#[account]
+#[derive(InitSpace)]
+pub struct Map {
+    pub id: u64,
+    pub creator: Pubkey,
+    /// Board width; height is `grid_size / 2` as for every board.
+    pub grid_size: u8,
+    /// Blocked cells, using the same bit layout as `cell_bit`.
+    pub blocked_mask: u64,
+    pub bump: u8,
+}
@@ -4,6 +4,8 @@ use anchor_lang::prelude::*;
 pub enum AccountType {
     Game { game_id: u64 },
     PlayerBoard { game_id: u64, player: Pubkey },
+    MultiGame { game_id: u64 },
+    MultiPlayerBoard { game_id: u64, player: Pubkey },
 }
 
 impl AccountType {
@@ -19,6 +21,16 @@ impl AccountType {
                     player.to_bytes().to_vec(),
                 ]
             }
+            Self::MultiGame { game_id } => {
+                vec![b"multi_game".to_vec(), game_id.to_le_bytes().to_vec()]
+            }
+            Self::MultiPlayerBoard { game_id, player } => {
+                vec![
+                    b"multi_player".to_vec(),
+                    game_id.to_le_bytes().to_vec(),
+                    player.to_bytes().to_vec(),
+                ]
+            }
         }
     }
 }
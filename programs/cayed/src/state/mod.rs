@@ -1,11 +1,33 @@
+pub mod challenge;
 pub mod config;
+pub mod escrow;
 pub mod game;
+pub mod game_match;
+pub mod map;
 pub mod mb_helpers;
+pub mod multi_game;
+pub mod open_games;
 pub mod player_board;
+pub mod player_games;
+pub mod player_profile;
+pub mod queue;
+pub mod season;
+pub mod tournament;
 pub mod vault;
 
+pub use challenge::*;
 pub use config::*;
+pub use escrow::*;
 pub use game::*;
+pub use game_match::*;
+pub use map::*;
 pub use mb_helpers::*;
+pub use multi_game::*;
+pub use open_games::*;
 pub use player_board::*;
+pub use player_games::*;
+pub use player_profile::*;
+pub use queue::*;
+pub use season::*;
+pub use tournament::*;
 pub use vault::*;
@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{GameStatus, ShipCoordinates};
+
+/// A free-for-all game for 3-4 players, or a 2v2 team game. Each player has a
+/// regular `PlayerBoard`, turns rotate by seat and the attacker picks which
+/// opponent to fire at.
+/// Ids come from `Config.game_counter`; boards live at `["multi_player", id, player]`.
+#[account]
+#[derive(InitSpace)]
+pub struct MultiGame {
+    pub id: u64,
+    pub grid_size: u8,
+    /// Seats to fill (3 or 4) before ships can be hidden.
+    pub max_players: u8,
+    /// Two teams of two: even seats against odd seats, sharing the pot per team.
+    pub teams: bool,
+    /// Seated players; seat order is turn order.
+    #[max_len(4)]
+    pub players: Vec<Pubkey>,
+    /// Bit `i` set once `players[i]` has hidden their fleet.
+    pub ships_placed_mask: u8,
+    /// Bit `i` set once `players[i]`'s fleet is fully sunk.
+    pub eliminated_mask: u8,
+    /// Seat whose turn it is; meaningless until `first_move_decided`.
+    pub turn: u8,
+    /// Set once the VRF has drawn the opening seat.
+    pub first_move_decided: bool,
+    #[max_len(20)]
+    pub revealed_ships: Vec<RevealedShip>,
+    // Every cell on up to four boards
+    #[max_len(200)]
+    pub moves: Vec<MultiMoveResult>,
+    /// Per-player stake; the last fleet (or team) afloat takes the whole pot.
+    pub wager: u64,
+    /// Bit `i` set once `players[i]` has claimed their share of the pot.
+    pub claimed_mask: u8,
+    pub status: GameStatus,
+    pub bump: u8,
+}
+
+impl MultiGame {
+    pub fn seat_of(&self, player: &Pubkey) -> Option<u8> {
+        self.players
+            .iter()
+            .position(|p| p == player)
+            .map(|seat| seat as u8)
+    }
+
+    #[inline]
+    pub fn is_eliminated(&self, seat: u8) -> bool {
+        (self.eliminated_mask >> seat) & 1 == 1
+    }
+
+    #[inline]
+    pub fn team_of(seat: u8) -> u8 {
+        seat % 2
+    }
+
+    /// True once a team has lost both boards, or a single fleet is left afloat.
+    pub fn is_decided(&self) -> bool {
+        if self.teams {
+            return (0..2).any(|team| self.is_eliminated(team) && self.is_eliminated(team + 2));
+        }
+        let seats = self.players.len() as u8;
+        (0..seats).filter(|&seat| !self.is_eliminated(seat)).count() == 1
+    }
+
+    /// Whether `seat` shares in a win by `winner` (themselves, or their teammate).
+    pub fn on_winning_side(&self, seat: u8, winner: &Pubkey) -> bool {
+        match self.seat_of(winner) {
+            Some(winning_seat) if self.teams => Self::team_of(seat) == Self::team_of(winning_seat),
+            Some(winning_seat) => seat == winning_seat,
+            None => false,
+        }
+    }
+
+    /// Hand the first turn to a seat picked by `randomness`.
+    pub fn draw_opener(&mut self, randomness: [u8; 32]) {
+        let draw = u64::from_le_bytes(randomness[..8].try_into().unwrap());
+        self.turn = (draw % self.players.len() as u64) as u8;
+        self.first_move_decided = true;
+    }
+
+    /// Rotate the turn to the next seat that still has ships afloat.
+    pub fn advance_turn(&mut self) {
+        if self.teams {
+            self.turn = self.next_team_seat();
+            return;
+        }
+        let seats = self.players.len() as u8;
+        for step in 1..=seats {
+            let seat = (self.turn + step) % seats;
+            if !self.is_eliminated(seat) {
+                self.turn = seat;
+                return;
+            }
+        }
+    }
+
+    /// Team games alternate between teams and rotate between teammates: the other
+    /// team's member who did not shoot last goes next, unless their fleet is sunk.
+    fn next_team_seat(&self) -> u8 {
+        let team = 1 - Self::team_of(self.turn);
+        let preferred = self
+            .moves
+            .iter()
+            .rev()
+            .find(|m| Self::team_of(m.shooter) == team)
+            .map_or(team, |m| (m.shooter + 2) % 4);
+        if self.is_eliminated(preferred) {
+            (preferred + 2) % 4
+        } else {
+            preferred
+        }
+    }
+}
+
+/// A sunk ship made public, tagged with the seat that owned it.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub struct RevealedShip {
+    pub seat: u8,
+    pub ship: ShipCoordinates,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub struct MultiMoveResult {
+    pub shooter: u8,
+    pub target: u8,
+    pub x: u8,
+    pub y: u8,
+    pub is_hit: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn team_game(turn: u8) -> MultiGame {
+        MultiGame {
+            id: 0,
+            grid_size: 4,
+            max_players: 4,
+            teams: true,
+            players: (0..4).map(|_| Pubkey::new_unique()).collect(),
+            ships_placed_mask: 0b1111,
+            eliminated_mask: 0,
+            turn,
+            first_move_decided: true,
+            revealed_ships: vec![],
+            moves: vec![],
+            wager: 0,
+            claimed_mask: 0,
+            status: GameStatus::InProgress,
+            bump: 0,
+        }
+    }
+
+    // Record a miss by the seat on turn and pass the turn on
+    fn shoot(game: &mut MultiGame) -> u8 {
+        let shooter = game.turn;
+        game.moves.push(MultiMoveResult {
+            shooter,
+            target: (shooter + 1) % 4,
+            x: 0,
+            y: 0,
+            is_hit: false,
+        });
+        game.advance_turn();
+        shooter
+    }
+
+    #[test]
+    fn teams_alternate_and_teammates_rotate() {
+        for opener in 0..4 {
+            let mut game = team_game(opener);
+            let shooters: Vec<u8> = (0..8).map(|_| shoot(&mut game)).collect();
+
+            for pair in shooters.windows(2) {
+                assert_ne!(MultiGame::team_of(pair[0]), MultiGame::team_of(pair[1]));
+            }
+            // Every seat shoots once per four turns
+            let mut first_round = shooters[..4].to_vec();
+            first_round.sort();
+            assert_eq!(first_round, vec![0, 1, 2, 3]);
+            assert_eq!(shooters[..4], shooters[4..]);
+        }
+    }
+
+    #[test]
+    fn a_sunk_teammate_hands_every_turn_to_the_other() {
+        let mut game = team_game(0);
+        game.eliminated_mask = 1 << 3;
+
+        let shooters: Vec<u8> = (0..6).map(|_| shoot(&mut game)).collect();
+        assert_eq!(shooters, vec![0, 1, 2, 1, 0, 1]);
+    }
+
+    #[test]
+    fn the_drawn_opener_is_any_seated_player() {
+        let mut game = team_game(0);
+        game.teams = false;
+        game.players.truncate(3);
+
+        let mut drawn = [false; 3];
+        for byte in 0..=255u8 {
+            game.first_move_decided = false;
+            game.draw_opener([byte; 32]);
+            assert!(game.first_move_decided);
+            drawn[game.turn as usize] = true;
+        }
+        assert_eq!(drawn, [true; 3]);
+    }
+
+    #[test]
+    fn a_team_loses_once_both_boards_are_sunk() {
+        let mut game = team_game(0);
+        game.eliminated_mask = (1 << 1) | (1 << 2);
+        assert!(!game.is_decided());
+
+        game.eliminated_mask |= 1 << 3;
+        assert!(game.is_decided());
+
+        // Both members of the winning team share the win, even with a sunk fleet
+        let winner = game.players[0];
+        assert!(game.on_winning_side(0, &winner));
+        assert!(game.on_winning_side(2, &winner));
+        assert!(!game.on_winning_side(1, &winner));
+        assert!(!game.on_winning_side(3, &winner));
+    }
+}
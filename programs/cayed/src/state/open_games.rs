@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+
+/// One page of games still waiting for a second player, so lobbies can list them by
+/// reading `Config.open_games_pages` accounts instead of scanning every `Game`.
+#[account]
+#[derive(InitSpace)]
+pub struct OpenGames {
+    pub page: u32,
+    #[max_len(32)]
+    pub entries: Vec<OpenGame>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct OpenGame {
+    pub id: u64,
+    pub creator: Pubkey,
+    pub grid_size: u8,
+    pub wager: u64,
+    /// Only the invited player can join.
+    pub invite_only: bool,
+}
+
+impl OpenGames {
+    pub const MAX_ENTRIES: usize = 32;
+
+    pub fn list(&mut self, entry: OpenGame) -> Result<()> {
+        require!(
+            self.entries.len() < Self::MAX_ENTRIES,
+            CayedError::OpenGamesPageFull
+        );
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    pub fn unlist(&mut self, id: u64) -> Result<()> {
+        let position = self
+            .entries
+            .iter()
+            .position(|entry| entry.id == id)
+            .ok_or(CayedError::GameNotListed)?;
+        // Oldest games stay first
+        self.entries.remove(position);
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::state::ShipCoordinates;
+use crate::errors::CayedError;
+use crate::state::{Heading, ShipCoordinates, ShotResult};
 
 /// Encode cell (x, y) into a single-bit `u64` mask for the grid bitmap.
 /// Each player's board is `grid_size` wide × `grid_size / 2` tall.
@@ -10,6 +11,173 @@ pub fn cell_bit(x: u8, y: u8, grid_size: u8) -> u64 {
     1u64 << (y as u32 * grid_size as u32 + x as u32)
 }
 
+/// Mask of every cell on a `grid_size` × `grid_size / 2` board.
+#[inline]
+pub fn board_mask(grid_size: u8) -> u64 {
+    let cells = grid_size as u32 * (grid_size as u32 / 2);
+    (1u64 << cells) - 1
+}
+
+/// Validate a fleet layout and return each ship's mask plus their union.
+/// Ships must run start <= end, be linear, stay in bounds, not overlap and avoid blocked cells.
+pub fn fleet_masks(
+    ships: &[ShipCoordinates],
+    grid_size: u8,
+    blocked_mask: u64,
+) -> Result<(Vec<u64>, u64)> {
+    let half = grid_size / 2;
+
+    let mut ship_masks: Vec<u64> = Vec::with_capacity(ships.len());
+    let mut all_ships_mask: u64 = 0;
+
+    for ship in ships {
+        // Ships must have start <= end
+        require!(
+            ship.start_x <= ship.end_x && ship.start_y <= ship.end_y,
+            CayedError::ShipCoordsReversed
+        );
+
+        // Ships must be linear (horizontal or vertical, not rectangular)
+        require!(
+            ship.start_x == ship.end_x || ship.start_y == ship.end_y,
+            CayedError::ShipNotLinear
+        );
+
+        // Bounds check (since start <= end, only need to check end)
+        require!(
+            ship.end_x < grid_size && ship.end_y < half,
+            CayedError::InvalidShipPlacement
+        );
+
+        // Compute bitmask for this ship and check for overlaps
+        let mut mask: u64 = 0;
+        for x in ship.start_x..=ship.end_x {
+            for y in ship.start_y..=ship.end_y {
+                let bit = cell_bit(x, y, grid_size);
+                require!((all_ships_mask & bit) == 0, CayedError::ShipOverlap);
+                mask |= bit;
+            }
+        }
+        require!((mask & blocked_mask) == 0, CayedError::ShipOnBlockedCell);
+        all_ships_mask |= mask;
+        ship_masks.push(mask);
+    }
+
+    Ok((ship_masks, all_ships_mask))
+}
+
+/// Small xorshift stream seeded from VRF output; plenty for laying out one board.
+pub struct BoardRng(u64);
+
+impl BoardRng {
+    pub fn new(randomness: &[u8; 32]) -> Self {
+        let seed = randomness.chunks_exact(8).fold(0u64, |acc, c| {
+            acc ^ u64::from_le_bytes(c.try_into().unwrap())
+        });
+        // xorshift never leaves zero
+        Self(if seed == 0 {
+            0x9E37_79B9_7F4A_7C15
+        } else {
+            seed
+        })
+    }
+
+    /// Uniform-enough value in `0..n`; `n` must be non-zero.
+    pub fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+// Restarts before giving up on a crowded map
+const PLACEMENT_ATTEMPTS: usize = 8;
+
+/// Lay out a random fleet of `grid_size / 2` ships sized `grid_size / 2` down to 1,
+/// plus `extra_ships` single cells, avoiding `blocked_mask`. The result still goes
+/// through `fleet_masks`.
+pub fn random_fleet(
+    rng: &mut BoardRng,
+    grid_size: u8,
+    blocked_mask: u64,
+    extra_ships: u8,
+) -> Result<Vec<ShipCoordinates>> {
+    let half = grid_size / 2;
+    let lengths = || (1..=half).rev().chain((0..extra_ships).map(|_| 1));
+
+    'attempt: for _ in 0..PLACEMENT_ATTEMPTS {
+        let mut occupied = blocked_mask;
+        let mut ships = Vec::with_capacity((half + extra_ships) as usize);
+
+        // Longest first, while there is still room for it
+        for len in lengths() {
+            let mut options: Vec<(ShipCoordinates, u64)> = vec![];
+            for y in 0..half {
+                for x in 0..grid_size {
+                    let horizontal = ShipCoordinates {
+                        start_x: x,
+                        start_y: y,
+                        end_x: x + len - 1,
+                        end_y: y,
+                    };
+                    let vertical = ShipCoordinates {
+                        start_x: x,
+                        start_y: y,
+                        end_x: x,
+                        end_y: y + len - 1,
+                    };
+                    for ship in [horizontal, vertical] {
+                        if ship.end_x >= grid_size || ship.end_y >= half {
+                            continue;
+                        }
+                        let mut mask = 0;
+                        for cx in ship.start_x..=ship.end_x {
+                            for cy in ship.start_y..=ship.end_y {
+                                mask |= cell_bit(cx, cy, grid_size);
+                            }
+                        }
+                        if mask & occupied == 0 {
+                            options.push((ship, mask));
+                        }
+                        // A single cell is the same ship either way
+                        if len == 1 {
+                            break;
+                        }
+                    }
+                }
+            }
+            if options.is_empty() {
+                continue 'attempt;
+            }
+            let (ship, mask) = options.swap_remove(rng.below(options.len()));
+            occupied |= mask;
+            ships.push(ship);
+        }
+
+        return Ok(ships);
+    }
+
+    err!(CayedError::NoRoomForFleet)
+}
+
+/// Pick `count` random cells outside `occupied` and return them as a mask.
+pub fn random_cells(rng: &mut BoardRng, count: u8, grid_size: u8, occupied: u64) -> Result<u64> {
+    let mut mask = 0;
+    for _ in 0..count {
+        let free = board_mask(grid_size) & !(occupied | mask);
+        let open = free.count_ones() as usize;
+        require!(open > 0, CayedError::NoRoomForFleet);
+        // Walk to the n-th free bit
+        let mut rest = free;
+        for _ in 0..rng.below(open) {
+            rest &= rest - 1;
+        }
+        mask |= rest & rest.wrapping_neg();
+    }
+    Ok(mask)
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct PlayerBoard {
@@ -28,12 +196,303 @@ pub struct PlayerBoard {
     pub hits_bitmap: u64,
     /// Per-ship sunk tracker - bit `i` set means `ship_coordinates[i]` is fully sunk.
     pub sunk_mask: u8,
+    /// Hidden mine cells - hitting one costs the attacker a turn.
+    pub mines_mask: u64,
+    /// Hidden decoy cells - report a hit but never count towards sinking.
+    pub decoys_mask: u64,
+    /// Player's secret mixed into the public VRF output for auto-placement; wiped once
+    /// the fleet is laid out.
+    pub placement_salt: [u8; 32],
 }
 
 impl PlayerBoard {
+    pub fn new(game_id: u64, player: Pubkey, bump: u8) -> Self {
+        Self {
+            game_id,
+            player,
+            bump,
+            ship_coordinates: vec![],
+            ship_masks: vec![],
+            all_ships_mask: 0,
+            hits_bitmap: 0,
+            sunk_mask: 0,
+            mines_mask: 0,
+            decoys_mask: 0,
+            placement_salt: [0; 32],
+        }
+    }
+
     /// True when every ship cell has been hit.
     #[inline]
     pub fn all_ships_sunk(&self) -> bool {
         self.all_ships_mask != 0 && (self.hits_bitmap & self.all_ships_mask) == self.all_ships_mask
     }
+
+    /// Flag every ship whose cells are now all hit and return the newly sunk indices.
+    pub fn mark_sunk_ships(&mut self) -> Vec<u8> {
+        let mut newly_sunk = vec![];
+        for (i, ship_mask) in self.ship_masks.iter().enumerate() {
+            // Skip empty masks and already-sunk ships
+            if *ship_mask == 0 || (self.sunk_mask >> i) & 1 == 1 {
+                continue;
+            }
+            if (self.hits_bitmap & ship_mask) == *ship_mask {
+                self.sunk_mask |= 1u8 << i;
+                newly_sunk.push(i as u8);
+            }
+        }
+        newly_sunk
+    }
+
+    /// Ship cells hit and ships sunk on this board, compared in that order to score
+    /// limited-ammo games.
+    pub fn damage(&self) -> (u32, u32) {
+        (
+            (self.hits_bitmap & self.all_ships_mask).count_ones(),
+            self.sunk_mask.count_ones(),
+        )
+    }
+
+    /// Move an undamaged ship one cell. The new position must stay on the board and clear
+    /// of other ships, traps, blocked cells and any cell already fired at.
+    pub fn move_ship(
+        &mut self,
+        index: u8,
+        heading: Heading,
+        grid_size: u8,
+        blocked_mask: u64,
+    ) -> Result<()> {
+        let i = index as usize;
+        require!(i < self.ship_masks.len(), CayedError::InvalidShipIndex);
+        require!(
+            self.ship_masks[i] & self.hits_bitmap == 0,
+            CayedError::ShipDamaged
+        );
+
+        let ship = &self.ship_coordinates[i];
+        let (dx, dy): (i16, i16) = match heading {
+            Heading::North => (0, -1),
+            Heading::South => (0, 1),
+            Heading::East => (1, 0),
+            Heading::West => (-1, 0),
+        };
+        let start_x = ship.start_x as i16 + dx;
+        let start_y = ship.start_y as i16 + dy;
+        let end_x = ship.end_x as i16 + dx;
+        let end_y = ship.end_y as i16 + dy;
+        require!(
+            start_x >= 0
+                && start_y >= 0
+                && end_x < grid_size as i16
+                && end_y < (grid_size / 2) as i16,
+            CayedError::IllegalManeuver
+        );
+        let moved = ShipCoordinates {
+            start_x: start_x as u8,
+            start_y: start_y as u8,
+            end_x: end_x as u8,
+            end_y: end_y as u8,
+        };
+
+        let mut mask = 0;
+        for x in moved.start_x..=moved.end_x {
+            for y in moved.start_y..=moved.end_y {
+                mask |= cell_bit(x, y, grid_size);
+            }
+        }
+        let others = self.all_ships_mask & !self.ship_masks[i];
+        let taken = others | self.mines_mask | self.decoys_mask | blocked_mask | self.hits_bitmap;
+        require!(mask & taken == 0, CayedError::IllegalManeuver);
+
+        self.ship_coordinates[i] = moved;
+        self.ship_masks[i] = mask;
+        self.all_ships_mask = others | mask;
+        Ok(())
+    }
+
+    /// What an attack on `bit` reveals publicly.
+    #[inline]
+    pub fn shot_result(&self, bit: u64) -> ShotResult {
+        if (self.mines_mask & bit) != 0 {
+            ShotResult::Mine
+        } else if ((self.all_ships_mask | self.decoys_mask) & bit) != 0 {
+            ShotResult::Hit
+        } else {
+            ShotResult::Miss
+        }
+    }
+
+    /// Cells in `area` a sonar sweep reports. Decoys answer like ship cells, as they do
+    /// when shot, so a sweep cannot tell them apart.
+    #[inline]
+    pub fn sonar_contacts(&self, area: u64) -> u8 {
+        ((self.all_ships_mask | self.decoys_mask) & area).count_ones() as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::*;
+
+    const GRID: u8 = 4;
+
+    // A 4 x 2 board with a two-cell ship on (0, 0)-(1, 0) and a single cell on (0, 1)
+    fn board() -> PlayerBoard {
+        let ships = vec![
+            ShipCoordinates {
+                start_x: 0,
+                start_y: 0,
+                end_x: 1,
+                end_y: 0,
+            },
+            ShipCoordinates {
+                start_x: 0,
+                start_y: 1,
+                end_x: 0,
+                end_y: 1,
+            },
+        ];
+        let (ship_masks, all_ships_mask) = fleet_masks(&ships, GRID, 0).unwrap();
+        let mut board = PlayerBoard::new(0, Pubkey::default(), 0);
+        board.ship_coordinates = ships;
+        board.ship_masks = ship_masks;
+        board.all_ships_mask = all_ships_mask;
+        board
+    }
+
+    fn fire(board: &mut PlayerBoard, cells: &[(u8, u8)]) {
+        for &(x, y) in cells {
+            board.hits_bitmap |= cell_bit(x, y, GRID);
+        }
+        board.mark_sunk_ships();
+    }
+
+    fn damage_after(cells: &[(u8, u8)]) -> (u32, u32) {
+        let mut board = board();
+        fire(&mut board, cells);
+        board.damage()
+    }
+
+    #[test]
+    fn damage_counts_ship_cells_hit_and_ships_sunk() {
+        let mut board = board();
+        board.decoys_mask = cell_bit(3, 1, GRID);
+        assert_eq!(board.damage(), (0, 0));
+
+        // Water and decoys don't count
+        fire(&mut board, &[(2, 0), (3, 1)]);
+        assert_eq!(board.damage(), (0, 0));
+
+        fire(&mut board, &[(0, 0)]);
+        assert_eq!(board.damage(), (1, 0));
+
+        fire(&mut board, &[(1, 0), (0, 1)]);
+        assert_eq!(board.damage(), (3, 2));
+    }
+
+    #[test]
+    fn hits_outrank_sinkings_and_level_damage_is_a_draw() {
+        let two_hits = damage_after(&[(0, 0), (1, 0)]);
+        let one_sunk = damage_after(&[(0, 1)]);
+        let one_hit = damage_after(&[(0, 0)]);
+
+        assert_eq!(two_hits.cmp(&one_sunk), Ordering::Greater);
+        // Level on hits, so the sinking decides
+        assert_eq!(one_sunk.cmp(&one_hit), Ordering::Greater);
+        assert_eq!(one_hit.cmp(&damage_after(&[(1, 0)])), Ordering::Equal);
+    }
+
+    #[test]
+    fn a_ship_moves_one_cell_into_open_water() {
+        let mut board = board();
+        board.move_ship(1, Heading::East, GRID, 0).unwrap();
+
+        assert_eq!(board.ship_coordinates[1].start_x, 1);
+        assert_eq!(board.ship_masks[1], cell_bit(1, 1, GRID));
+        assert_eq!(
+            board.all_ships_mask,
+            cell_bit(0, 0, GRID) | cell_bit(1, 0, GRID) | cell_bit(1, 1, GRID)
+        );
+    }
+
+    #[test]
+    fn a_ship_cannot_leave_the_board() {
+        let mut board = board();
+        for heading in [Heading::North, Heading::West] {
+            assert_eq!(
+                board.move_ship(0, heading, GRID, 0).unwrap_err(),
+                CayedError::IllegalManeuver.into()
+            );
+        }
+        // The single cell already sits on the bottom row
+        assert_eq!(
+            board.move_ship(1, Heading::South, GRID, 0).unwrap_err(),
+            CayedError::IllegalManeuver.into()
+        );
+    }
+
+    #[test]
+    fn a_ship_cannot_move_onto_a_fired_at_cell() {
+        let mut board = board();
+        fire(&mut board, &[(1, 1)]);
+        assert_eq!(
+            board.move_ship(1, Heading::East, GRID, 0).unwrap_err(),
+            CayedError::IllegalManeuver.into()
+        );
+    }
+
+    #[test]
+    fn a_ship_cannot_move_onto_a_trap_or_blocked_cell() {
+        let target = cell_bit(1, 1, GRID);
+
+        let mut mined = board();
+        mined.mines_mask = target;
+        let mut decoyed = board();
+        decoyed.decoys_mask = target;
+        for mut board in [mined, decoyed] {
+            assert_eq!(
+                board.move_ship(1, Heading::East, GRID, 0).unwrap_err(),
+                CayedError::IllegalManeuver.into()
+            );
+        }
+
+        let mut board = board();
+        assert_eq!(
+            board.move_ship(1, Heading::East, GRID, target).unwrap_err(),
+            CayedError::IllegalManeuver.into()
+        );
+        // Nor onto another ship
+        assert_eq!(
+            board.move_ship(1, Heading::North, GRID, 0).unwrap_err(),
+            CayedError::IllegalManeuver.into()
+        );
+    }
+
+    #[test]
+    fn a_damaged_ship_stays_put() {
+        let mut board = board();
+        fire(&mut board, &[(1, 0)]);
+        assert_eq!(
+            board.move_ship(0, Heading::East, GRID, 0).unwrap_err(),
+            CayedError::ShipDamaged.into()
+        );
+        assert_eq!(
+            board.move_ship(2, Heading::East, GRID, 0).unwrap_err(),
+            CayedError::InvalidShipIndex.into()
+        );
+        assert_eq!(board.ship_coordinates[0].end_x, 1);
+    }
+
+    #[test]
+    fn sonar_counts_a_decoy_like_a_ship_cell() {
+        let mut board = board();
+        let area = cell_bit(2, 0, GRID) | cell_bit(3, 0, GRID) | cell_bit(3, 1, GRID);
+        assert_eq!(board.sonar_contacts(area), 0);
+
+        board.decoys_mask = cell_bit(3, 1, GRID);
+        assert_eq!(board.sonar_contacts(area), 1);
+        assert!(board.shot_result(cell_bit(3, 1, GRID)) == ShotResult::Hit);
+    }
 }
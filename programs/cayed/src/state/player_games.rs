@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+
+/// Ids of a wallet's unsettled two-player games, so clients can find them without
+/// scanning. Multiplayer games are not indexed.
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerGames {
+    pub player: Pubkey,
+    #[max_len(32)]
+    pub games: Vec<u64>,
+    pub bump: u8,
+}
+
+impl PlayerGames {
+    /// Upper bound for `Config.max_active_games`, fixed by the account's space.
+    pub const MAX_GAMES: u8 = 32;
+
+    pub fn check_room(&self, cap: u8) -> Result<()> {
+        require!(
+            self.games.len() < cap as usize,
+            CayedError::TooManyActiveGames
+        );
+        Ok(())
+    }
+
+    pub fn track(&mut self, id: u64, cap: u8) -> Result<()> {
+        self.check_room(cap)?;
+        self.games.push(id);
+        Ok(())
+    }
+
+    /// Track a game the player was paired into rather than chose to start. Their cap was
+    /// checked when they signed up, so a full index skips the game instead of failing.
+    pub fn track_paired(&mut self, id: u64) {
+        if self.games.len() < Self::MAX_GAMES as usize {
+            self.games.push(id);
+        }
+    }
+
+    pub fn untrack(&mut self, id: u64) {
+        self.games.retain(|game| *game != id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_full_index_refuses_new_games_but_skips_paired_ones() {
+        let mut games = PlayerGames {
+            player: Pubkey::default(),
+            games: (0..PlayerGames::MAX_GAMES as u64).collect(),
+            bump: 0,
+        };
+        assert_eq!(
+            games.track(99, PlayerGames::MAX_GAMES).unwrap_err(),
+            CayedError::TooManyActiveGames.into()
+        );
+
+        games.track_paired(99);
+        assert_eq!(games.games.len(), PlayerGames::MAX_GAMES as usize);
+        assert!(!games.games.contains(&99));
+
+        games.untrack(0);
+        games.track_paired(99);
+        assert_eq!(games.games.last(), Some(&99));
+    }
+}
@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Config, Game, MoveOutcome, ShotResult};
+
+/// Lifetime statistics for a wallet, updated whenever one of its games is settled.
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerProfile {
+    pub player: Pubkey,
+    pub games_played: u32,
+    pub games_won: u32,
+    pub games_lost: u32,
+    pub games_drawn: u32,
+    /// Games lost on time.
+    pub games_forfeited: u32,
+    pub total_wagered: u64,
+    /// Payouts from games won, after fees.
+    pub total_won: u64,
+    pub shots: u32,
+    /// Shots that struck a ship or a decoy; the two look the same to the shooter.
+    pub hits: u32,
+    pub ships_sunk: u32,
+    /// Fewest moves the player needed to win a game; zero until their first win.
+    pub fastest_win: u16,
+    /// Elo rating from ranked games.
+    pub rating: u16,
+    pub ranked_games: u32,
+    /// Season `season_wins` counts towards.
+    pub season: u64,
+    pub season_wins: u32,
+    pub bump: u8,
+}
+
+/// Expected score in basis points at rating gaps of 0, 25, ..., 800 points.
+const EXPECTED_SCORE_BPS: [u16; 33] = [
+    5000, 5359, 5715, 6063, 6401, 6725, 7034, 7325, 7597, 7850, 8083, 8296, 8490, 8666, 8823, 8965,
+    9091, 9203, 9302, 9390, 9468, 9536, 9595, 9648, 9693, 9733, 9768, 9799, 9825, 9848, 9868, 9886,
+    9901,
+];
+
+impl PlayerProfile {
+    pub const START_RATING: u16 = 1200;
+    pub const MIN_RATING: u16 = 100;
+    /// Ranked games played at the faster provisional K-factor.
+    pub const PROVISIONAL_GAMES: u32 = 30;
+
+    /// Fill in a profile created at settlement; existing profiles are left alone.
+    pub fn open(&mut self, player: Pubkey, bump: u8) {
+        if self.player == Pubkey::default() {
+            self.player = player;
+            self.rating = Self::START_RATING;
+            self.bump = bump;
+        }
+    }
+
+    /// Fold a settled game into the player's statistics.
+    pub fn record(&mut self, game: &Game, config: &Config) -> Result<()> {
+        let player = self.player;
+        self.games_played += 1;
+        self.total_wagered += game.wager;
+
+        let mut moves = 0u16;
+        for result in game.moves.iter().filter(|result| result.shooter == player) {
+            moves += 1;
+            let shot = match &result.outcome {
+                MoveOutcome::Shot { result } | MoveOutcome::Torpedo { result, .. } => result,
+                MoveOutcome::Sonar { .. } | MoveOutcome::Maneuver => continue,
+            };
+            self.shots += 1;
+            if *shot == ShotResult::Hit {
+                self.hits += 1;
+            }
+            if result.sunk_ship.is_some() {
+                self.ships_sunk += 1;
+            }
+        }
+
+        match game.winner() {
+            Some(winner) if winner == player => {
+                self.games_won += 1;
+                let (to_player_1, to_player_2) = game.payouts(config)?;
+                self.total_won += to_player_1.max(to_player_2);
+                if self.fastest_win == 0 || moves < self.fastest_win {
+                    self.fastest_win = moves;
+                }
+            }
+            Some(_) => {
+                self.games_lost += 1;
+                if game.forfeit {
+                    self.games_forfeited += 1;
+                }
+            }
+            None => self.games_drawn += 1,
+        }
+
+        Ok(())
+    }
+
+    /// Start counting wins afresh when the player's first game of a season settles.
+    pub fn enter_season(&mut self, season: u64) {
+        if self.season != season {
+            self.season = season;
+            self.season_wins = 0;
+        }
+    }
+
+    /// Move both players' ratings after a settled game; unranked games leave them be.
+    pub fn rate(player_1: &mut Self, player_2: &mut Self, game: &Game) {
+        if !game.settings.ranked {
+            return;
+        }
+        // Player 1's score in basis points
+        let score = match game.winner() {
+            Some(winner) if winner == game.player_1 => 10_000,
+            Some(_) => 0,
+            None => 5_000,
+        };
+        let expected = expected_score_bps(player_1.rating as i32 - player_2.rating as i32);
+        let rating_1 = player_1.adjusted(score - expected);
+        let rating_2 = player_2.adjusted(expected - score);
+        player_1.rating = rating_1;
+        player_2.rating = rating_2;
+        player_1.ranked_games += 1;
+        player_2.ranked_games += 1;
+    }
+
+    fn k_factor(&self) -> i32 {
+        if self.ranked_games < Self::PROVISIONAL_GAMES {
+            40
+        } else if self.rating < 2400 {
+            20
+        } else {
+            10
+        }
+    }
+
+    /// Rating after scoring `surprise` basis points above expectation, rounded to nearest.
+    fn adjusted(&self, surprise: i32) -> u16 {
+        let change = (self.k_factor() * surprise * 2 + 10_000 * surprise.signum()) / 20_000;
+        (self.rating as i32 + change).clamp(Self::MIN_RATING as i32, u16::MAX as i32) as u16
+    }
+
+    /// Share of shots that hit, in basis points.
+    pub fn accuracy_bps(&self) -> u16 {
+        if self.shots == 0 {
+            return 0;
+        }
+        (self.hits as u64 * 10_000 / self.shots as u64) as u16
+    }
+}
+
+/// Elo expected score of the player `gap` rating points ahead, in basis points, read off
+/// `EXPECTED_SCORE_BPS` with linear interpolation.
+fn expected_score_bps(gap: i32) -> i32 {
+    let steps = gap.unsigned_abs().min(800) as usize;
+    let (index, within) = (steps / 25, (steps % 25) as i32);
+    let low = EXPECTED_SCORE_BPS[index] as i32;
+    let high = EXPECTED_SCORE_BPS[(index + 1).min(32)] as i32;
+    let ahead = low + (high - low) * within / 25;
+    if gap < 0 {
+        10_000 - ahead
+    } else {
+        ahead
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{GameSettings, GameStatus};
+
+    fn profile(rating: u16, ranked_games: u32) -> PlayerProfile {
+        PlayerProfile {
+            player: Pubkey::new_unique(),
+            games_played: ranked_games,
+            games_won: 0,
+            games_lost: 0,
+            games_drawn: 0,
+            games_forfeited: 0,
+            total_wagered: 0,
+            total_won: 0,
+            shots: 0,
+            hits: 0,
+            ships_sunk: 0,
+            fastest_win: 0,
+            rating,
+            ranked_games,
+            season: 0,
+            season_wins: 0,
+            bump: 0,
+        }
+    }
+
+    // A settled game between the two profiles; `None` is a draw
+    fn game(player_1: &PlayerProfile, winner: Option<Pubkey>, ranked: bool) -> Game {
+        let settings = GameSettings {
+            ranked,
+            ..GameSettings::default()
+        };
+        let mut game = Game::new(0, 4, player_1.player, 0, settings, 0);
+        game.status = match winner {
+            Some(winner) => GameStatus::WinnerRevealed { winner },
+            None => GameStatus::Drawn,
+        };
+        game
+    }
+
+    #[test]
+    fn an_upset_moves_more_points_than_an_expected_win() {
+        let mut favourite = profile(1400, 50);
+        let mut underdog = profile(1200, 50);
+
+        let win = game(&favourite, Some(favourite.player), true);
+        PlayerProfile::rate(&mut favourite, &mut underdog, &win);
+        // 200 points ahead the favourite was expected to score 76%
+        assert_eq!((favourite.rating, underdog.rating), (1405, 1195));
+
+        let upset = game(&favourite, Some(underdog.player), true);
+        PlayerProfile::rate(&mut favourite, &mut underdog, &upset);
+        assert_eq!((favourite.rating, underdog.rating), (1390, 1210));
+        assert_eq!((favourite.ranked_games, underdog.ranked_games), (52, 52));
+    }
+
+    #[test]
+    fn provisional_players_move_twice_as_fast() {
+        let mut newcomer = profile(1200, 0);
+        let mut regular = profile(1200, PlayerProfile::PROVISIONAL_GAMES);
+
+        let win = game(&newcomer, Some(newcomer.player), true);
+        PlayerProfile::rate(&mut newcomer, &mut regular, &win);
+        assert_eq!(newcomer.rating, 1220);
+        assert_eq!(regular.rating, 1190);
+
+        // Masters settle down further still
+        let mut master = profile(2400, 100);
+        let mut rival = profile(2400, 100);
+        let win = game(&master, Some(master.player), true);
+        PlayerProfile::rate(&mut master, &mut rival, &win);
+        assert_eq!((master.rating, rival.rating), (2405, 2395));
+    }
+
+    #[test]
+    fn a_draw_pulls_ratings_together() {
+        let mut stronger = profile(1400, 50);
+        let mut weaker = profile(1200, 50);
+        let draw = game(&stronger, None, true);
+        PlayerProfile::rate(&mut stronger, &mut weaker, &draw);
+        assert_eq!((stronger.rating, weaker.rating), (1395, 1205));
+
+        // Level players drawing stay put
+        let mut one = profile(1200, 50);
+        let mut other = profile(1200, 50);
+        let draw = game(&one, None, true);
+        PlayerProfile::rate(&mut one, &mut other, &draw);
+        assert_eq!((one.rating, other.rating), (1200, 1200));
+    }
+
+    #[test]
+    fn unranked_games_leave_ratings_alone() {
+        let mut winner = profile(1200, 0);
+        let mut loser = profile(1200, 0);
+        let win = game(&winner, Some(winner.player), false);
+        PlayerProfile::rate(&mut winner, &mut loser, &win);
+        assert_eq!((winner.rating, loser.rating), (1200, 1200));
+        assert_eq!(winner.ranked_games, 0);
+    }
+
+    #[test]
+    fn ratings_never_fall_below_the_floor() {
+        let mut winner = profile(PlayerProfile::MIN_RATING + 5, 0);
+        let mut loser = profile(PlayerProfile::MIN_RATING + 5, 0);
+        let win = game(&winner, Some(winner.player), true);
+        PlayerProfile::rate(&mut winner, &mut loser, &win);
+        assert_eq!(winner.rating, PlayerProfile::MIN_RATING + 25);
+        assert_eq!(loser.rating, PlayerProfile::MIN_RATING);
+    }
+}
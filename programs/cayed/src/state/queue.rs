@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+
+/// Stakes a matchmaking queue can be opened for, in lamports. Queues are keyed by index.
+pub const WAGER_BUCKETS: [u64; 6] = [
+    0,
+    10_000_000,
+    50_000_000,
+    100_000_000,
+    500_000_000,
+    1_000_000_000,
+];
+
+/// Players waiting for an opponent at one grid size and stake. First in, first paired.
+#[account]
+#[derive(InitSpace)]
+pub struct Queue {
+    pub grid_size: u8,
+    /// Index into `WAGER_BUCKETS`.
+    pub bucket: u8,
+    #[max_len(16)]
+    pub entries: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl Queue {
+    pub const MAX_ENTRIES: usize = 16;
+
+    pub fn wager(bucket: u8) -> Result<u64> {
+        WAGER_BUCKETS
+            .get(bucket as usize)
+            .copied()
+            .ok_or(CayedError::InvalidWagerBucket.into())
+    }
+
+    /// The player `place` in line, or the default key if nobody is there yet.
+    pub fn waiting(&self, place: usize) -> Pubkey {
+        self.entries.get(place).copied().unwrap_or_default()
+    }
+}
@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Game, PlayerProfile};
+
+/// A ranked period run by `Config.authority`, with a top-N leaderboard kept up to date
+/// as games are settled between `start_slot` and `end_slot`.
+#[account]
+#[derive(InitSpace)]
+pub struct Season {
+    pub id: u64,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    pub ranking: SeasonRanking,
+    /// Lamports escrowed in this account by `create_season` and claimed by the winners
+    /// once it closes.
+    pub prize_pool: u64,
+    /// Share of the pool for each place in basis points, first place first.
+    #[max_len(10)]
+    pub prize_split: Vec<u16>,
+    /// Best first.
+    #[max_len(10)]
+    pub standings: Vec<Standing>,
+    /// Set by `close_season`; standings no longer change.
+    pub closed: bool,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq)]
+pub enum SeasonRanking {
+    /// Games won during the season.
+    Wins,
+    /// Current Elo rating.
+    Rating,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub struct Standing {
+    pub player: Pubkey,
+    pub score: u32,
+    /// Set once the player has taken their prize from the closed season.
+    pub claimed: bool,
+}
+
+impl Season {
+    pub const MAX_STANDINGS: usize = 10;
+
+    /// Move `amount` lamports of the escrowed pool out of the season account, which this
+    /// program owns.
+    pub fn pay_out<'info>(
+        season: &Account<'info, Season>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let season_info = season.to_account_info();
+        let to_lamports = to
+            .lamports()
+            .checked_add(amount)
+            .ok_or(CayedError::Overflow)?;
+        **season_info.try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? = to_lamports;
+        Ok(())
+    }
+
+    /// Share of the pool won by the player in `place`, counting from zero.
+    pub fn prize(&self, place: usize) -> u64 {
+        let share = self.prize_split.get(place).copied().unwrap_or(0);
+        (self.prize_pool as u128 * share as u128 / 10_000) as u64
+    }
+
+    /// The part of the pool no standing can claim: the shares of places nobody reached,
+    /// whatever `prize_split` leaves out and what rounding each share down leaves over.
+    pub fn unawarded(&self) -> u64 {
+        let awarded: u64 = (0..self.standings.len())
+            .map(|place| self.prize(place))
+            .sum();
+        self.prize_pool.saturating_sub(awarded)
+    }
+
+    pub fn is_live(&self, slot: u64) -> bool {
+        !self.closed && (self.start_slot..=self.end_slot).contains(&slot)
+    }
+
+    /// Count a settled game towards both players' standings.
+    pub fn record(
+        &mut self,
+        player_1: &mut PlayerProfile,
+        player_2: &mut PlayerProfile,
+        game: &Game,
+        slot: u64,
+    ) {
+        if !self.is_live(slot) {
+            return;
+        }
+        for profile in [player_1, player_2] {
+            profile.enter_season(self.id);
+            if game.winner() == Some(profile.player) {
+                profile.season_wins += 1;
+            }
+            let score = match self.ranking {
+                SeasonRanking::Wins => profile.season_wins,
+                SeasonRanking::Rating => profile.rating as u32,
+            };
+            self.rank(profile.player, score);
+        }
+    }
+
+    fn rank(&mut self, player: Pubkey, score: u32) {
+        self.standings.retain(|standing| standing.player != player);
+        // Ties go to whoever is already on the score; re-ranking counts as arriving now,
+        // so a player who drops and climbs back lines up behind them
+        let place = self
+            .standings
+            .iter()
+            .position(|standing| standing.score < score)
+            .unwrap_or(self.standings.len());
+        if place < Self::MAX_STANDINGS {
+            self.standings.insert(
+                place,
+                Standing {
+                    player,
+                    score,
+                    claimed: false,
+                },
+            );
+            self.standings.truncate(Self::MAX_STANDINGS);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn season(prize_split: Vec<u16>) -> Season {
+        Season {
+            id: 0,
+            start_slot: 0,
+            end_slot: 10,
+            ranking: SeasonRanking::Wins,
+            prize_pool: 1_000,
+            prize_split,
+            standings: vec![],
+            closed: false,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn places_nobody_reached_are_unclaimed_prizes() {
+        let mut season = season(vec![6_000, 3_000, 1_000]);
+        season.rank(Pubkey::new_unique(), 3);
+        season.rank(Pubkey::new_unique(), 1);
+
+        assert_eq!(season.prize(0), 600);
+        assert_eq!(season.prize(1), 300);
+        assert_eq!(season.prize(3), 0);
+        assert_eq!(season.unawarded(), 100);
+    }
+
+    #[test]
+    fn rounding_dust_goes_back_with_the_unawarded_pool() {
+        let mut season = season(vec![3_333, 3_333, 3_334]);
+        season.prize_pool = 1_001;
+        for score in [3, 2, 1] {
+            season.rank(Pubkey::new_unique(), score);
+        }
+
+        // Each share rounds 333.x lamports down
+        assert_eq!((0..3).map(|place| season.prize(place)).sum::<u64>(), 999);
+        assert_eq!(season.unawarded(), 2);
+    }
+
+    #[test]
+    fn a_player_who_re_ranks_lines_up_behind_their_new_score() {
+        let mut season = season(vec![]);
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        season.rank(first, 2);
+        season.rank(second, 2);
+        season.rank(first, 1);
+        season.rank(first, 2);
+
+        let order: Vec<Pubkey> = season.standings.iter().map(|s| s.player).collect();
+        assert_eq!(order, vec![second, first]);
+    }
+}
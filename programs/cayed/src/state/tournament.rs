@@ -0,0 +1,398 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CayedError;
+use crate::state::{Game, GameSettings};
+
+/// A single-elimination bracket. Entry fees are held in the tournament account itself
+/// and paid out by placement once a champion is decided.
+#[account]
+#[derive(InitSpace)]
+pub struct Tournament {
+    pub id: u64,
+    pub organizer: Pubkey,
+    pub grid_size: u8,
+    /// Always has a time bank, so a game under way can be won on time.
+    pub settings: GameSettings,
+    pub entry_fee: u64,
+    /// Seconds each pairing's players get to set up their game before a no-show can be
+    /// recorded against them.
+    pub round_secs: u32,
+    /// Bracket size; a power of two up to 16.
+    pub max_players: u8,
+    /// Share of the prize pool each player in a placement tier gets, in basis points:
+    /// champion, runner-up, semi-finalists, quarter-finalists.
+    #[max_len(4)]
+    pub prize_split: Vec<u16>,
+    /// Sorted by seed once the tournament starts.
+    #[max_len(16)]
+    pub entrants: Vec<Entrant>,
+    /// Entrants still in, as indexes into `entrants` in bracket order; neighbours meet.
+    /// `BYE` marks an empty seat in the first round.
+    #[max_len(16)]
+    pub bracket: Vec<u8>,
+    /// Games of the current round, one per neighbouring pair in `bracket`.
+    #[max_len(8)]
+    pub pairings: Vec<Pairing>,
+    pub round: u8,
+    pub rounds: u8,
+    pub status: TournamentStatus,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub struct Entrant {
+    pub player: Pubkey,
+    /// Elo rating at registration, used for seeding.
+    pub rating: u16,
+    /// Round the entrant was knocked out in; `None` while still in or once champion.
+    pub eliminated_in: Option<u8>,
+    pub claimed: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub struct Pairing {
+    /// Game being played for this pairing; cleared again if it ends in a draw.
+    pub game_id: Option<u64>,
+    pub winner: Option<Pubkey>,
+    /// When the pairing's game must be under way; reset for a replay.
+    pub deadline: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq)]
+pub enum TournamentStatus {
+    Registration,
+    InProgress,
+    Completed { winner: Pubkey },
+    Cancelled,
+}
+
+impl Tournament {
+    pub const MAX_PLAYERS: u8 = 16;
+    pub const BYE: u8 = u8::MAX;
+
+    /// Each tier's share is paid to every player in it, so the whole split must fit the
+    /// pool for a full bracket.
+    pub fn validate_prize_split(prize_split: &[u16], max_players: u8) -> Result<()> {
+        let rounds = max_players.trailing_zeros() as usize;
+        let total: u32 = prize_split
+            .iter()
+            .enumerate()
+            .map(|(tier, share)| *share as u32 * Self::tier_size(tier as u8))
+            .sum();
+        require!(
+            prize_split.len() <= rounds + 1 && total <= 10_000,
+            CayedError::InvalidPrizeSplit
+        );
+        Ok(())
+    }
+
+    /// Players finishing in a placement tier: one champion, one runner-up, then 2, 4...
+    fn tier_size(tier: u8) -> u32 {
+        if tier == 0 {
+            1
+        } else {
+            1 << (tier - 1)
+        }
+    }
+
+    /// Seed entrants by rating and lay out the first round so top seeds meet last. A field
+    /// short of a power of two is padded with byes, which fall to the top seeds.
+    pub fn seed(&mut self, now: i64) {
+        self.entrants.sort_by(|a, b| b.rating.cmp(&a.rating));
+        let entrants = self.entrants.len() as u8;
+        let size = self.entrants.len().next_power_of_two();
+        let mut order = vec![0u8];
+        while order.len() < size {
+            let last = (order.len() * 2 - 1) as u8;
+            order = order.iter().flat_map(|seed| [*seed, last - seed]).collect();
+        }
+        self.bracket = order
+            .into_iter()
+            .map(|seed| if seed < entrants { seed } else { Self::BYE })
+            .collect();
+        self.rounds = size.trailing_zeros() as u8;
+        self.round = 0;
+        self.open_round(now);
+    }
+
+    /// A pairing against a bye is decided on the spot.
+    fn open_round(&mut self, now: i64) {
+        let deadline = self.deadline_from(now);
+        let pairings = self
+            .bracket
+            .chunks(2)
+            .enumerate()
+            .map(|(pair, seats)| {
+                let (player_a, player_b) = self.players(pair as u8);
+                let winner = match seats {
+                    [_, Self::BYE] => Some(player_a),
+                    [Self::BYE, _] => Some(player_b),
+                    _ => None,
+                };
+                Pairing {
+                    game_id: None,
+                    winner,
+                    deadline,
+                }
+            })
+            .collect();
+        self.pairings = pairings;
+    }
+
+    fn deadline_from(&self, now: i64) -> i64 {
+        now.saturating_add(self.round_secs as i64)
+    }
+
+    /// The undecided pairing `game_id` is being played for.
+    pub fn pairing_of(&self, game_id: u64) -> Result<usize> {
+        self.pairings
+            .iter()
+            .position(|pairing| pairing.game_id == Some(game_id) && pairing.winner.is_none())
+            .ok_or(CayedError::NotTournamentGame.into())
+    }
+
+    /// Clear a drawn pairing so it can be played again, with a fresh deadline.
+    pub fn replay(&mut self, pair: usize, now: i64) {
+        self.pairings[pair].game_id = None;
+        self.pairings[pair].deadline = self.deadline_from(now);
+    }
+
+    /// Who goes through a pairing whose game is still being set up past its deadline: the
+    /// only player who hid a fleet, or the higher seed if neither did, or both did but
+    /// nobody drew the opener. `None` once the clock runs, as `claim_timeout` decides then.
+    pub fn no_show_winner(&self, pair: usize, game: &Game) -> Option<Pubkey> {
+        match (game.ships_hidden_player_1, game.ships_hidden_player_2) {
+            (true, false) => Some(game.player_1),
+            (false, true) => game.player_2,
+            (true, true) if game.first_move_decided => None,
+            _ => {
+                let seats = &self.bracket[pair * 2..pair * 2 + 2];
+                let top = seats.iter().min().copied()?;
+                self.entrants
+                    .get(top as usize)
+                    .map(|entrant| entrant.player)
+            }
+        }
+    }
+
+    /// The two players meeting in `pair`, or default keys for a bye or when there is no
+    /// such pairing.
+    pub fn players(&self, pair: u8) -> (Pubkey, Pubkey) {
+        let seat = |index: usize| {
+            self.bracket
+                .get(index)
+                .and_then(|entrant| self.entrants.get(*entrant as usize))
+                .map(|entrant| entrant.player)
+                .unwrap_or_default()
+        };
+        (seat(pair as usize * 2), seat(pair as usize * 2 + 1))
+    }
+
+    /// Record the winner of `pair`, knocking out the loser. Once every pairing is
+    /// decided the winners move on, or the tournament is complete.
+    pub fn advance(&mut self, pair: usize, winner: Pubkey, now: i64) -> Result<()> {
+        let (player_a, player_b) = self.players(pair as u8);
+        let loser = if winner == player_a {
+            player_b
+        } else {
+            player_a
+        };
+        let round = self.round;
+        if let Some(entrant) = self.entrants.iter_mut().find(|e| e.player == loser) {
+            entrant.eliminated_in = Some(round);
+        }
+        self.pairings[pair].winner = Some(winner);
+
+        if self.pairings.iter().any(|pairing| pairing.winner.is_none()) {
+            return Ok(());
+        }
+        let winners: Vec<Pubkey> = self.pairings.iter().filter_map(|p| p.winner).collect();
+        if winners.len() == 1 {
+            self.status = TournamentStatus::Completed { winner };
+            return Ok(());
+        }
+        self.bracket = winners
+            .iter()
+            .map(|winner| {
+                self.entrants
+                    .iter()
+                    .position(|entrant| entrant.player == *winner)
+                    .map(|index| index as u8)
+                    .ok_or(CayedError::InvalidPairing.into())
+            })
+            .collect::<Result<_>>()?;
+        self.round += 1;
+        self.open_round(now);
+        Ok(())
+    }
+
+    /// Lamports an entrant has won, by how far they got.
+    pub fn prize(&self, entrant: &Entrant) -> u64 {
+        let tier = match entrant.eliminated_in {
+            None => 0,
+            Some(round) => self.rounds - round,
+        };
+        let share = self.prize_split.get(tier as usize).copied().unwrap_or(0);
+        let pool = self.entry_fee as u128 * self.entrants.len() as u128;
+        (pool * share as u128 / 10_000) as u64
+    }
+
+    /// Prizes won but not yet claimed.
+    pub fn unclaimed(&self) -> u64 {
+        self.entrants
+            .iter()
+            .filter(|entrant| !entrant.claimed)
+            .map(|entrant| self.prize(entrant))
+            .sum()
+    }
+
+    /// Move `amount` lamports out of the tournament account, which this program owns.
+    pub fn pay_out<'info>(
+        tournament: &Account<'info, Tournament>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let tournament_info = tournament.to_account_info();
+        let to_lamports = to
+            .lamports()
+            .checked_add(amount)
+            .ok_or(CayedError::Overflow)?;
+        **tournament_info.try_borrow_mut_lamports()? -= amount;
+        **to.try_borrow_mut_lamports()? = to_lamports;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tournament(ratings: &[u16]) -> Tournament {
+        Tournament {
+            id: 0,
+            organizer: Pubkey::default(),
+            grid_size: 4,
+            settings: GameSettings::default(),
+            entry_fee: 1_000,
+            round_secs: 60,
+            max_players: 8,
+            prize_split: vec![6_000, 2_000],
+            entrants: ratings
+                .iter()
+                .map(|rating| Entrant {
+                    player: Pubkey::new_unique(),
+                    rating: *rating,
+                    eliminated_in: None,
+                    claimed: false,
+                })
+                .collect(),
+            bracket: vec![],
+            pairings: vec![],
+            round: 0,
+            rounds: 0,
+            status: TournamentStatus::InProgress,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn seeds_a_power_of_two_without_byes() {
+        let mut tournament = tournament(&[1200, 1400, 1300, 1100]);
+        tournament.seed(0);
+        assert_eq!(tournament.bracket, vec![0, 3, 1, 2]);
+        assert_eq!(tournament.rounds, 2);
+        assert!(tournament.pairings.iter().all(|p| p.winner.is_none()));
+    }
+
+    #[test]
+    fn top_seeds_get_the_byes() {
+        let mut tournament = tournament(&[1200, 1500, 1300]);
+        tournament.seed(0);
+        let top = tournament.entrants[0].player;
+        assert_eq!(tournament.entrants[0].rating, 1500);
+        assert_eq!(tournament.bracket, vec![0, Tournament::BYE, 1, 2]);
+        assert_eq!(tournament.rounds, 2);
+        assert_eq!(tournament.pairings[0].winner, Some(top));
+        assert_eq!(tournament.pairings[1].winner, None);
+        assert_eq!(tournament.players(0), (top, Pubkey::default()));
+    }
+
+    #[test]
+    fn a_bye_moves_on_with_the_last_game_of_the_round() {
+        let mut tournament = tournament(&[1500, 1300, 1200]);
+        tournament.seed(0);
+        let (second, third) = tournament.players(1);
+        tournament.advance(1, third, 0).unwrap();
+        assert_eq!(tournament.round, 1);
+        assert_eq!(tournament.bracket, vec![0, 2]);
+        let loser = tournament.entrants.iter().find(|e| e.player == second);
+        assert_eq!(loser.unwrap().eliminated_in, Some(0));
+    }
+
+    // A bracket game for `pair`, with neither fleet hidden yet
+    fn pairing_game(tournament: &Tournament, pair: u8) -> Game {
+        let (player_1, player_2) = tournament.players(pair);
+        let mut game = Game::new(0, 4, player_1, 0, GameSettings::default(), 0);
+        game.player_2 = Some(player_2);
+        game
+    }
+
+    #[test]
+    fn an_overdue_pairing_goes_to_whoever_showed_up() {
+        let mut tournament = tournament(&[1500, 1300, 1200, 1100]);
+        tournament.seed(0);
+        let (top, fourth) = tournament.players(0);
+        let mut game = pairing_game(&tournament, 0);
+
+        assert_eq!(tournament.no_show_winner(0, &game), Some(top));
+        game.ships_hidden_player_2 = true;
+        assert_eq!(tournament.no_show_winner(0, &game), Some(fourth));
+        // Both fleets hidden but nobody drew the opener
+        game.ships_hidden_player_1 = true;
+        assert_eq!(tournament.no_show_winner(0, &game), Some(top));
+        // The clock decides from here
+        game.first_move_decided = true;
+        assert_eq!(tournament.no_show_winner(0, &game), None);
+    }
+
+    #[test]
+    fn a_later_round_no_show_goes_to_the_higher_seed() {
+        let mut tournament = tournament(&[1500, 1300, 1200, 1100]);
+        tournament.seed(0);
+        let (_, fourth) = tournament.players(0);
+        let (second, _) = tournament.players(1);
+        tournament.advance(0, fourth, 0).unwrap();
+        tournament.advance(1, second, 0).unwrap();
+        assert_eq!(tournament.players(0), (fourth, second));
+
+        let game = pairing_game(&tournament, 0);
+        assert_eq!(tournament.no_show_winner(0, &game), Some(second));
+    }
+
+    #[test]
+    fn a_replay_gets_a_fresh_deadline() {
+        let mut tournament = tournament(&[1500, 1300]);
+        tournament.seed(100);
+        assert_eq!(tournament.pairings[0].deadline, 160);
+
+        tournament.pairings[0].game_id = Some(7);
+        tournament.replay(0, 500);
+        assert_eq!(tournament.pairings[0].game_id, None);
+        assert_eq!(tournament.pairings[0].deadline, 560);
+    }
+
+    #[test]
+    fn sweeps_only_what_nobody_is_owed() {
+        let mut tournament = tournament(&[1500, 1300, 1200]);
+        tournament.seed(0);
+        let champion = tournament.entrants[0].player;
+        let (_, third) = tournament.players(1);
+        tournament.advance(1, third, 0).unwrap();
+        tournament.advance(0, champion, 0).unwrap();
+        assert!(tournament.status == TournamentStatus::Completed { winner: champion });
+        // 60% to the champion and 20% to the runner-up of a 3_000 pool
+        assert_eq!(tournament.unclaimed(), 2_400);
+        tournament.entrants[0].claimed = true;
+        assert_eq!(tournament.unclaimed(), 600);
+    }
+}
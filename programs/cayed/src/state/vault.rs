@@ -1,7 +1,32 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::CayedError;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Vault {
     pub authority: Pubkey,
 }
+
+impl Vault {
+    /// Move `amount` lamports out of the vault. The vault is owned by this program,
+    /// so lamports are debited directly instead of through a system transfer.
+    pub fn pay_out<'info>(
+        vault: &Account<'info, Vault>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let vault_info = vault.to_account_info();
+        let vault_lamports = vault_info
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(CayedError::Overflow)?;
+        let to_lamports = to
+            .lamports()
+            .checked_add(amount)
+            .ok_or(CayedError::Overflow)?;
+        **vault_info.try_borrow_mut_lamports()? = vault_lamports;
+        **to.try_borrow_mut_lamports()? = to_lamports;
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+import { createHash, randomBytes } from 'crypto';
 import { readFileSync } from 'fs';
 import { homedir } from 'os';
 
@@ -12,6 +13,7 @@ import {
 } from '@magicblock-labs/ephemeral-rollups-sdk';
 import {
   Connection,
+  Ed25519Program,
   Keypair,
   PublicKey,
   sendAndConfirmTransaction,
@@ -20,6 +22,15 @@ import {
 import { describe, beforeAll, it, expect } from 'bun:test';
 import nacl from 'tweetnacl';
 
+// MagicBlock VRF oracle queue serving the ER
+const VRF_EPHEMERAL_QUEUE = new PublicKey(
+  '5hBR571xnXppuCPveTrctfTU7tJLSN94nq7kv7FRK5Tc'
+);
+
+// Coin flip commitment: sha256(secret || player)
+const commitCoinFlip = (secret: Buffer, player: PublicKey) =>
+  [...createHash('sha256').update(secret).update(player.toBuffer()).digest()];
+
 const sleep = (ms: number) => new Promise<void>(r => setTimeout(r, ms));
 
 async function sendAndConfirmER(
@@ -70,6 +81,19 @@ describe('cayed', () => {
   let player2: Keypair;
   let otherAuth: Keypair;
 
+  const DEFAULT_SETTINGS = {
+    mines: 0,
+    decoys: 0,
+    sonarCharges: 0,
+    torpedoCharges: 0,
+    timeBankSecs: 0,
+    incrementSecs: 0,
+    handicap: { none: {} },
+    ammo: 0,
+    maneuvers: false,
+    ranked: false,
+  };
+
   let configPda: PublicKey;
   let vaultPda: PublicKey;
 
@@ -83,6 +107,84 @@ describe('cayed', () => {
   const erUrl = process.env.EPHEMERAL_ENDPOINT || 'http://127.0.0.1:7799';
   const erWs = process.env.EPHEMERAL_WS_ENDPOINT || 'ws://127.0.0.1:7800';
 
+  // Game ids are handed out by the program; read the one the next game will get
+  // Id the program hands the next account of a kind, from its counter on `Config`
+  const nextId = async (
+    counter: 'gameCounter' | 'mapCounter' | 'matchCounter' | 'tournamentCounter'
+  ): Promise<anchor.BN> => {
+    const raw = await baseConn.getAccountInfo(configPda);
+    return program.coder.accounts.decode('config', raw!.data)[counter];
+  };
+
+  const nextGameId = () => nextId('gameCounter');
+
+  const openGamesPda = (page: number): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('open_games'), new anchor.BN(page).toArrayLike(Buffer, 'le', 4)],
+      program.programId
+    )[0];
+
+  const playerGamesPda = (player: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('player_games'), player.toBuffer()],
+      program.programId
+    )[0];
+
+  const profilePda = (player: PublicKey): PublicKey =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('profile'), player.toBuffer()],
+      program.programId
+    )[0];
+
+  // System program error when an `init` account already exists
+  const ACCOUNT_IN_USE = 0;
+
+  // Run a transaction that must fail and check it failed with this error, named as in
+  // the IDL or given as a raw custom code
+  const expectError = async (send: () => Promise<unknown>, error: string | number) => {
+    const code =
+      typeof error === 'number'
+        ? error
+        : program.idl.errors!.find(e => e.name === error)!.code;
+    let failure: unknown;
+    try {
+      await send();
+    } catch (e) {
+      failure = e;
+    }
+    expect(failure).toBeDefined();
+    const message = String(failure);
+    expect(
+      message.includes(`"Custom":${code}`) ||
+        message.includes(`custom program error: 0x${code.toString(16)}`)
+    ).toBe(true);
+  };
+
+  const openGamesPages = async (): Promise<any[]> => {
+    const raw = await baseConn.getAccountInfo(configPda);
+    const count = program.coder.accounts.decode('config', raw!.data).openGamesPages;
+    const pages = [];
+    for (let page = 0; page < count; page++) {
+      const info = await baseConn.getAccountInfo(openGamesPda(page));
+      pages.push(program.coder.accounts.decode('openGames', info!.data));
+    }
+    return pages;
+  };
+
+  // First registry page with room, or the next one to be created
+  const openGamesPage = async (): Promise<number> => {
+    const pages = await openGamesPages();
+    const free = pages.findIndex(p => p.entries.length < 32);
+    return free === -1 ? pages.length : free;
+  };
+
+  // Page listing a game; page 0 when it is not listed anywhere
+  const listedOn = async (gid: anchor.BN): Promise<PublicKey> => {
+    const pages = await openGamesPages();
+    const page = pages.findIndex(p => p.entries.some((e: any) => e.id.eq(gid)));
+    return openGamesPda(Math.max(page, 0));
+  };
+
   const ER_VALIDATOR = new PublicKey(
     process.env.ER_VALIDATOR || 'mAGicPQYBMvcYveUZA5F5UNNwyHvfYh5xkLS2Fr1mev'
   );
@@ -142,7 +244,7 @@ describe('cayed', () => {
 
   it('inits config', async () => {
     const tx = await program.methods
-      .initConfig(10, 100)
+      .initConfig(10, 100, 32)
       .accounts({
         authority: authorityKp.publicKey,
         config: configPda,
@@ -164,7 +266,7 @@ describe('cayed', () => {
 
   it('rejects re-init by different authority', async () => {
     const tx = await program.methods
-      .initConfig(6, 50)
+      .initConfig(6, 50, 32)
       .accounts({
         authority: otherAuth.publicKey,
         config: configPda,
@@ -172,21 +274,19 @@ describe('cayed', () => {
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .transaction();
-    tx.feePayer = otherAuth.publicKey;
-    try {
-      await sendAndConfirmTransaction(baseConn, tx, [otherAuth], {
+    // the real authority pays the fee, so the unfunded impostor reaches the check
+    tx.feePayer = authorityKp.publicKey;
+    await expectError(async () => {
+      await sendAndConfirmTransaction(baseConn, tx, [authorityKp, otherAuth], {
         commitment: 'confirmed',
       });
-      throw new Error('should have failed');
-    } catch {
-      // expected — Unauthorized
-    }
+    }, 'Unauthorized');
   });
 
   // ─────────── Create Game ───────────
 
   it('creates game with permission + delegate', async () => {
-    const gid = new anchor.BN(Date.now());
+    const gid = await nextGameId();
     const id = gid.toArrayLike(Buffer, 'le', 8);
     const [gamePda] = PublicKey.findProgramAddressSync(
       [Buffer.from('game'), id],
@@ -199,13 +299,16 @@ describe('cayed', () => {
 
     const permAddr = permissionPdaFromAccount(p1Pda);
 
+    const page = await openGamesPage();
     const createIx = await program.methods
-      .createGame(gid, 4, new anchor.BN(0))
+      .createGame(4, new anchor.BN(0), DEFAULT_SETTINGS, null, null, page)
       .accounts({
         player: player1.publicKey,
         game: gamePda,
         playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
         config: configPda,
+        openGames: openGamesPda(page),
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -245,13 +348,16 @@ describe('cayed', () => {
     const raw = await baseConn.getAccountInfo(gamePda);
     const g = program.coder.accounts.decode('game', raw!.data);
     expect(g.status).toHaveProperty('awaitingPlayerTwo');
+    // the program assigned the id and moved the counter on
+    expect(g.id.toString()).toBe(gid.toString());
+    expect((await nextGameId()).toString()).toBe(gid.addn(1).toString());
 
     const ok = await waitUntilPermissionActive(erUrl, p1Pda);
     expect(ok).toBe(true);
   });
 
   it('rejects wager below minimum', async () => {
-    const gid = new anchor.BN(Date.now());
+    const gid = await nextGameId();
     const id = gid.toArrayLike(Buffer, 'le', 8);
     const [gamePda] = PublicKey.findProgramAddressSync(
       [Buffer.from('game'), id],
@@ -261,31 +367,31 @@ describe('cayed', () => {
       [Buffer.from('player'), id, player1.publicKey.toBuffer()],
       program.programId
     );
+    const page = await openGamesPage();
     const tx = await program.methods
-      .createGame(gid, 4, new anchor.BN(50_000))
+      .createGame(4, new anchor.BN(50_000), DEFAULT_SETTINGS, null, null, page)
       .accounts({
         player: player1.publicKey,
         game: gamePda,
         playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
         config: configPda,
+        openGames: openGamesPda(page),
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .transaction();
     tx.feePayer = player1.publicKey;
-    try {
+    await expectError(async () => {
       await sendAndConfirmTransaction(baseConn, tx, [player1], {
         skipPreflight: true,
         commitment: 'confirmed',
       });
-      throw new Error('should have failed');
-    } catch {
-      // expected
-    }
+    }, 'MinimumWager');
   });
 
   it('rejects grid > config max', async () => {
-    const gid = new anchor.BN(Date.now());
+    const gid = await nextGameId();
     const id = gid.toArrayLike(Buffer, 'le', 8);
     const [gamePda] = PublicKey.findProgramAddressSync(
       [Buffer.from('game'), id],
@@ -295,33 +401,228 @@ describe('cayed', () => {
       [Buffer.from('player'), id, player1.publicKey.toBuffer()],
       program.programId
     );
+    const page = await openGamesPage();
     const tx = await program.methods
-      .createGame(gid, 12, new anchor.BN(0))
+      .createGame(12, new anchor.BN(0), DEFAULT_SETTINGS, null, null, page)
       .accounts({
         player: player1.publicKey,
         game: gamePda,
         playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
         config: configPda,
+        openGames: openGamesPda(page),
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .transaction();
     tx.feePayer = player1.publicKey;
-    try {
+    await expectError(async () => {
       await sendAndConfirmTransaction(baseConn, tx, [player1], {
         skipPreflight: true,
         commitment: 'confirmed',
       });
-      throw new Error('should have failed');
-    } catch {
-      // expected
-    }
+    }, 'GridSizeTooLarge');
+  });
+
+  it('rejects extra-ship handicap on a full-size fleet', async () => {
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [gamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player1.publicKey.toBuffer()],
+      program.programId
+    );
+    const settings = { ...DEFAULT_SETTINGS, handicap: { extraShip: { player1: true } } };
+    const page = await openGamesPage();
+    const tx = await program.methods
+      .createGame(10, new anchor.BN(0), settings, null, null, page)
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
+        config: configPda,
+        openGames: openGamesPda(page),
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await expectError(async () => {
+      await sendAndConfirmTransaction(baseConn, tx, [player1], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'InvalidHandicap');
+  });
+
+  it('rejects a ranked game with a handicap', async () => {
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [gamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player1.publicKey.toBuffer()],
+      program.programId
+    );
+    const settings = {
+      ...DEFAULT_SETTINGS,
+      handicap: { freeSonar: { player1: true } },
+      ranked: true,
+    };
+    const page = await openGamesPage();
+    const tx = await program.methods
+      .createGame(4, new anchor.BN(0), settings, null, null, page)
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
+        config: configPda,
+        openGames: openGamesPda(page),
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await expectError(async () => {
+      await sendAndConfirmTransaction(baseConn, tx, [player1], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'RankedHandicap');
+  });
+
+  it('rejects more ammo than board cells', async () => {
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [gamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player1.publicKey.toBuffer()],
+      program.programId
+    );
+    const settings = { ...DEFAULT_SETTINGS, ammo: 9 };
+    const page = await openGamesPage();
+    const tx = await program.methods
+      .createGame(4, new anchor.BN(0), settings, null, null, page)
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
+        config: configPda,
+        openGames: openGamesPda(page),
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await expectError(async () => {
+      await sendAndConfirmTransaction(baseConn, tx, [player1], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'TooMuchAmmo');
+  });
+
+  // ─────────── Maps ───────────
+
+  it('creates map and game on it', async () => {
+    const mid = await nextId('mapCounter');
+    const [mapPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('map'), mid.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    // Block (3,0) and (3,1) on a 4 x 2 board
+    const blocked = new anchor.BN((1 << 3) | (1 << 7));
+    let tx = await program.methods
+      .createMap(4, blocked)
+      .accounts({
+        creator: player1.publicKey,
+        map: mapPda,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [gamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player1.publicKey.toBuffer()],
+      program.programId
+    );
+    const page = await openGamesPage();
+    tx = await program.methods
+      .createGame(4, new anchor.BN(0), DEFAULT_SETTINGS, null, null, page)
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
+        config: configPda,
+        openGames: openGamesPda(page),
+        map: mapPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const raw = await baseConn.getAccountInfo(gamePda);
+    const g = program.coder.accounts.decode('game', raw!.data);
+    expect(g.map?.toBase58()).toBe(mapPda.toBase58());
+    expect(g.blockedMask.toNumber()).toBe(blocked.toNumber());
+  });
+
+  it('rejects map with blocked cells off the grid', async () => {
+    const mid = await nextId('mapCounter');
+    const [mapPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('map'), mid.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const tx = await program.methods
+      .createMap(4, new anchor.BN(1 << 8))
+      .accounts({
+        creator: player1.publicKey,
+        map: mapPda,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await expectError(async () => {
+      await sendAndConfirmTransaction(baseConn, tx, [player1], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'BlockedCellOutOfBounds');
   });
 
   // ─────────── Join Game ───────────
 
   it('joins game + permission + delegate', async () => {
-    const gid = new anchor.BN(Date.now());
+    const gid = await nextGameId();
     const id = gid.toArrayLike(Buffer, 'le', 8);
     const [gamePda] = PublicKey.findProgramAddressSync(
       [Buffer.from('game'), id],
@@ -337,13 +638,16 @@ describe('cayed', () => {
     );
 
     // create
+    const page = await openGamesPage();
     let tx = await program.methods
-      .createGame(gid, 4, new anchor.BN(0))
+      .createGame(4, new anchor.BN(0), DEFAULT_SETTINGS, null, null, page)
       .accounts({
         player: player1.publicKey,
         game: gamePda,
         playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
         config: configPda,
+        openGames: openGamesPda(page),
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -357,11 +661,14 @@ describe('cayed', () => {
     // join + permission + delegate game + delegate board
     const permAddr = permissionPdaFromAccount(p2Pda);
     const joinIx = await program.methods
-      .joinGame()
+      .joinGame(null, null)
       .accounts({
         player: player2.publicKey,
         game: gamePda,
         playerBoard: p2Pda,
+        playerGames: playerGamesPda(player2.publicKey),
+        openGames: await listedOn(gid),
+        config: configPda,
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -413,7 +720,7 @@ describe('cayed', () => {
   });
 
   it('rejects self-join', async () => {
-    const gid = new anchor.BN(Date.now());
+    const gid = await nextGameId();
     const id = gid.toArrayLike(Buffer, 'le', 8);
     const [gamePda] = PublicKey.findProgramAddressSync(
       [Buffer.from('game'), id],
@@ -424,13 +731,16 @@ describe('cayed', () => {
       program.programId
     );
 
+    const page = await openGamesPage();
     let tx = await program.methods
-      .createGame(gid, 4, new anchor.BN(0))
+      .createGame(4, new anchor.BN(0), DEFAULT_SETTINGS, null, null, page)
       .accounts({
         player: player1.publicKey,
         game: gamePda,
         playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
         config: configPda,
+        openGames: openGamesPda(page),
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -442,29 +752,95 @@ describe('cayed', () => {
     });
 
     tx = await program.methods
-      .joinGame()
+      .joinGame(null, null)
       .accounts({
         player: player1.publicKey,
         game: gamePda,
         playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
+        openGames: await listedOn(gid),
+        config: configPda,
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .transaction();
     tx.feePayer = player1.publicKey;
-    try {
+    // player 1's board for this game already exists, so the join stops creating it
+    await expectError(async () => {
       await sendAndConfirmTransaction(baseConn, tx, [player1], {
         skipPreflight: true,
         commitment: 'confirmed',
       });
-      throw new Error('should have failed');
-    } catch {
-      // expected
-    }
+    }, ACCOUNT_IN_USE);
+  });
+
+  it('rejects uninvited player', async () => {
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [gamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player1.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const page = await openGamesPage();
+    let tx = await program.methods
+      .createGame(
+        4,
+        new anchor.BN(0),
+        DEFAULT_SETTINGS,
+        null,
+        { player: [player2.publicKey] },
+        page
+      )
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
+        config: configPda,
+        openGames: openGamesPda(page),
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const [strangerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, authorityKp.publicKey.toBuffer()],
+      program.programId
+    );
+    tx = await program.methods
+      .joinGame(null, null)
+      .accounts({
+        player: authorityKp.publicKey,
+        game: gamePda,
+        playerBoard: strangerPda,
+        playerGames: playerGamesPda(authorityKp.publicKey),
+        openGames: await listedOn(gid),
+        config: configPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = authorityKp.publicKey;
+    await expectError(async () => {
+      await sendAndConfirmTransaction(baseConn, tx, [authorityKp], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'NotInvited');
   });
 
   it('rejects 3rd player', async () => {
-    const gid = new anchor.BN(Date.now());
+    const gid = await nextGameId();
     const id = gid.toArrayLike(Buffer, 'le', 8);
     const [gamePda] = PublicKey.findProgramAddressSync(
       [Buffer.from('game'), id],
@@ -479,13 +855,16 @@ describe('cayed', () => {
       program.programId
     );
 
+    const page = await openGamesPage();
     let tx = await program.methods
-      .createGame(gid, 4, new anchor.BN(0))
+      .createGame(4, new anchor.BN(0), DEFAULT_SETTINGS, null, null, page)
       .accounts({
         player: player1.publicKey,
         game: gamePda,
         playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
         config: configPda,
+        openGames: openGamesPda(page),
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -497,11 +876,14 @@ describe('cayed', () => {
     });
 
     tx = await program.methods
-      .joinGame()
+      .joinGame(null, null)
       .accounts({
         player: player2.publicKey,
         game: gamePda,
         playerBoard: p2Pda,
+        playerGames: playerGamesPda(player2.publicKey),
+        openGames: await listedOn(gid),
+        config: configPda,
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
@@ -512,44 +894,37 @@ describe('cayed', () => {
       commitment: 'confirmed',
     });
 
-    const p3 = Keypair.generate();
+    // a funded wallet, so the join gets as far as the seat check
+    const p3 = authorityKp;
     const [p3Pda] = PublicKey.findProgramAddressSync(
       [Buffer.from('player'), id, p3.publicKey.toBuffer()],
       program.programId
     );
     tx = await program.methods
-      .joinGame()
+      .joinGame(null, null)
       .accounts({
         player: p3.publicKey,
         game: gamePda,
         playerBoard: p3Pda,
+        playerGames: playerGamesPda(p3.publicKey),
+        openGames: await listedOn(gid),
+        config: configPda,
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .transaction();
     tx.feePayer = p3.publicKey;
-    try {
+    await expectError(async () => {
       await sendAndConfirmTransaction(baseConn, tx, [p3], {
         commitment: 'confirmed',
       });
-      throw new Error('should have failed');
-    } catch {
-      // expected
-    }
+    }, 'GameFull');
   });
 
-  // ─────────── Hide Ships ───────────
-
-  const P1_SHIPS = [
-    { startX: 0, startY: 0, endX: 1, endY: 0 },
-    { startX: 0, startY: 1, endX: 0, endY: 1 },
-  ];
-  const P2_SHIPS = [
-    { startX: 2, startY: 0, endX: 3, endY: 0 },
-    { startX: 1, startY: 1, endX: 1, endY: 1 },
-  ];
+  // ─────────── Coin Flip ───────────
 
-  async function createAndJoin(gid: anchor.BN) {
+  it('decides first move by commit-reveal coin flip', async () => {
+    const gid = await nextGameId();
     const id = gid.toArrayLike(Buffer, 'le', 8);
     const [gamePda] = PublicKey.findProgramAddressSync(
       [Buffer.from('game'), id],
@@ -563,528 +938,2536 @@ describe('cayed', () => {
       [Buffer.from('player'), id, player2.publicKey.toBuffer()],
       program.programId
     );
+    const secret1 = randomBytes(32);
+    const secret2 = randomBytes(32);
 
-    const perm1 = permissionPdaFromAccount(p1Pda);
-    const perm2 = permissionPdaFromAccount(p2Pda);
-
-    // create game + permission + delegate P1 board
-    const createIx = await program.methods
-      .createGame(gid, 4, new anchor.BN(0))
+    const page = await openGamesPage();
+    let tx = await program.methods
+      .createGame(
+        4,
+        new anchor.BN(0),
+        DEFAULT_SETTINGS,
+        commitCoinFlip(secret1, player1.publicKey),
+        null,
+        page
+      )
       .accounts({
         player: player1.publicKey,
         game: gamePda,
         playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
         config: configPda,
+        openGames: openGamesPda(page),
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
-      .instruction();
-
-    const perm1Ix = await program.methods
-      .createPermission({ playerBoard: { gameId: gid, player: player1.publicKey } }, [
-        { flags: AUTHORITY_FLAG | TX_LOGS_FLAG, pubkey: player1.publicKey },
-      ])
-      .accounts({
-        payer: player1.publicKey,
-        permissionedAccount: p1Pda,
-        permission: perm1,
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .instruction();
-
-    const delPerm1 = createDelegatePermissionInstruction({
-      payer: player1.publicKey,
-      authority: [player1.publicKey, true],
-      permissionedAccount: [p1Pda, false],
-      validator: ER_VALIDATOR,
-    });
-
-    const delPda1 = await program.methods
-      .delegatePda({ playerBoard: { gameId: gid, player: player1.publicKey } })
-      .accounts({ payer: player1.publicKey, pda: p1Pda, validator: ER_VALIDATOR })
-      .instruction();
-
-    let tx = new Transaction().add(createIx, perm1Ix, delPerm1, delPda1);
+      .transaction();
     tx.feePayer = player1.publicKey;
     await sendAndConfirmTransaction(baseConn, tx, [player1], {
       skipPreflight: true,
       commitment: 'confirmed',
     });
 
-    await waitUntilPermissionActive(erUrl, p1Pda);
-
-    // join + permission + delegate P2 board + game
-    const joinIx = await program.methods
-      .joinGame()
+    tx = await program.methods
+      .joinGame(commitCoinFlip(secret2, player2.publicKey), null)
       .accounts({
         player: player2.publicKey,
         game: gamePda,
         playerBoard: p2Pda,
+        playerGames: playerGamesPda(player2.publicKey),
+        openGames: await listedOn(gid),
+        config: configPda,
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
-      .instruction();
-
-    const perm2Ix = await program.methods
-      .createPermission({ playerBoard: { gameId: gid, player: player2.publicKey } }, [
-        { flags: AUTHORITY_FLAG | TX_LOGS_FLAG, pubkey: player2.publicKey },
-      ])
-      .accounts({
-        payer: player2.publicKey,
-        permissionedAccount: p2Pda,
-        permission: perm2,
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .instruction();
-
-    const delPerm2 = createDelegatePermissionInstruction({
-      payer: player2.publicKey,
-      authority: [player2.publicKey, true],
-      permissionedAccount: [p2Pda, false],
-      validator: ER_VALIDATOR,
-    });
-
-    const delGameIx = await program.methods
-      .delegatePda({ game: { gameId: gid } })
-      .accounts({ payer: player2.publicKey, pda: gamePda, validator: ER_VALIDATOR })
-      .instruction();
-
-    const delPda2 = await program.methods
-      .delegatePda({ playerBoard: { gameId: gid, player: player2.publicKey } })
-      .accounts({ payer: player2.publicKey, pda: p2Pda, validator: ER_VALIDATOR })
-      .instruction();
-
-    tx = new Transaction().add(joinIx, delGameIx, perm2Ix, delPerm2, delPda2);
+      .transaction();
     tx.feePayer = player2.publicKey;
     await sendAndConfirmTransaction(baseConn, tx, [player2], {
       skipPreflight: true,
       commitment: 'confirmed',
     });
 
-    await waitUntilPermissionActive(erUrl, p2Pda);
+    // a secret that doesn't match the commitment is refused
+    tx = await program.methods
+      .revealCoinFlip([...secret2])
+      .accounts({ player: player1.publicKey, game: gamePda })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await expectError(async () => {
+      await sendAndConfirmTransaction(baseConn, tx, [player1], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'InvalidSecret');
+
+    for (const [player, secret] of [
+      [player1, secret1],
+      [player2, secret2],
+    ] as const) {
+      tx = await program.methods
+        .revealCoinFlip([...secret])
+        .accounts({ player: player.publicKey, game: gamePda })
+        .transaction();
+      tx.feePayer = player.publicKey;
+      await sendAndConfirmTransaction(baseConn, tx, [player], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }
 
-    return { gamePda, p1Pda, p2Pda };
-  }
+    const raw = await baseConn.getAccountInfo(gamePda);
+    const g = program.coder.accounts.decode('game', raw!.data);
+    expect(g.firstMoveDecided).toBe(true);
+    expect(g.nextMovePlayer1).toBe(((secret1[0]! ^ secret2[0]!) & 1) === 0);
+  });
 
-  it('hides ships on both boards', async () => {
-    const gid = new anchor.BN(Date.now());
-    const { gamePda, p1Pda, p2Pda } = await createAndJoin(gid);
+  // ─────────── Open Games ───────────
 
-    const ix1 = await program.methods
-      .hideShips(P1_SHIPS)
+  it('lists an open game until it is cancelled', async () => {
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [gamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player1.publicKey.toBuffer()],
+      program.programId
+    );
+    const [p2Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player2.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const page = await openGamesPage();
+    let tx = await program.methods
+      .createGame(4, new anchor.BN(100_000), DEFAULT_SETTINGS, null, null, page)
       .accounts({
         player: player1.publicKey,
         game: gamePda,
         playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
+        config: configPda,
+        openGames: openGamesPda(page),
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
       })
-      .instruction();
-    await sendAndConfirmER(erConnP1, player1, ix1);
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
 
-    const ix2 = await program.methods
-      .hideShips(P2_SHIPS)
+    let pages = await openGamesPages();
+    const listing = pages[page].entries.find((e: any) => e.id.eq(gid));
+    expect(listing.gridSize).toBe(4);
+    expect(listing.wager.toNumber()).toBe(100_000);
+    expect(listing.inviteOnly).toBe(false);
+
+    tx = await program.methods
+      .cancelGame()
       .accounts({
-        player: player2.publicKey,
+        player: player1.publicKey,
         game: gamePda,
-        playerBoard: p2Pda,
+        openGames: openGamesPda(page),
+        playerGames: playerGamesPda(player1.publicKey),
+        vault: vaultPda,
       })
-      .instruction();
-    await sendAndConfirmER(erConnP2, player2, ix2);
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
 
-    const b1 = program.coder.accounts.decode(
-      'playerBoard',
-      (await erConnP1.getAccountInfo(p1Pda))!.data
-    );
-    const b2 = program.coder.accounts.decode(
-      'playerBoard',
-      (await erConnP2.getAccountInfo(p2Pda))!.data
+    pages = await openGamesPages();
+    expect(pages[page].entries.some((e: any) => e.id.eq(gid))).toBe(false);
+    const g = program.coder.accounts.decode(
+      'game',
+      (await baseConn.getAccountInfo(gamePda))!.data
     );
-    expect(b1.shipCoordinates.length).toBe(2);
-    expect(b2.shipCoordinates.length).toBe(2);
+    expect(g.status).toHaveProperty('cancelled');
+
+    await expectError(async () => {
+      const tx = await program.methods
+        .joinGame(null, null)
+        .accounts({
+          player: player2.publicKey,
+          game: gamePda,
+          playerBoard: p2Pda,
+          playerGames: playerGamesPda(player2.publicKey),
+          openGames: openGamesPda(page),
+          config: configPda,
+          vault: vaultPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .transaction();
+      tx.feePayer = player2.publicKey;
+      await sendAndConfirmTransaction(baseConn, tx, [player2], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'InvalidGameStatus');
   });
 
-  it('rejects wrong ship count', async () => {
-    const gid = new anchor.BN(Date.now());
-    const { gamePda, p1Pda } = await createAndJoin(gid);
-    const ix = await program.methods
-      .hideShips([{ startX: 0, startY: 0, endX: 1, endY: 0 }])
+  // ─────────── Matchmaking ───────────
+
+  it('pairs queued players into a game', async () => {
+    const [queuePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('queue'), Buffer.from([4]), Buffer.from([0])],
+      program.programId
+    );
+
+    for (const player of [player1, player2]) {
+      const tx = await program.methods
+        .enqueue(4, 0)
+        .accounts({
+          player: player.publicKey,
+          queue: queuePda,
+          playerGames: playerGamesPda(player.publicKey),
+          config: configPda,
+          vault: vaultPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .transaction();
+      tx.feePayer = player.publicKey;
+      await sendAndConfirmTransaction(baseConn, tx, [player], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }
+
+    const q = program.coder.accounts.decode(
+      'queue',
+      (await baseConn.getAccountInfo(queuePda))!.data
+    );
+    const [first, second] = q.entries as PublicKey[];
+
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [gamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, first!.toBuffer()],
+      program.programId
+    );
+    const [p2Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, second!.toBuffer()],
+      program.programId
+    );
+    const tx = await program.methods
+      .matchPlayers()
       .accounts({
-        player: player1.publicKey,
+        payer: authorityKp.publicKey,
+        queue: queuePda,
         game: gamePda,
-        playerBoard: p1Pda,
+        player1Board: p1Pda,
+        player2Board: p2Pda,
+        player1Games: playerGamesPda(first!),
+        player2Games: playerGamesPda(second!),
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
       })
-      .instruction();
-    try {
-      await sendAndConfirmER(erConnP1, player1, ix);
-      throw new Error('should have failed');
-    } catch {
-      // expected
+      .transaction();
+    tx.feePayer = authorityKp.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [authorityKp], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const g = program.coder.accounts.decode(
+      'game',
+      (await baseConn.getAccountInfo(gamePda))!.data
+    );
+    expect(g.status).toHaveProperty('hidingShips');
+    expect(g.player1.toBase58()).toBe(first!.toBase58());
+    expect(g.player2.toBase58()).toBe(second!.toBase58());
+    for (const player of [first!, second!]) {
+      const index = program.coder.accounts.decode(
+        'playerGames',
+        (await baseConn.getAccountInfo(playerGamesPda(player)))!.data
+      );
+      expect(index.games.map((g: anchor.BN) => g.toString())).toContain(
+        gid.toString()
+      );
     }
   });
 
-  it('rejects diagonal ship', async () => {
-    const gid = new anchor.BN(Date.now());
-    const { gamePda, p1Pda } = await createAndJoin(gid);
-    const ix = await program.methods
-      .hideShips([
-        { startX: 0, startY: 0, endX: 1, endY: 1 },
-        { startX: 2, startY: 0, endX: 3, endY: 0 },
-      ])
+  // ─────────── Tournaments ───────────
+
+  // Bracket games always run on a clock; pairings left unplayed for ROUND_SECS can be decided
+  const TOURNAMENT_SETTINGS = { ...DEFAULT_SETTINGS, timeBankSecs: 600 };
+  const ROUND_SECS = 5;
+  let tournamentPda: PublicKey;
+  let bracketGamePda: PublicKey;
+
+  it('registers players for a tournament', async () => {
+    const tid = await nextId('tournamentCounter');
+    [tournamentPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('tournament'), tid.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    let tx = await program.methods
+      .createTournament(
+        4,
+        TOURNAMENT_SETTINGS,
+        new anchor.BN(100_000),
+        2,
+        [10_000],
+        ROUND_SECS
+      )
       .accounts({
-        player: player1.publicKey,
-        game: gamePda,
-        playerBoard: p1Pda,
+        organizer: authorityKp.publicKey,
+        tournament: tournamentPda,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
       })
-      .instruction();
-    try {
-      await sendAndConfirmER(erConnP1, player1, ix);
-      throw new Error('should have failed');
-    } catch {
-      // expected
+      .transaction();
+    tx.feePayer = authorityKp.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [authorityKp], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    for (const player of [player1, player2]) {
+      tx = await program.methods
+        .registerTournament()
+        .accounts({
+          player: player.publicKey,
+          tournament: tournamentPda,
+          profile: null,
+          playerGames: playerGamesPda(player.publicKey),
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .transaction();
+      tx.feePayer = player.publicKey;
+      await sendAndConfirmTransaction(baseConn, tx, [player], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
     }
+
+    const t = program.coder.accounts.decode(
+      'tournament',
+      (await baseConn.getAccountInfo(tournamentPda))!.data
+    );
+    expect(t.entrants.length).toBe(2);
+    expect(t.status).toHaveProperty('registration');
   });
 
-  it('rejects out of bounds', async () => {
-    const gid = new anchor.BN(Date.now());
-    const { gamePda, p1Pda } = await createAndJoin(gid);
-    const ix = await program.methods
-      .hideShips([
-        { startX: 0, startY: 0, endX: 1, endY: 0 },
-        { startX: 0, startY: 2, endX: 0, endY: 2 },
-      ])
+  it('rejects registering twice', async () => {
+    await expectError(async () => {
+      const tx = await program.methods
+        .registerTournament()
+        .accounts({
+          player: player1.publicKey,
+          tournament: tournamentPda,
+          profile: null,
+          playerGames: playerGamesPda(player1.publicKey),
+          config: configPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .transaction();
+      tx.feePayer = player1.publicKey;
+      await sendAndConfirmTransaction(baseConn, tx, [player1], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'AlreadyRegistered');
+  });
+
+  it('starts the bracket and creates the first game', async () => {
+    let tx = await program.methods
+      .startTournament()
+      .accounts({ organizer: authorityKp.publicKey, tournament: tournamentPda })
+      .transaction();
+    tx.feePayer = authorityKp.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [authorityKp], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    let t = program.coder.accounts.decode(
+      'tournament',
+      (await baseConn.getAccountInfo(tournamentPda))!.data
+    );
+    expect(t.status).toHaveProperty('inProgress');
+    expect(t.pairings.length).toBe(1);
+    const [first, second] = (t.bracket as number[]).map(
+      (i: number) => t.entrants[i].player as PublicKey
+    );
+
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [gamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, first!.toBuffer()],
+      program.programId
+    );
+    const [p2Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, second!.toBuffer()],
+      program.programId
+    );
+    tx = await program.methods
+      .startTournamentGame(0)
       .accounts({
-        player: player1.publicKey,
+        payer: authorityKp.publicKey,
+        tournament: tournamentPda,
+        config: configPda,
         game: gamePda,
-        playerBoard: p1Pda,
+        player1Board: p1Pda,
+        player2Board: p2Pda,
+        player1Games: playerGamesPda(first!),
+        player2Games: playerGamesPda(second!),
+        systemProgram: anchor.web3.SystemProgram.programId,
       })
-      .instruction();
-    try {
-      await sendAndConfirmER(erConnP1, player1, ix);
-      throw new Error('should have failed');
-    } catch {
-      // expected
-    }
+      .transaction();
+    tx.feePayer = authorityKp.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [authorityKp], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const g = program.coder.accounts.decode(
+      'game',
+      (await baseConn.getAccountInfo(gamePda))!.data
+    );
+    expect(g.status).toHaveProperty('hidingShips');
+    expect(g.player1.toBase58()).toBe(first!.toBase58());
+    expect(g.player2.toBase58()).toBe(second!.toBase58());
+
+    t = program.coder.accounts.decode(
+      'tournament',
+      (await baseConn.getAccountInfo(tournamentPda))!.data
+    );
+    expect(t.pairings[0].gameId.eq(gid)).toBe(true);
+    bracketGamePda = gamePda;
   });
 
-  it('rejects overlapping ships', async () => {
-    const gid = new anchor.BN(Date.now());
-    const { gamePda, p1Pda } = await createAndJoin(gid);
-    const ix = await program.methods
-      .hideShips([
-        { startX: 0, startY: 0, endX: 1, endY: 0 },
-        { startX: 1, startY: 0, endX: 2, endY: 0 },
-      ])
+  const recordBracketGame = async () => {
+    const t = program.coder.accounts.decode(
+      'tournament',
+      (await baseConn.getAccountInfo(tournamentPda))!.data
+    );
+    const [first, second] = (t.bracket as number[]).map(
+      (i: number) => t.entrants[i].player as PublicKey
+    );
+    const tx = await program.methods
+      .recordTournamentGame()
       .accounts({
-        player: player1.publicKey,
+        payer: authorityKp.publicKey,
+        tournament: tournamentPda,
+        game: bracketGamePda,
+        player1Games: playerGamesPda(first!),
+        player2Games: playerGamesPda(second!),
+        player1Profile: profilePda(first!),
+        player2Profile: profilePda(second!),
+        season: null,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = authorityKp.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [authorityKp], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+    return first!;
+  };
+
+  it('rejects deciding an unplayed pairing before its deadline', async () => {
+    await expectError(recordBracketGame, 'PairingNotOverdue');
+  });
+
+  it('sends the top seed through once nobody shows up', async () => {
+    await sleep((ROUND_SECS + 1) * 1000);
+    const top = await recordBracketGame();
+
+    const t = program.coder.accounts.decode(
+      'tournament',
+      (await baseConn.getAccountInfo(tournamentPda))!.data
+    );
+    expect(t.status.completed.winner.toBase58()).toBe(top.toBase58());
+    const g = program.coder.accounts.decode(
+      'game',
+      (await baseConn.getAccountInfo(bracketGamePda))!.data
+    );
+    expect(g.forfeit).toBe(true);
+    expect(g.settled).toBe(true);
+  });
+
+  it('cancels a tournament in registration and refunds the entrants', async () => {
+    const tid = await nextId('tournamentCounter');
+    const [cancelledPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('tournament'), tid.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const fee = 100_000;
+    let tx = await program.methods
+      .createTournament(4, TOURNAMENT_SETTINGS, new anchor.BN(fee), 4, [10_000], ROUND_SECS)
+      .accounts({
+        organizer: authorityKp.publicKey,
+        tournament: cancelledPda,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = authorityKp.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [authorityKp], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    tx = await program.methods
+      .registerTournament()
+      .accounts({
+        player: player1.publicKey,
+        tournament: cancelledPda,
+        profile: null,
+        playerGames: playerGamesPda(player1.publicKey),
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const before = await baseConn.getBalance(player1.publicKey);
+    tx = await program.methods
+      .cancelTournament()
+      .accounts({ organizer: authorityKp.publicKey, tournament: cancelledPda })
+      .remainingAccounts([
+        { pubkey: player1.publicKey, isSigner: false, isWritable: true },
+      ])
+      .transaction();
+    tx.feePayer = authorityKp.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [authorityKp], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const t = program.coder.accounts.decode(
+      'tournament',
+      (await baseConn.getAccountInfo(cancelledPda))!.data
+    );
+    expect(t.status).toHaveProperty('cancelled');
+    expect(await baseConn.getBalance(player1.publicKey)).toBe(before + fee);
+  });
+
+  // ─────────── Challenges ───────────
+
+  it('accepts a signed challenge once', async () => {
+    const gid = await nextGameId();
+    const [gamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), gid.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+
+    // player 1 signs the terms off-chain
+    const challenge = {
+      challenger: player1.publicKey,
+      opponent: player2.publicKey,
+      gridSize: 4,
+      wager: new anchor.BN(0),
+      expiry: new anchor.BN(Math.floor(Date.now() / 1000) + 600),
+      nonce: new anchor.BN(Date.now()),
+    };
+    const message = Buffer.concat([
+      Buffer.from('cayed:challenge'),
+      program.programId.toBuffer(),
+      program.coder.types.encode('challenge', challenge),
+    ]);
+    const [receiptPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from('challenge'),
+        player1.publicKey.toBuffer(),
+        challenge.nonce.toArrayLike(Buffer, 'le', 8),
+      ],
+      program.programId
+    );
+
+    const accept = async (gid: anchor.BN) => {
+      const id = gid.toArrayLike(Buffer, 'le', 8);
+      const [gamePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('game'), id],
+        program.programId
+      );
+      const [p1Pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('player'), id, player1.publicKey.toBuffer()],
+        program.programId
+      );
+      const [p2Pda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('player'), id, player2.publicKey.toBuffer()],
+        program.programId
+      );
+      const tx = new Transaction().add(
+        Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: player1.secretKey,
+          message,
+        }),
+        await program.methods
+          .acceptChallenge(challenge)
+          .accounts({
+            player: player2.publicKey,
+            challenger: player1.publicKey,
+            game: gamePda,
+            challengerBoard: p1Pda,
+            playerBoard: p2Pda,
+            challengerGames: playerGamesPda(player1.publicKey),
+            playerGames: playerGamesPda(player2.publicKey),
+            receipt: receiptPda,
+            escrow: null,
+            config: configPda,
+            vault: vaultPda,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .instruction()
+      );
+      tx.feePayer = player2.publicKey;
+      await sendAndConfirmTransaction(baseConn, tx, [player2], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    };
+    await accept(gid);
+
+    const raw = await baseConn.getAccountInfo(gamePda);
+    const g = program.coder.accounts.decode('game', raw!.data);
+    expect(g.status).toHaveProperty('hidingShips');
+    expect(g.player2.toBase58()).toBe(player2.publicKey.toBase58());
+
+    // the same signature can't open a second game: its receipt already exists
+    await expectError(async () => {
+      await accept(await nextGameId());
+    }, ACCOUNT_IN_USE);
+  });
+
+  // ─────────── Match Series ───────────
+
+  it('creates best-of-3 match and starts its first game', async () => {
+    const mid = await nextId('matchCounter');
+    const [matchPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('match'), mid.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+
+    let tx = await program.methods
+      .createMatch(4, new anchor.BN(0), DEFAULT_SETTINGS, 3)
+      .accounts({
+        player: player1.publicKey,
+        gameMatch: matchPda,
+        playerGames: playerGamesPda(player1.publicKey),
+        config: configPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    tx = await program.methods
+      .joinMatch()
+      .accounts({
+        player: player2.publicKey,
+        gameMatch: matchPda,
+        playerGames: playerGamesPda(player2.publicKey),
+        config: configPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player2.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player2], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [gamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player1.publicKey.toBuffer()],
+      program.programId
+    );
+    const [p2Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player2.publicKey.toBuffer()],
+      program.programId
+    );
+    tx = await program.methods
+      .startMatchGame()
+      .accounts({
+        payer: player1.publicKey,
+        gameMatch: matchPda,
+        config: configPda,
         game: gamePda,
+        player1Board: p1Pda,
+        player2Board: p2Pda,
+        player1Games: playerGamesPda(player1.publicKey),
+        player2Games: playerGamesPda(player2.publicKey),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const m = program.coder.accounts.decode(
+      'match',
+      (await baseConn.getAccountInfo(matchPda))!.data
+    );
+    expect(m.status).toHaveProperty('inProgress');
+    expect(m.winsRequired).toBe(2);
+    expect(m.games.length).toBe(1);
+
+    const g = program.coder.accounts.decode(
+      'game',
+      (await baseConn.getAccountInfo(gamePda))!.data
+    );
+    expect(g.status).toHaveProperty('hidingShips');
+    expect(g.matchId.toString()).toBe(mid.toString());
+    expect(g.nextMovePlayer1).toBe(true);
+  });
+
+  it('cancels an unjoined match and refunds the wager', async () => {
+    const mid = await nextId('matchCounter');
+    const [matchPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('match'), mid.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const wager = new anchor.BN(1_000_000);
+
+    let tx = await program.methods
+      .createMatch(4, wager, DEFAULT_SETTINGS, 3)
+      .accounts({
+        player: player1.publicKey,
+        gameMatch: matchPda,
+        playerGames: playerGamesPda(player1.publicKey),
+        config: configPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const vaultBefore = await baseConn.getBalance(vaultPda);
+    tx = await program.methods
+      .cancelMatch()
+      .accounts({
+        player: player1.publicKey,
+        gameMatch: matchPda,
+        vault: vaultPda,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const m = program.coder.accounts.decode(
+      'match',
+      (await baseConn.getAccountInfo(matchPda))!.data
+    );
+    expect(m.status).toHaveProperty('cancelled');
+    expect(await baseConn.getBalance(vaultPda)).toBe(vaultBefore - wager.toNumber());
+  });
+
+  // ─────────── Free-for-all ───────────
+
+  it('fills a 3-player game', async () => {
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [multiPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('multi_game'), id],
+      program.programId
+    );
+    const boardOf = (p: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('multi_player'), id, p.toBuffer()],
+        program.programId
+      )[0];
+
+    let tx = await program.methods
+      .createMultiGame(4, new anchor.BN(0), 3, false)
+      .accounts({
+        player: player1.publicKey,
+        multiGame: multiPda,
+        playerBoard: boardOf(player1.publicKey),
+        config: configPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    for (const kp of [player2, authorityKp]) {
+      tx = await program.methods
+        .joinMultiGame()
+        .accounts({
+          player: kp.publicKey,
+          multiGame: multiPda,
+          playerBoard: boardOf(kp.publicKey),
+          vault: vaultPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .transaction();
+      tx.feePayer = kp.publicKey;
+      await sendAndConfirmTransaction(baseConn, tx, [kp], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }
+
+    const mg = program.coder.accounts.decode(
+      'multiGame',
+      (await baseConn.getAccountInfo(multiPda))!.data
+    );
+    expect(mg.players.length).toBe(3);
+    expect(mg.status).toHaveProperty('hidingShips');
+    // the opening seat is left to the VRF draw
+    expect(mg.firstMoveDecided).toBe(false);
+  });
+
+  it('rejects team game without four seats', async () => {
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [multiPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('multi_game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('multi_player'), id, player1.publicKey.toBuffer()],
+      program.programId
+    );
+    const tx = await program.methods
+      .createMultiGame(4, new anchor.BN(0), 3, true)
+      .accounts({
+        player: player1.publicKey,
+        multiGame: multiPda,
         playerBoard: p1Pda,
+        config: configPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
       })
-      .instruction();
-    try {
-      await sendAndConfirmER(erConnP1, player1, ix);
-      throw new Error('should have failed');
-    } catch {
-      // expected
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await expectError(async () => {
+      await sendAndConfirmTransaction(baseConn, tx, [player1], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'InvalidPlayerCount');
+  });
+
+  it('cancels an unfilled game and refunds every seat', async () => {
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [multiPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('multi_game'), id],
+      program.programId
+    );
+    const boardOf = (p: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('multi_player'), id, p.toBuffer()],
+        program.programId
+      )[0];
+    const wager = new anchor.BN(1_000_000);
+
+    let tx = await program.methods
+      .createMultiGame(4, wager, 4, false)
+      .accounts({
+        player: player1.publicKey,
+        multiGame: multiPda,
+        playerBoard: boardOf(player1.publicKey),
+        config: configPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    tx = await program.methods
+      .joinMultiGame()
+      .accounts({
+        player: player2.publicKey,
+        multiGame: multiPda,
+        playerBoard: boardOf(player2.publicKey),
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player2.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player2], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const vaultBefore = await baseConn.getBalance(vaultPda);
+    tx = await program.methods
+      .cancelMultiGame()
+      .accounts({
+        player: player1.publicKey,
+        multiGame: multiPda,
+        vault: vaultPda,
+      })
+      .remainingAccounts(
+        [player1, player2].map(kp => ({
+          pubkey: kp.publicKey,
+          isSigner: false,
+          isWritable: true,
+        }))
+      )
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const mg = program.coder.accounts.decode(
+      'multiGame',
+      (await baseConn.getAccountInfo(multiPda))!.data
+    );
+    expect(mg.status).toHaveProperty('cancelled');
+    expect(await baseConn.getBalance(vaultPda)).toBe(vaultBefore - 2 * wager.toNumber());
+  });
+
+  // ─────────── Hide Ships ───────────
+
+  const P1_SHIPS = [
+    { startX: 0, startY: 0, endX: 1, endY: 0 },
+    { startX: 0, startY: 1, endX: 0, endY: 1 },
+  ];
+  const P2_SHIPS = [
+    { startX: 2, startY: 0, endX: 3, endY: 0 },
+    { startX: 1, startY: 1, endX: 1, endY: 1 },
+  ];
+
+  async function createAndJoin(gid: anchor.BN) {
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [gamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player1.publicKey.toBuffer()],
+      program.programId
+    );
+    const [p2Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player2.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const perm1 = permissionPdaFromAccount(p1Pda);
+    const perm2 = permissionPdaFromAccount(p2Pda);
+
+    // create game + permission + delegate P1 board
+    const page = await openGamesPage();
+    const createIx = await program.methods
+      .createGame(4, new anchor.BN(0), DEFAULT_SETTINGS, null, null, page)
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
+        config: configPda,
+        openGames: openGamesPda(page),
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+
+    const perm1Ix = await program.methods
+      .createPermission({ playerBoard: { gameId: gid, player: player1.publicKey } }, [
+        { flags: AUTHORITY_FLAG | TX_LOGS_FLAG, pubkey: player1.publicKey },
+      ])
+      .accounts({
+        payer: player1.publicKey,
+        permissionedAccount: p1Pda,
+        permission: perm1,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+
+    const delPerm1 = createDelegatePermissionInstruction({
+      payer: player1.publicKey,
+      authority: [player1.publicKey, true],
+      permissionedAccount: [p1Pda, false],
+      validator: ER_VALIDATOR,
+    });
+
+    const delPda1 = await program.methods
+      .delegatePda({ playerBoard: { gameId: gid, player: player1.publicKey } })
+      .accounts({ payer: player1.publicKey, pda: p1Pda, validator: ER_VALIDATOR })
+      .instruction();
+
+    let tx = new Transaction().add(createIx, perm1Ix, delPerm1, delPda1);
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    await waitUntilPermissionActive(erUrl, p1Pda);
+
+    // join + permission + delegate P2 board + game
+    const joinIx = await program.methods
+      .joinGame(null, null)
+      .accounts({
+        player: player2.publicKey,
+        game: gamePda,
+        playerBoard: p2Pda,
+        playerGames: playerGamesPda(player2.publicKey),
+        openGames: await listedOn(gid),
+        config: configPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+
+    const perm2Ix = await program.methods
+      .createPermission({ playerBoard: { gameId: gid, player: player2.publicKey } }, [
+        { flags: AUTHORITY_FLAG | TX_LOGS_FLAG, pubkey: player2.publicKey },
+      ])
+      .accounts({
+        payer: player2.publicKey,
+        permissionedAccount: p2Pda,
+        permission: perm2,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+
+    const delPerm2 = createDelegatePermissionInstruction({
+      payer: player2.publicKey,
+      authority: [player2.publicKey, true],
+      permissionedAccount: [p2Pda, false],
+      validator: ER_VALIDATOR,
+    });
+
+    const delGameIx = await program.methods
+      .delegatePda({ game: { gameId: gid } })
+      .accounts({ payer: player2.publicKey, pda: gamePda, validator: ER_VALIDATOR })
+      .instruction();
+
+    const delPda2 = await program.methods
+      .delegatePda({ playerBoard: { gameId: gid, player: player2.publicKey } })
+      .accounts({ payer: player2.publicKey, pda: p2Pda, validator: ER_VALIDATOR })
+      .instruction();
+
+    tx = new Transaction().add(joinIx, delGameIx, perm2Ix, delPerm2, delPda2);
+    tx.feePayer = player2.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player2], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    await waitUntilPermissionActive(erUrl, p2Pda);
+
+    return { gamePda, p1Pda, p2Pda };
+  }
+
+  it('hides ships on both boards', async () => {
+    const gid = await nextGameId();
+    const { gamePda, p1Pda, p2Pda } = await createAndJoin(gid);
+
+    const ix1 = await program.methods
+      .hideShips(P1_SHIPS, [], [])
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+      })
+      .instruction();
+    await sendAndConfirmER(erConnP1, player1, ix1);
+
+    const ix2 = await program.methods
+      .hideShips(P2_SHIPS, [], [])
+      .accounts({
+        player: player2.publicKey,
+        game: gamePda,
+        playerBoard: p2Pda,
+      })
+      .instruction();
+    await sendAndConfirmER(erConnP2, player2, ix2);
+
+    const b1 = program.coder.accounts.decode(
+      'playerBoard',
+      (await erConnP1.getAccountInfo(p1Pda))!.data
+    );
+    const b2 = program.coder.accounts.decode(
+      'playerBoard',
+      (await erConnP2.getAccountInfo(p2Pda))!.data
+    );
+    expect(b1.shipCoordinates.length).toBe(2);
+    expect(b2.shipCoordinates.length).toBe(2);
+  });
+
+  it('auto-places a fleet with VRF', async () => {
+    const gid = await nextGameId();
+    const { gamePda, p1Pda } = await createAndJoin(gid);
+
+    const autoPlace = async () =>
+      sendAndConfirmER(
+        erConnP1,
+        player1,
+        await program.methods
+          .autoPlaceShips(3, [...randomBytes(32)])
+          .accounts({
+            player: player1.publicKey,
+            game: gamePda,
+            playerBoard: p1Pda,
+            oracleQueue: VRF_EPHEMERAL_QUEUE,
+          })
+          .instruction()
+      );
+    await autoPlace();
+
+    let b = program.coder.accounts.decode(
+      'playerBoard',
+      (await erConnP1.getAccountInfo(p1Pda))!.data
+    );
+    for (let i = 0; i < 30 && b.shipCoordinates.length === 0; i++) {
+      await sleep(1000);
+      b = program.coder.accounts.decode(
+        'playerBoard',
+        (await erConnP1.getAccountInfo(p1Pda))!.data
+      );
+    }
+    expect(b.shipCoordinates.length).toBe(2);
+    expect(b.allShipsMask.toNumber()).not.toBe(0);
+
+    // the board is now taken
+    await expectError(async () => {
+      await autoPlace();
+    }, 'ShipsAlreadyPlaced');
+  });
+
+  it('rejects wrong ship count', async () => {
+    const gid = await nextGameId();
+    const { gamePda, p1Pda } = await createAndJoin(gid);
+    const ix = await program.methods
+      .hideShips([{ startX: 0, startY: 0, endX: 1, endY: 0 }], [], [])
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+      })
+      .instruction();
+    await expectError(async () => {
+      await sendAndConfirmER(erConnP1, player1, ix);
+    }, 'IncorrectShipsLen');
+  });
+
+  it('rejects diagonal ship', async () => {
+    const gid = await nextGameId();
+    const { gamePda, p1Pda } = await createAndJoin(gid);
+    const ix = await program.methods
+      .hideShips(
+        [
+          { startX: 0, startY: 0, endX: 1, endY: 1 },
+          { startX: 2, startY: 0, endX: 3, endY: 0 },
+        ],
+        [],
+        []
+      )
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+      })
+      .instruction();
+    await expectError(async () => {
+      await sendAndConfirmER(erConnP1, player1, ix);
+    }, 'ShipNotLinear');
+  });
+
+  it('rejects out of bounds', async () => {
+    const gid = await nextGameId();
+    const { gamePda, p1Pda } = await createAndJoin(gid);
+    const ix = await program.methods
+      .hideShips(
+        [
+          { startX: 0, startY: 0, endX: 1, endY: 0 },
+          { startX: 0, startY: 2, endX: 0, endY: 2 },
+        ],
+        [],
+        []
+      )
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+      })
+      .instruction();
+    await expectError(async () => {
+      await sendAndConfirmER(erConnP1, player1, ix);
+    }, 'InvalidShipPlacement');
+  });
+
+  it('rejects overlapping ships', async () => {
+    const gid = await nextGameId();
+    const { gamePda, p1Pda } = await createAndJoin(gid);
+    const ix = await program.methods
+      .hideShips(
+        [
+          { startX: 0, startY: 0, endX: 1, endY: 0 },
+          { startX: 1, startY: 0, endX: 2, endY: 0 },
+        ],
+        [],
+        []
+      )
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+      })
+      .instruction();
+    await expectError(async () => {
+      await sendAndConfirmER(erConnP1, player1, ix);
+    }, 'ShipOverlap');
+  });
+
+  it('rejects mines in a game without traps', async () => {
+    const gid = await nextGameId();
+    const { gamePda, p1Pda } = await createAndJoin(gid);
+    const ix = await program.methods
+      .hideShips(P1_SHIPS, [{ x: 3, y: 1 }], [])
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+      })
+      .instruction();
+    await expectError(async () => {
+      await sendAndConfirmER(erConnP1, player1, ix);
+    }, 'IncorrectTrapsLen');
+  });
+
+  // ─────────── Privacy ───────────
+
+  it('player sees own board but not opponent', async () => {
+    const gid = await nextGameId();
+    const { gamePda, p1Pda } = await createAndJoin(gid);
+
+    await sendAndConfirmER(
+      erConnP1,
+      player1,
+      await program.methods
+        .hideShips(P1_SHIPS, [], [])
+        .accounts({
+          player: player1.publicKey,
+          game: gamePda,
+          playerBoard: p1Pda,
+        })
+        .instruction()
+    );
+
+    const own = await erConnP1.getAccountInfo(p1Pda);
+    expect(own).not.toBeNull();
+
+    const sneak = await erConnP2.getAccountInfo(p1Pda);
+    expect(sneak).toBeNull();
+  });
+
+  // ─────────── Full Game: Play + Reveal ───────────
+
+  // A game both players have joined and hidden their fleets in, with the opener drawn
+  type PlayGame = {
+    gamePda: PublicKey;
+    p1Pda: PublicKey;
+    p2Pda: PublicKey;
+    p1First: boolean;
+  };
+
+  async function setUpPlayGame(
+    settings = DEFAULT_SETTINGS,
+    wager = 0,
+    fleets = { p1: P1_SHIPS, p2: P2_SHIPS }
+  ): Promise<PlayGame> {
+    const gid = await nextGameId();
+
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    const [gamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player1.publicKey.toBuffer()],
+      program.programId
+    );
+    const [p2Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player2.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const perm1 = permissionPdaFromAccount(p1Pda);
+    const perm2 = permissionPdaFromAccount(p2Pda);
+
+    // create + permission + delegate P1 board
+    const page = await openGamesPage();
+    const createIx = await program.methods
+      .createGame(4, new anchor.BN(wager), settings, null, null, page)
+      .accounts({
+        player: player1.publicKey,
+        game: gamePda,
+        playerBoard: p1Pda,
+        playerGames: playerGamesPda(player1.publicKey),
+        config: configPda,
+        openGames: openGamesPda(page),
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+
+    const perm1Ix = await program.methods
+      .createPermission({ playerBoard: { gameId: gid, player: player1.publicKey } }, [
+        { flags: AUTHORITY_FLAG | TX_LOGS_FLAG, pubkey: player1.publicKey },
+      ])
+      .accounts({
+        payer: player1.publicKey,
+        permissionedAccount: p1Pda,
+        permission: perm1,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+
+    const delPerm1 = createDelegatePermissionInstruction({
+      payer: player1.publicKey,
+      authority: [player1.publicKey, true],
+      permissionedAccount: [p1Pda, false],
+      validator: ER_VALIDATOR,
+    });
+
+    const delPda1 = await program.methods
+      .delegatePda({ playerBoard: { gameId: gid, player: player1.publicKey } })
+      .accounts({ payer: player1.publicKey, pda: p1Pda, validator: ER_VALIDATOR })
+      .instruction();
+
+    let tx = new Transaction().add(createIx, perm1Ix, delPerm1, delPda1);
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    await waitUntilPermissionActive(erUrl, p1Pda);
+
+    // join + permission + delegate P2 board + game
+    const joinIx = await program.methods
+      .joinGame(null, null)
+      .accounts({
+        player: player2.publicKey,
+        game: gamePda,
+        playerBoard: p2Pda,
+        playerGames: playerGamesPda(player2.publicKey),
+        openGames: await listedOn(gid),
+        config: configPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+
+    const perm2Ix = await program.methods
+      .createPermission({ playerBoard: { gameId: gid, player: player2.publicKey } }, [
+        { flags: AUTHORITY_FLAG | TX_LOGS_FLAG, pubkey: player2.publicKey },
+      ])
+      .accounts({
+        payer: player2.publicKey,
+        permissionedAccount: p2Pda,
+        permission: perm2,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .instruction();
+
+    const delPerm2 = createDelegatePermissionInstruction({
+      payer: player2.publicKey,
+      authority: [player2.publicKey, true],
+      permissionedAccount: [p2Pda, false],
+      validator: ER_VALIDATOR,
+    });
+
+    const delGameIx = await program.methods
+      .delegatePda({ game: { gameId: gid } })
+      .accounts({ payer: player2.publicKey, pda: gamePda, validator: ER_VALIDATOR })
+      .instruction();
+
+    const delPda2 = await program.methods
+      .delegatePda({ playerBoard: { gameId: gid, player: player2.publicKey } })
+      .accounts({ payer: player2.publicKey, pda: p2Pda, validator: ER_VALIDATOR })
+      .instruction();
+
+    tx = new Transaction().add(joinIx, delGameIx, perm2Ix, delPerm2, delPda2);
+    tx.feePayer = player2.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player2], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    await waitUntilPermissionActive(erUrl, p2Pda);
+
+    // hide ships
+    await sendAndConfirmER(
+      erConnP1,
+      player1,
+      await program.methods
+        .hideShips(fleets.p1, [], [])
+        .accounts({
+          player: player1.publicKey,
+          game: gamePda,
+          playerBoard: p1Pda,
+        })
+        .instruction()
+    );
+
+    await sendAndConfirmER(
+      erConnP2,
+      player2,
+      await program.methods
+        .hideShips(fleets.p2, [], [])
+        .accounts({
+          player: player2.publicKey,
+          game: gamePda,
+          playerBoard: p2Pda,
+        })
+        .instruction()
+    );
+
+    // draw the opening player
+    await sendAndConfirmER(
+      erConnP1,
+      player1,
+      await program.methods
+        .requestFirstMove(7)
+        .accounts({
+          player: player1.publicKey,
+          game: gamePda,
+          oracleQueue: VRF_EPHEMERAL_QUEUE,
+        })
+        .instruction()
+    );
+
+    let g = program.coder.accounts.decode(
+      'game',
+      (await erConnP1.getAccountInfo(gamePda))!.data
+    );
+    for (let i = 0; i < 30 && !g.firstMoveDecided; i++) {
+      await sleep(1000);
+      g = program.coder.accounts.decode(
+        'game',
+        (await erConnP1.getAccountInfo(gamePda))!.data
+      );
+    }
+    expect(g.firstMoveDecided).toBe(true);
+    return { gamePda, p1Pda, p2Pda, p1First: g.nextMovePlayer1 as boolean };
+  }
+
+  // Send a turn (shot, sonar, torpedo or maneuver) for player 1 or player 2
+  const play = async (game: PlayGame, asP1: boolean, method: any) => {
+    const [player, opponent] = asP1 ? [player1, player2] : [player2, player1];
+    const ix = await method
+      .accounts({
+        player: player.publicKey,
+        opponent: opponent.publicKey,
+        game: game.gamePda,
+        playerBoard: asP1 ? game.p1Pda : game.p2Pda,
+        opponentBoard: asP1 ? game.p2Pda : game.p1Pda,
+      })
+      .instruction();
+    await sendAndConfirmER(asP1 ? erConnP1 : erConnP2, player, ix);
+  };
+
+  const erGame = async (gamePda: PublicKey) =>
+    program.coder.accounts.decode('game', (await erConnP1.getAccountInfo(gamePda))!.data);
+
+  // Open both boards and hand the game back to the base layer
+  const reveal = async (game: PlayGame) => {
+    await sendAndConfirmER(
+      erConnP1,
+      player1,
+      await program.methods
+        .revealWinner()
+        .accounts({
+          game: game.gamePda,
+          player1Board: game.p1Pda,
+          player2Board: game.p2Pda,
+          permission1: permissionPdaFromAccount(game.p1Pda),
+          permission2: permissionPdaFromAccount(game.p2Pda),
+          payer: player1.publicKey,
+        })
+        .instruction()
+    );
+    await sleep(5000);
+  };
+
+  const settle = async (game: PlayGame, payer: Keypair) => {
+    const tx = await program.methods
+      .settleGame()
+      .accounts({
+        payer: payer.publicKey,
+        game: game.gamePda,
+        player1: player1.publicKey,
+        player2: player2.publicKey,
+        player1Games: playerGamesPda(player1.publicKey),
+        player2Games: playerGamesPda(player2.publicKey),
+        player1Profile: profilePda(player1.publicKey),
+        player2Profile: profilePda(player2.publicKey),
+        season: seasonPda,
+        config: configPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = payer.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [payer], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+  };
+
+  let playGamePda: PublicKey;
+  let playP1Pda: PublicKey;
+  let playP2Pda: PublicKey;
+  let p1First: boolean;
+
+  it('sets up game for playthrough', async () => {
+    ({
+      gamePda: playGamePda,
+      p1Pda: playP1Pda,
+      p2Pda: playP2Pda,
+      p1First,
+    } = await setUpPlayGame());
+  });
+
+  it('rejects wrong turn', async () => {
+    const [player, opponent] = p1First ? [player2, player1] : [player1, player2];
+    const [playerBoard, opponentBoard] = p1First
+      ? [playP2Pda, playP1Pda]
+      : [playP1Pda, playP2Pda];
+    const ix = await program.methods
+      .makeMove(0, 0)
+      .accounts({
+        player: player.publicKey,
+        opponent: opponent.publicKey,
+        game: playGamePda,
+        playerBoard,
+        opponentBoard,
+      })
+      .instruction();
+    await expectError(async () => {
+      await sendAndConfirmER(p1First ? erConnP2 : erConnP1, player, ix);
+    }, 'InvalidTurn');
+  });
+
+  it('rejects out of bounds move', async () => {
+    const [player, opponent] = p1First ? [player1, player2] : [player2, player1];
+    const [playerBoard, opponentBoard] = p1First
+      ? [playP1Pda, playP2Pda]
+      : [playP2Pda, playP1Pda];
+    const ix = await program.methods
+      .makeMove(4, 0)
+      .accounts({
+        player: player.publicKey,
+        opponent: opponent.publicKey,
+        game: playGamePda,
+        playerBoard,
+        opponentBoard,
+      })
+      .instruction();
+    await expectError(async () => {
+      await sendAndConfirmER(p1First ? erConnP1 : erConnP2, player, ix);
+    }, 'AttackOutOfBounds');
+  });
+
+  it('rejects sonar without charges', async () => {
+    const [player, opponent] = p1First ? [player1, player2] : [player2, player1];
+    const [playerBoard, opponentBoard] = p1First
+      ? [playP1Pda, playP2Pda]
+      : [playP2Pda, playP1Pda];
+    const ix = await program.methods
+      .sonarSweep(1, 0)
+      .accounts({
+        player: player.publicKey,
+        opponent: opponent.publicKey,
+        game: playGamePda,
+        playerBoard,
+        opponentBoard,
+      })
+      .instruction();
+    await expectError(async () => {
+      await sendAndConfirmER(p1First ? erConnP1 : erConnP2, player, ix);
+    }, 'NoChargesLeft');
+  });
+
+  it('rejects timeout claim in an untimed game', async () => {
+    // whoever is waiting tries to flag the player on turn
+    const waiting = p1First ? player2 : player1;
+    const ix = await program.methods
+      .claimTimeout()
+      .accounts({ player: waiting.publicKey, game: playGamePda })
+      .instruction();
+    await expectError(async () => {
+      await sendAndConfirmER(p1First ? erConnP2 : erConnP1, waiting, ix);
+    }, 'ClockNotRunning');
+  });
+
+  it('rejects maneuver when the mode is off', async () => {
+    const [player, opponent] = p1First ? [player1, player2] : [player2, player1];
+    const [playerBoard, opponentBoard] = p1First
+      ? [playP1Pda, playP2Pda]
+      : [playP2Pda, playP1Pda];
+    const ix = await program.methods
+      .maneuverShip(0, { south: {} })
+      .accounts({
+        player: player.publicKey,
+        opponent: opponent.publicKey,
+        game: playGamePda,
+        playerBoard,
+        opponentBoard,
+      })
+      .instruction();
+    await expectError(async () => {
+      await sendAndConfirmER(p1First ? erConnP1 : erConnP2, player, ix);
+    }, 'ManeuversDisabled');
+  });
+
+  it('P1 wins, reveals winner', async () => {
+    // P2 ships at (2,0)(3,0) and (1,1). P1 attacks all 3.
+    const hits = [
+      [2, 0],
+      [3, 0],
+      [1, 1],
+    ];
+    // P1 ships at (0,0)(1,0) and (0,1). P2 only finds open water.
+    const misses = [
+      [2, 0],
+      [3, 0],
+      [1, 1],
+    ];
+
+    let m = 0;
+    const p2Miss = async () => {
+      const [x, y] = misses[m++]!;
+      await sendAndConfirmER(
+        erConnP2,
+        player2,
+        await program.methods
+          .makeMove(x!, y!)
+          .accounts({
+            player: player2.publicKey,
+            opponent: player1.publicKey,
+            game: playGamePda,
+            playerBoard: playP2Pda,
+            opponentBoard: playP1Pda,
+          })
+          .instruction()
+      );
+    };
+
+    if (!p1First) await p2Miss();
+    for (let i = 0; i < hits.length; i++) {
+      await sendAndConfirmER(
+        erConnP1,
+        player1,
+        await program.methods
+          .makeMove(hits[i]![0]!, hits[i]![1]!)
+          .accounts({
+            player: player1.publicKey,
+            opponent: player2.publicKey,
+            game: playGamePda,
+            playerBoard: playP1Pda,
+            opponentBoard: playP2Pda,
+          })
+          .instruction()
+      );
+      if (i < hits.length - 1) await p2Miss();
+    }
+
+    const raw = await erConnP1.getAccountInfo(playGamePda);
+    const g = program.coder.accounts.decode('game', raw!.data);
+    expect(g.status).toHaveProperty('completed');
+    expect(g.status.completed.winner.toBase58()).toBe(player1.publicKey.toBase58());
+
+    // the winning shot sank P2's last ship
+    const last = g.moves[g.moves.length - 1];
+    expect(last.shooter.toBase58()).toBe(player1.publicKey.toBase58());
+    expect(last.sunkShip).not.toBeNull();
+    expect(last.slot.toNumber()).toBeGreaterThan(0);
+
+    // reveal
+    const perm1 = permissionPdaFromAccount(playP1Pda);
+    const perm2 = permissionPdaFromAccount(playP2Pda);
+
+    await sendAndConfirmER(
+      erConnP1,
+      player1,
+      await program.methods
+        .revealWinner()
+        .accounts({
+          game: playGamePda,
+          player1Board: playP1Pda,
+          player2Board: playP2Pda,
+          permission1: perm1,
+          permission2: perm2,
+          payer: player1.publicKey,
+        })
+        .instruction()
+    );
+    await sleep(5000);
+
+    // boards public
+    const p1r = await baseConn.getAccountInfo(playP1Pda);
+    const p1 = program.coder.accounts.decode('playerBoard', p1r!.data);
+    expect(p1.shipCoordinates.length).toBe(2);
+
+    const p2r = await baseConn.getAccountInfo(playP2Pda);
+    const p2 = program.coder.accounts.decode('playerBoard', p2r!.data);
+    expect(p2.shipCoordinates.length).toBe(2);
+
+    const gr = await baseConn.getAccountInfo(playGamePda);
+    const g2 = program.coder.accounts.decode('game', gr!.data);
+    expect(g2.status).toHaveProperty('winnerRevealed');
+    expect(g2.status.winnerRevealed.winner.toBase58()).toBe(player1.publicKey.toBase58());
+  });
+
+  it('offers a rematch on the finished game', async () => {
+    const tx = await program.methods
+      .offerRematch(false)
+      .accounts({
+        player: player2.publicKey,
+        game: playGamePda,
+        config: configPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player2.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player2], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const g = program.coder.accounts.decode(
+      'game',
+      (await baseConn.getAccountInfo(playGamePda))!.data
+    );
+    expect(g.rematch.from.toBase58()).toBe(player2.publicKey.toBase58());
+    expect(g.rematch.doubleOrNothing).toBe(false);
+  });
+
+  it('rejects a second rematch offer', async () => {
+    await expectError(async () => {
+      const tx = await program.methods
+        .offerRematch(true)
+        .accounts({
+          player: player1.publicKey,
+          game: playGamePda,
+          config: configPda,
+          vault: vaultPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .transaction();
+      tx.feePayer = player1.publicKey;
+      await sendAndConfirmTransaction(baseConn, tx, [player1], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'RematchAlreadyOffered');
+  });
+
+  // ─────────── Seasons ───────────
+
+  let seasonPda: PublicKey;
+
+  it('opens a season ranked by wins', async () => {
+    const sid = new anchor.BN(Date.now());
+    [seasonPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('season'), sid.toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    );
+    const slot = await baseConn.getSlot();
+    const tx = await program.methods
+      .createSeason(
+        sid,
+        new anchor.BN(slot),
+        new anchor.BN(slot + 1_000_000),
+        { wins: {} },
+        new anchor.BN(1_000_000),
+        [10_000]
+      )
+      .accounts({
+        authority: authorityKp.publicKey,
+        season: seasonPda,
+        config: configPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = authorityKp.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [authorityKp], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const raw = await baseConn.getAccountInfo(configPda);
+    const c = program.coder.accounts.decode('config', raw!.data);
+    expect(c.season.eq(sid)).toBe(true);
+
+    // The prize pool sits in the season account, not the shared vault
+    const season = await baseConn.getAccountInfo(seasonPda);
+    const rent = await baseConn.getMinimumBalanceForRentExemption(
+      season!.data.length
+    );
+    expect(season!.lamports - rent).toBe(1_000_000);
+  });
+
+  it('rejects closing a season before it ends', async () => {
+    await expectError(async () => {
+      const tx = await program.methods
+        .closeSeason()
+        .accounts({
+          authority: authorityKp.publicKey,
+          season: seasonPda,
+          config: configPda,
+        })
+        .transaction();
+      tx.feePayer = authorityKp.publicKey;
+      await sendAndConfirmTransaction(baseConn, tx, [authorityKp], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'SeasonNotOver');
+  });
+
+  it('rejects claiming a season prize before the season closes', async () => {
+    await expectError(async () => {
+      const tx = await program.methods
+        .claimSeasonPrize()
+        .accounts({
+          player: player1.publicKey,
+          season: seasonPda,
+        })
+        .transaction();
+      tx.feePayer = player1.publicKey;
+      await sendAndConfirmTransaction(baseConn, tx, [player1], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'SeasonNotClosed');
+  });
+
+  it('settles the game and drops it from both indexes', async () => {
+    const tx = await program.methods
+      .settleGame()
+      .accounts({
+        payer: player1.publicKey,
+        game: playGamePda,
+        player1: player1.publicKey,
+        player2: player2.publicKey,
+        player1Games: playerGamesPda(player1.publicKey),
+        player2Games: playerGamesPda(player2.publicKey),
+        player1Profile: profilePda(player1.publicKey),
+        player2Profile: profilePda(player2.publicKey),
+        season: seasonPda,
+        config: configPda,
+        vault: vaultPda,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .transaction();
+    tx.feePayer = player1.publicKey;
+    await sendAndConfirmTransaction(baseConn, tx, [player1], {
+      skipPreflight: true,
+      commitment: 'confirmed',
+    });
+
+    const g = program.coder.accounts.decode(
+      'game',
+      (await baseConn.getAccountInfo(playGamePda))!.data
+    );
+    expect(g.settled).toBe(true);
+    expect(g.rematch).toBeNull();
+
+    for (const player of [player1, player2]) {
+      const raw = await baseConn.getAccountInfo(playerGamesPda(player.publicKey));
+      const index = program.coder.accounts.decode('playerGames', raw!.data);
+      expect(index.games.some((id: anchor.BN) => id.eq(g.id))).toBe(false);
+    }
+
+    const raw = await baseConn.getAccountInfo(profilePda(player1.publicKey));
+    const winner = program.coder.accounts.decode('playerProfile', raw!.data);
+    expect(winner.gamesWon).toBe(1);
+    expect(winner.shots).toBe(3);
+    expect(winner.hits).toBe(3);
+    expect(winner.shipsSunk).toBe(2);
+    expect(winner.fastestWin).toBe(3);
+    // unranked, so the rating is untouched
+    expect(winner.rating).toBe(1200);
+
+    const season = program.coder.accounts.decode(
+      'season',
+      (await baseConn.getAccountInfo(seasonPda))!.data
+    );
+    expect(season.standings[0].player.toBase58()).toBe(player1.publicKey.toBase58());
+    expect(season.standings[0].score).toBe(1);
+  });
+
+  it('rejects accepting a rematch once settled', async () => {
+    const id = (await nextGameId()).toArrayLike(Buffer, 'le', 8);
+    const [newGamePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('game'), id],
+      program.programId
+    );
+    const [p1Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player1.publicKey.toBuffer()],
+      program.programId
+    );
+    const [p2Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('player'), id, player2.publicKey.toBuffer()],
+      program.programId
+    );
+    await expectError(async () => {
+      const tx = await program.methods
+        .acceptRematch()
+        .accounts({
+          player: player1.publicKey,
+          game: playGamePda,
+          newGame: newGamePda,
+          player1: player1.publicKey,
+          player2: player2.publicKey,
+          player1Board: p1Pda,
+          player2Board: p2Pda,
+          player1Games: playerGamesPda(player1.publicKey),
+          player2Games: playerGamesPda(player2.publicKey),
+          player1Profile: profilePda(player1.publicKey),
+          player2Profile: profilePda(player2.publicKey),
+          season: seasonPda,
+          config: configPda,
+          vault: vaultPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .transaction();
+      tx.feePayer = player1.publicKey;
+      await sendAndConfirmTransaction(baseConn, tx, [player1], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'GameAlreadySettled');
+  });
+
+  // ─────────── Special Weapons ───────────
+
+  it('sweeps with sonar and fires a torpedo down a row', async () => {
+    const game = await setUpPlayGame({
+      ...DEFAULT_SETTINGS,
+      sonarCharges: 1,
+      torpedoCharges: 1,
+    });
+    const opener = game.p1First;
+
+    // the sonar centred on (2, 0) counts ship cells around it without saying which
+    await play(game, opener, program.methods.sonarSweep(2, 0));
+    // the reply runs in from the east along row 1 until it strikes a ship
+    await play(game, !opener, program.methods.fireTorpedo(1, true));
+
+    const g = await erGame(game.gamePda);
+    const [sonar, torpedo] = g.moves;
+    expect(sonar.outcome.sonar.shipCells).toBe(opener ? 3 : 1);
+    expect(torpedo.outcome.torpedo.result).toHaveProperty('hit');
+    // P1's single-cell ship sits at (0, 1), P2's at (1, 1)
+    expect(torpedo.x).toBe(opener ? 0 : 1);
+    expect(g.chargesPlayer1.sonar + g.chargesPlayer2.sonar).toBe(1);
+    expect(g.chargesPlayer1.torpedo + g.chargesPlayer2.torpedo).toBe(1);
+  });
+
+  // ─────────── Team Games ───────────
+
+  // ER connection able to read what `kp` is permitted to see
+  const erConnFor = async (kp: Keypair): Promise<Connection> => {
+    if (kp === player1) return erConnP1;
+    if (kp === player2) return erConnP2;
+    if (!erUrl.includes('tee')) return erConn;
+    const clean = erUrl.replace(/\/$/, '');
+    const { token } = await getAuthToken(clean, kp.publicKey, m =>
+      Promise.resolve(nacl.sign.detached(m, kp.secretKey))
+    );
+    return new Connection(`${clean}?token=${token}`, {
+      wsEndpoint: `${erWs}?token=${token}`,
+      commitment: 'confirmed',
+    });
+  };
+
+  // Seat order is join order: player1 and player2 sit at 0 and 2 and play as a team
+  let teamPda: PublicKey;
+  let teamSeats: Keypair[];
+  let teamBoards: PublicKey[];
+  let teamConns: Connection[];
+
+  const teamGame = async () =>
+    program.coder.accounts.decode(
+      'multiGame',
+      (await teamConns[0]!.getAccountInfo(teamPda))!.data
+    );
+
+  const teamMove = (seat: number, target: number, x: number, y: number) =>
+    program.methods
+      .multiMakeMove(x, y)
+      .accounts({
+        player: teamSeats[seat]!.publicKey,
+        target: teamSeats[target]!.publicKey,
+        multiGame: teamPda,
+        targetBoard: teamBoards[target],
+      })
+      .instruction();
+
+  it('seats a 2v2 game on the ER', async () => {
+    const fourth = Keypair.generate();
+    let tx = new Transaction().add(
+      anchor.web3.SystemProgram.transfer({
+        fromPubkey: authorityKp.publicKey,
+        toPubkey: fourth.publicKey,
+        lamports: anchor.web3.LAMPORTS_PER_SOL / 2,
+      })
+    );
+    await sendAndConfirmTransaction(baseConn, tx, [authorityKp], {
+      commitment: 'confirmed',
+    });
+
+    const gid = await nextGameId();
+    const id = gid.toArrayLike(Buffer, 'le', 8);
+    [teamPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('multi_game'), id],
+      program.programId
+    );
+    teamSeats = [player1, authorityKp, player2, fourth];
+    teamBoards = teamSeats.map(
+      kp =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from('multi_player'), id, kp.publicKey.toBuffer()],
+          program.programId
+        )[0]
+    );
+
+    for (const [seat, kp] of teamSeats.entries()) {
+      const board = teamBoards[seat]!;
+      const seatIx =
+        seat === 0
+          ? await program.methods
+              .createMultiGame(4, new anchor.BN(0), 4, true)
+              .accounts({
+                player: kp.publicKey,
+                multiGame: teamPda,
+                playerBoard: board,
+                config: configPda,
+                vault: vaultPda,
+                systemProgram: anchor.web3.SystemProgram.programId,
+              })
+              .instruction()
+          : await program.methods
+              .joinMultiGame()
+              .accounts({
+                player: kp.publicKey,
+                multiGame: teamPda,
+                playerBoard: board,
+                vault: vaultPda,
+                systemProgram: anchor.web3.SystemProgram.programId,
+              })
+              .instruction();
+
+      const permIx = await program.methods
+        .createPermission({ multiPlayerBoard: { gameId: gid, player: kp.publicKey } }, [
+          { flags: AUTHORITY_FLAG | TX_LOGS_FLAG, pubkey: kp.publicKey },
+        ])
+        .accounts({
+          payer: kp.publicKey,
+          permissionedAccount: board,
+          permission: permissionPdaFromAccount(board),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .instruction();
+
+      const delPerm = createDelegatePermissionInstruction({
+        payer: kp.publicKey,
+        authority: [kp.publicKey, true],
+        permissionedAccount: [board, false],
+        validator: ER_VALIDATOR,
+      });
+
+      const delBoard = await program.methods
+        .delegatePda({ multiPlayerBoard: { gameId: gid, player: kp.publicKey } })
+        .accounts({ payer: kp.publicKey, pda: board, validator: ER_VALIDATOR })
+        .instruction();
+
+      tx = new Transaction().add(seatIx, permIx, delPerm, delBoard);
+      // the last seat hands the game itself to the ER
+      if (seat === teamSeats.length - 1) {
+        tx.add(
+          await program.methods
+            .delegatePda({ multiGame: { gameId: gid } })
+            .accounts({ payer: kp.publicKey, pda: teamPda, validator: ER_VALIDATOR })
+            .instruction()
+        );
+      }
+      tx.feePayer = kp.publicKey;
+      await sendAndConfirmTransaction(baseConn, tx, [kp], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+      await waitUntilPermissionActive(erUrl, board);
+    }
+
+    teamConns = await Promise.all(teamSeats.map(erConnFor));
+    for (const [seat, kp] of teamSeats.entries()) {
+      await sendAndConfirmER(
+        teamConns[seat]!,
+        kp,
+        await program.methods
+          .hideMultiShips(seat % 2 === 0 ? P1_SHIPS : P2_SHIPS)
+          .accounts({
+            player: kp.publicKey,
+            multiGame: teamPda,
+            playerBoard: teamBoards[seat],
+          })
+          .instruction()
+      );
+    }
+
+    // nobody fires before the opening seat is drawn
+    await expectError(async () => {
+      await sendAndConfirmER(teamConns[0]!, player1, await teamMove(0, 1, 3, 1));
+    }, 'FirstMoveNotDecided');
+
+    await sendAndConfirmER(
+      teamConns[0]!,
+      player1,
+      await program.methods
+        .requestMultiFirstMove(7)
+        .accounts({
+          player: player1.publicKey,
+          multiGame: teamPda,
+          oracleQueue: VRF_EPHEMERAL_QUEUE,
+        })
+        .instruction()
+    );
+
+    let mg = await teamGame();
+    for (let i = 0; i < 30 && !mg.firstMoveDecided; i++) {
+      await sleep(1000);
+      mg = await teamGame();
     }
+    expect(mg.teams).toBe(true);
+    expect(mg.shipsPlacedMask).toBe(0b1111);
+    expect(mg.firstMoveDecided).toBe(true);
+    expect(mg.turn).toBeLessThan(4);
   });
 
-  // ─────────── Privacy ───────────
-
-  it('player sees own board but not opponent', async () => {
-    const gid = new anchor.BN(Date.now());
-    const { gamePda, p1Pda } = await createAndJoin(gid);
+  it('shares a board with the teammate and nobody else', async () => {
+    // player2 at seat 2 can't see player1's fleet until it is shared
+    expect(await erConnP2.getAccountInfo(teamBoards[0]!)).toBeNull();
 
     await sendAndConfirmER(
       erConnP1,
       player1,
       await program.methods
-        .hideShips(P1_SHIPS)
+        .shareBoardWithTeam()
         .accounts({
           player: player1.publicKey,
-          game: gamePda,
-          playerBoard: p1Pda,
+          multiGame: teamPda,
+          playerBoard: teamBoards[0],
+          permission: permissionPdaFromAccount(teamBoards[0]!),
         })
         .instruction()
     );
 
-    const own = await erConnP1.getAccountInfo(p1Pda);
-    expect(own).not.toBeNull();
-
-    const sneak = await erConnP2.getAccountInfo(p1Pda);
-    expect(sneak).toBeNull();
+    expect(await erConnP2.getAccountInfo(teamBoards[0]!)).not.toBeNull();
+    // the opponents on seats 1 and 3 are still locked out
+    expect(await teamConns[1]!.getAccountInfo(teamBoards[0]!)).toBeNull();
+    expect(await teamConns[3]!.getAccountInfo(teamBoards[0]!)).toBeNull();
   });
 
-  // ─────────── Full Game: Play + Reveal ───────────
-
-  let playGamePda: PublicKey;
-  let playP1Pda: PublicKey;
-  let playP2Pda: PublicKey;
-
-  it('sets up game for playthrough', async () => {
-    let gid = new anchor.BN(Date.now());
-    if (gid.toNumber() % 2 !== 0) gid = new anchor.BN(gid.toNumber() + 1); // P1 first
-
+  it('rejects sharing a board outside a team game', async () => {
+    const gid = await nextGameId();
     const id = gid.toArrayLike(Buffer, 'le', 8);
-    [playGamePda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('game'), id],
-      program.programId
-    );
-    [playP1Pda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('player'), id, player1.publicKey.toBuffer()],
+    const [multiPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('multi_game'), id],
       program.programId
     );
-    [playP2Pda] = PublicKey.findProgramAddressSync(
-      [Buffer.from('player'), id, player2.publicKey.toBuffer()],
+    const [boardPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('multi_player'), id, player1.publicKey.toBuffer()],
       program.programId
     );
-
-    const perm1 = permissionPdaFromAccount(playP1Pda);
-    const perm2 = permissionPdaFromAccount(playP2Pda);
-
-    // create + permission + delegate P1 board
     const createIx = await program.methods
-      .createGame(gid, 4, new anchor.BN(0))
+      .createMultiGame(4, new anchor.BN(0), 4, false)
       .accounts({
         player: player1.publicKey,
-        game: playGamePda,
-        playerBoard: playP1Pda,
+        multiGame: multiPda,
+        playerBoard: boardPda,
         config: configPda,
         vault: vaultPda,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .instruction();
-
-    const perm1Ix = await program.methods
-      .createPermission({ playerBoard: { gameId: gid, player: player1.publicKey } }, [
-        { flags: AUTHORITY_FLAG | TX_LOGS_FLAG, pubkey: player1.publicKey },
-      ])
+    const shareIx = await program.methods
+      .shareBoardWithTeam()
       .accounts({
-        payer: player1.publicKey,
-        permissionedAccount: playP1Pda,
-        permission: perm1,
-        systemProgram: anchor.web3.SystemProgram.programId,
+        player: player1.publicKey,
+        multiGame: multiPda,
+        playerBoard: boardPda,
+        permission: permissionPdaFromAccount(boardPda),
       })
       .instruction();
-
-    const delPerm1 = createDelegatePermissionInstruction({
-      payer: player1.publicKey,
-      authority: [player1.publicKey, true],
-      permissionedAccount: [playP1Pda, false],
-      validator: ER_VALIDATOR,
-    });
-
-    const delPda1 = await program.methods
-      .delegatePda({ playerBoard: { gameId: gid, player: player1.publicKey } })
-      .accounts({ payer: player1.publicKey, pda: playP1Pda, validator: ER_VALIDATOR })
-      .instruction();
-
-    let tx = new Transaction().add(createIx, perm1Ix, delPerm1, delPda1);
+    const tx = new Transaction().add(createIx, shareIx);
     tx.feePayer = player1.publicKey;
-    await sendAndConfirmTransaction(baseConn, tx, [player1], {
-      skipPreflight: true,
-      commitment: 'confirmed',
-    });
-
-    await waitUntilPermissionActive(erUrl, playP1Pda);
+    await expectError(async () => {
+      await sendAndConfirmTransaction(baseConn, tx, [player1], {
+        skipPreflight: true,
+        commitment: 'confirmed',
+      });
+    }, 'NotATeamGame');
+  });
 
-    // join + permission + delegate P2 board + game
-    const joinIx = await program.methods
-      .joinGame()
-      .accounts({
-        player: player2.publicKey,
-        game: playGamePda,
-        playerBoard: playP2Pda,
-        vault: vaultPda,
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .instruction();
+  it('rejects firing on a teammate', async () => {
+    const seat = (await teamGame()).turn;
+    await expectError(async () => {
+      await sendAndConfirmER(
+        teamConns[seat]!,
+        teamSeats[seat]!,
+        await teamMove(seat, (seat + 2) % 4, 3, 1)
+      );
+    }, 'InvalidOpponent');
+  });
 
-    const perm2Ix = await program.methods
-      .createPermission({ playerBoard: { gameId: gid, player: player2.publicKey } }, [
-        { flags: AUTHORITY_FLAG | TX_LOGS_FLAG, pubkey: player2.publicKey },
-      ])
-      .accounts({
-        payer: player2.publicKey,
-        permissionedAccount: playP2Pda,
-        permission: perm2,
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .instruction();
+  it('alternates teams and rotates teammates', async () => {
+    const shooters: number[] = [];
+    for (let shot = 0; shot < 4; shot++) {
+      const seat = (await teamGame()).turn;
+      // (3, 1) is open water on every fleet, so nobody is sunk
+      await sendAndConfirmER(
+        teamConns[seat]!,
+        teamSeats[seat]!,
+        await teamMove(seat, (seat + 1) % 4, 3, 1)
+      );
+      shooters.push(seat);
+    }
 
-    const delPerm2 = createDelegatePermissionInstruction({
-      payer: player2.publicKey,
-      authority: [player2.publicKey, true],
-      permissionedAccount: [playP2Pda, false],
-      validator: ER_VALIDATOR,
-    });
+    for (let i = 1; i < shooters.length; i++) {
+      expect(shooters[i]! % 2).not.toBe(shooters[i - 1]! % 2);
+    }
+    expect([...shooters].sort()).toEqual([0, 1, 2, 3]);
 
-    const delGameIx = await program.methods
-      .delegatePda({ game: { gameId: gid } })
-      .accounts({ payer: player2.publicKey, pda: playGamePda, validator: ER_VALIDATOR })
-      .instruction();
+    const mg = await teamGame();
+    expect(mg.status).toHaveProperty('inProgress');
+    expect(mg.moves.length).toBe(4);
+    expect(mg.turn).toBe(shooters[0]);
+  });
 
-    const delPda2 = await program.methods
-      .delegatePda({ playerBoard: { gameId: gid, player: player2.publicKey } })
-      .accounts({ payer: player2.publicKey, pda: playP2Pda, validator: ER_VALIDATOR })
-      .instruction();
+  // ─────────── Time Banks ───────────
+
+  const TIMED_SETTINGS = { ...DEFAULT_SETTINGS, timeBankSecs: 20, incrementSecs: 20 };
+  const TIMED_WAGER = 1_000_000;
+  let timedGame: PlayGame;
+
+  it('adds the increment to the bank of the player who moved', async () => {
+    timedGame = await setUpPlayGame(TIMED_SETTINGS, TIMED_WAGER);
+    const opener = timedGame.p1First;
+    // (3, 1) is open water in both fleets
+    await play(timedGame, opener, program.methods.makeMove(3, 1));
+
+    const g = await erGame(timedGame.gamePda);
+    const [moved, waiting] = opener
+      ? [g.timeLeftPlayer1, g.timeLeftPlayer2]
+      : [g.timeLeftPlayer2, g.timeLeftPlayer1];
+    // whatever the move took, the increment more than made up for it
+    expect(moved).toBeGreaterThan(TIMED_SETTINGS.timeBankSecs);
+    expect(moved).toBeLessThanOrEqual(
+      TIMED_SETTINGS.timeBankSecs + TIMED_SETTINGS.incrementSecs
+    );
+    expect(waiting).toBe(TIMED_SETTINGS.timeBankSecs);
+    expect(g.turnStartedAt.toNumber()).toBeGreaterThan(0);
+  });
 
-    tx = new Transaction().add(joinIx, delGameIx, perm2Ix, delPerm2, delPda2);
-    tx.feePayer = player2.publicKey;
-    await sendAndConfirmTransaction(baseConn, tx, [player2], {
-      skipPreflight: true,
-      commitment: 'confirmed',
-    });
+  it('rejects a timeout claim while the opponent has time', async () => {
+    const opener = timedGame.p1First;
+    const [player, conn] = opener ? [player1, erConnP1] : [player2, erConnP2];
+    await expectError(async () => {
+      await sendAndConfirmER(
+        conn,
+        player,
+        await program.methods
+          .claimTimeout()
+          .accounts({ player: player.publicKey, game: timedGame.gamePda })
+          .instruction()
+      );
+    }, 'OpponentHasTime');
+  });
 
-    await waitUntilPermissionActive(erUrl, playP2Pda);
+  it('forfeits a player whose bank runs out and pays the winner', async () => {
+    const opener = timedGame.p1First;
+    const [winner, loser, conn] = opener
+      ? [player1, player2, erConnP1]
+      : [player2, player1, erConnP2];
 
-    // hide ships
+    await sleep((TIMED_SETTINGS.timeBankSecs + 2) * 1000);
     await sendAndConfirmER(
-      erConnP1,
-      player1,
+      conn,
+      winner,
       await program.methods
-        .hideShips(P1_SHIPS)
-        .accounts({
-          player: player1.publicKey,
-          game: playGamePda,
-          playerBoard: playP1Pda,
-        })
+        .claimTimeout()
+        .accounts({ player: winner.publicKey, game: timedGame.gamePda })
         .instruction()
     );
 
-    await sendAndConfirmER(
-      erConnP2,
-      player2,
-      await program.methods
-        .hideShips(P2_SHIPS)
-        .accounts({
-          player: player2.publicKey,
-          game: playGamePda,
-          playerBoard: playP2Pda,
-        })
-        .instruction()
+    const g = await erGame(timedGame.gamePda);
+    expect(g.status).toHaveProperty('forfeited');
+    expect(g.status.forfeited.winner.toBase58()).toBe(winner.publicKey.toBase58());
+    expect(opener ? g.timeLeftPlayer2 : g.timeLeftPlayer1).toBe(0);
+
+    await reveal(timedGame);
+    const revealed = program.coder.accounts.decode(
+      'game',
+      (await baseConn.getAccountInfo(timedGame.gamePda))!.data
+    );
+    expect(revealed.status.winnerRevealed.winner.toBase58()).toBe(
+      winner.publicKey.toBase58()
+    );
+    expect(revealed.forfeit).toBe(true);
+
+    const { fee } = program.coder.accounts.decode(
+      'config',
+      (await baseConn.getAccountInfo(configPda))!.data
+    );
+    const pot = 2 * TIMED_WAGER;
+    const winnerBefore = await baseConn.getBalance(winner.publicKey);
+    // the loser pays the fees, so the winner's balance moves by the payout alone
+    await settle(timedGame, loser);
+    expect(await baseConn.getBalance(winner.publicKey)).toBe(
+      winnerBefore + pot - Math.floor((pot * fee) / 10_000)
     );
   });
 
-  it('rejects wrong turn', async () => {
-    const ix = await program.methods
-      .makeMove(0, 0)
-      .accounts({
-        player: player2.publicKey,
-        opponent: player1.publicKey,
-        game: playGamePda,
-        playerBoard: playP2Pda,
-        opponentBoard: playP1Pda,
-      })
-      .instruction();
-    try {
-      await sendAndConfirmER(erConnP2, player2, ix);
-      throw new Error('should have failed');
-    } catch {
-      // expected
-    }
+  // ─────────── Handicaps ───────────
+
+  it('hides an extra single-cell ship for the handicapped player', async () => {
+    const game = await setUpPlayGame(
+      { ...DEFAULT_SETTINGS, handicap: { extraShip: { player1: true } } },
+      0,
+      { p1: [...P1_SHIPS, { startX: 3, startY: 1, endX: 3, endY: 1 }], p2: P2_SHIPS }
+    );
+
+    const p1 = program.coder.accounts.decode(
+      'playerBoard',
+      (await erConnP1.getAccountInfo(game.p1Pda))!.data
+    );
+    expect(p1.shipCoordinates.length).toBe(P1_SHIPS.length + 1);
+    const p2 = program.coder.accounts.decode(
+      'playerBoard',
+      (await erConnP2.getAccountInfo(game.p2Pda))!.data
+    );
+    expect(p2.shipCoordinates.length).toBe(P2_SHIPS.length);
   });
 
-  it('rejects out of bounds move', async () => {
-    const ix = await program.methods
-      .makeMove(4, 0)
-      .accounts({
-        player: player1.publicKey,
-        opponent: player2.publicKey,
-        game: playGamePda,
-        playerBoard: playP1Pda,
-        opponentBoard: playP2Pda,
-      })
-      .instruction();
-    try {
-      await sendAndConfirmER(erConnP1, player1, ix);
-      throw new Error('should have failed');
-    } catch {
-      // expected
-    }
+  it('keeps the turn for bonus shots', async () => {
+    const game = await setUpPlayGame({
+      ...DEFAULT_SETTINGS,
+      handicap: { bonusShots: { player1: true, shots: 1 } },
+    });
+    // P1 ships leave (3, 1) open; P2 ships leave (0, 0) and (2, 1) open
+    if (!game.p1First) await play(game, false, program.methods.makeMove(3, 1));
+
+    await play(game, true, program.methods.makeMove(0, 0));
+    let g = await erGame(game.gamePda);
+    expect(g.nextMovePlayer1).toBe(true);
+    expect(g.bonusShotsFired).toBe(1);
+
+    await play(game, true, program.methods.makeMove(2, 1));
+    g = await erGame(game.gamePda);
+    expect(g.nextMovePlayer1).toBe(false);
+    expect(g.bonusShotsFired).toBe(0);
   });
 
-  it('P1 wins, reveals winner', async () => {
-    // P2 ships at (2,0)(3,0) and (1,1). P1 attacks all 3.
-    const hits = [
-      [2, 0],
-      [3, 0],
-      [1, 1],
-    ];
-    const misses = [
-      [0, 1],
-      [0, 0],
-    ];
+  // ─────────── Limited Ammo ───────────
 
-    for (let i = 0; i < hits.length; i++) {
-      await sendAndConfirmER(
-        erConnP1,
-        player1,
-        await program.methods
-          .makeMove(hits[i]![0]!, hits[i]![1]!)
-          .accounts({
-            player: player1.publicKey,
-            opponent: player2.publicKey,
-            game: playGamePda,
-            playerBoard: playP1Pda,
-            opponentBoard: playP2Pda,
-          })
-          .instruction()
-      );
-      if (i < misses.length) {
-        await sendAndConfirmER(
-          erConnP2,
-          player2,
-          await program.methods
-            .makeMove(misses[i]![0]!, misses[i]![1]!)
-            .accounts({
-              player: player2.publicKey,
-              opponent: player1.publicKey,
-              game: playGamePda,
-              playerBoard: playP2Pda,
-              opponentBoard: playP1Pda,
-            })
-            .instruction()
-        );
+  const AMMO_SETTINGS = { ...DEFAULT_SETTINGS, ammo: 2 };
+
+  // Fire both players' shots in turn, the opener first
+  const fireAll = async (game: PlayGame, p1Shots: number[][], p2Shots: number[][]) => {
+    for (let i = 0; i < p1Shots.length; i++) {
+      for (const asP1 of game.p1First ? [true, false] : [false, true]) {
+        const [x, y] = (asP1 ? p1Shots : p2Shots)[i]!;
+        await play(game, asP1, program.methods.makeMove(x!, y!));
       }
     }
+  };
+
+  it('breaks a tie on hits by ships sunk once the ammo is spent', async () => {
+    const game = await setUpPlayGame(AMMO_SETTINGS);
+    // One hit each, but only P1's sinks a ship: P2's single cell at (1, 1)
+    await fireAll(
+      game,
+      [
+        [1, 1],
+        [0, 0],
+      ],
+      [
+        [0, 0],
+        [3, 1],
+      ]
+    );
 
-    const raw = await erConnP1.getAccountInfo(playGamePda);
-    const g = program.coder.accounts.decode('game', raw!.data);
-    expect(g.status).toHaveProperty('completed');
-    expect(g.status.completed.winner.toBase58()).toBe(player1.publicKey.toBase58());
+    const g = await erGame(game.gamePda);
+    expect(g.shotsFiredPlayer1).toBe(2);
+    expect(g.shotsFiredPlayer2).toBe(2);
+    expect(g.status).toHaveProperty('outOfAmmo');
+    expect(g.status.outOfAmmo.winner.toBase58()).toBe(player1.publicKey.toBase58());
 
-    // reveal
-    const perm1 = permissionPdaFromAccount(playP1Pda);
-    const perm2 = permissionPdaFromAccount(playP2Pda);
+    await reveal(game);
+    const revealed = program.coder.accounts.decode(
+      'game',
+      (await baseConn.getAccountInfo(game.gamePda))!.data
+    );
+    expect(revealed.status.winnerRevealed.winner.toBase58()).toBe(
+      player1.publicKey.toBase58()
+    );
+    expect(revealed.forfeit).toBe(false);
+  });
 
-    await sendAndConfirmER(
-      erConnP1,
-      player1,
-      await program.methods
-        .revealWinner()
-        .accounts({
-          game: playGamePda,
-          player1Board: playP1Pda,
-          player2Board: playP2Pda,
-          permission1: perm1,
-          permission2: perm2,
-          payer: player1.publicKey,
-        })
-        .instruction()
+  it('draws level damage and hands both stakes back', async () => {
+    const wager = 1_000_000;
+    const game = await setUpPlayGame(AMMO_SETTINGS, wager);
+    // Open water only on both boards
+    await fireAll(
+      game,
+      [
+        [0, 0],
+        [2, 1],
+      ],
+      [
+        [3, 1],
+        [2, 1],
+      ]
     );
-    await sleep(5000);
 
-    // boards public
-    const p1r = await baseConn.getAccountInfo(playP1Pda);
-    const p1 = program.coder.accounts.decode('playerBoard', p1r!.data);
-    expect(p1.shipCoordinates.length).toBe(2);
+    const g = await erGame(game.gamePda);
+    expect(g.status).toHaveProperty('outOfAmmo');
+    expect(g.status.outOfAmmo.winner).toBeNull();
 
-    const p2r = await baseConn.getAccountInfo(playP2Pda);
-    const p2 = program.coder.accounts.decode('playerBoard', p2r!.data);
-    expect(p2.shipCoordinates.length).toBe(2);
+    await reveal(game);
+    const revealed = program.coder.accounts.decode(
+      'game',
+      (await baseConn.getAccountInfo(game.gamePda))!.data
+    );
+    expect(revealed.status).toHaveProperty('drawn');
 
-    const gr = await baseConn.getAccountInfo(playGamePda);
-    const g2 = program.coder.accounts.decode('game', gr!.data);
-    expect(g2.status).toHaveProperty('winnerRevealed');
-    expect(g2.status.winnerRevealed.winner.toBase58()).toBe(player1.publicKey.toBase58());
+    const before = await Promise.all(
+      [player1, player2].map(kp => baseConn.getBalance(kp.publicKey))
+    );
+    await settle(game, authorityKp);
+    const after = await Promise.all(
+      [player1, player2].map(kp => baseConn.getBalance(kp.publicKey))
+    );
+    expect(after[0]! - before[0]!).toBe(wager);
+    expect(after[1]! - before[1]!).toBe(wager);
+  });
+
+  // ─────────── Maneuvers ───────────
+
+  it('slips a ship out from under the next shot', async () => {
+    const game = await setUpPlayGame({ ...DEFAULT_SETTINGS, maneuvers: true });
+    const opener = game.p1First;
+    // the opener's single-cell ship sits at (0, 1) for P1 and (1, 1) for P2
+    const from = opener ? 0 : 1;
+    await play(game, opener, program.methods.maneuverShip(1, { east: {} }));
+    await play(game, !opener, program.methods.makeMove(from, 1));
+
+    const [conn, boardPda] = opener ? [erConnP1, game.p1Pda] : [erConnP2, game.p2Pda];
+    const board = program.coder.accounts.decode(
+      'playerBoard',
+      (await conn.getAccountInfo(boardPda))!.data
+    );
+    expect(board.shipCoordinates[1]).toEqual({
+      startX: from + 1,
+      startY: 1,
+      endX: from + 1,
+      endY: 1,
+    });
+
+    const g = await erGame(game.gamePda);
+    const [maneuver, shot] = g.moves;
+    // the log shows a maneuver happened, not which ship or where
+    expect(maneuver.outcome).toHaveProperty('maneuver');
+    expect(maneuver.x).toBe(0);
+    expect(shot.outcome.shot.result).toHaveProperty('miss');
+    const charges = opener ? g.chargesPlayer1 : g.chargesPlayer2;
+    expect(charges.maneuver).toBe(4);
+  });
+
+  // ─────────── Ranked Games ───────────
+
+  it('moves both ratings when a ranked game settles', async () => {
+    const game = await setUpPlayGame({ ...DEFAULT_SETTINGS, ranked: true });
+    // P1 sinks P2's whole fleet while P2 finds only open water
+    const hits = [
+      [2, 0],
+      [3, 0],
+      [1, 1],
+    ];
+    const misses = [
+      [2, 0],
+      [3, 0],
+      [1, 1],
+    ];
+    if (!game.p1First) await play(game, false, program.methods.makeMove(3, 1));
+    for (const [i, [x, y]] of hits.entries()) {
+      await play(game, true, program.methods.makeMove(x!, y!));
+      if (i < hits.length - 1) {
+        await play(game, false, program.methods.makeMove(misses[i]![0]!, misses[i]![1]!));
+      }
+    }
+    await reveal(game);
+
+    const profiles = async () =>
+      Promise.all(
+        [player1, player2].map(async kp =>
+          program.coder.accounts.decode(
+            'playerProfile',
+            (await baseConn.getAccountInfo(profilePda(kp.publicKey)))!.data
+          )
+        )
+      );
+    const [winnerBefore, loserBefore] = await profiles();
+    await settle(game, player1);
+    const [winner, loser] = await profiles();
+
+    expect(winner.rating).toBeGreaterThan(winnerBefore.rating);
+    expect(loser.rating).toBeLessThan(loserBefore.rating);
+    expect(winner.rankedGames).toBe(winnerBefore.rankedGames + 1);
+    expect(loser.rankedGames).toBe(loserBefore.rankedGames + 1);
   });
 });
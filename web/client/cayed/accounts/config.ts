@@ -19,23 +19,31 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU16Decoder,
   getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from "@solana/kit";
 
@@ -53,6 +61,18 @@ export type Config = {
   vault: Address;
   maxGridSize: number;
   fee: number;
+  /** Id the next `Game` will be created with. */
+  gameCounter: bigint;
+  /** Ids the next `Map`, `Match` and `Tournament` will be created with. */
+  mapCounter: bigint;
+  matchCounter: bigint;
+  tournamentCounter: bigint;
+  /** `OpenGames` pages created so far, numbered from zero. */
+  openGamesPages: number;
+  /** Unsettled games a wallet may be in at once, up to `PlayerGames::MAX_GAMES`. */
+  maxActiveGames: number;
+  /** Season whose leaderboard settlements must update, until it is closed. */
+  season: Option<bigint>;
   bump: number;
 };
 
@@ -61,11 +81,23 @@ export type ConfigArgs = {
   vault: Address;
   maxGridSize: number;
   fee: number;
+  /** Id the next `Game` will be created with. */
+  gameCounter: number | bigint;
+  /** Ids the next `Map`, `Match` and `Tournament` will be created with. */
+  mapCounter: number | bigint;
+  matchCounter: number | bigint;
+  tournamentCounter: number | bigint;
+  /** `OpenGames` pages created so far, numbered from zero. */
+  openGamesPages: number;
+  /** Unsettled games a wallet may be in at once, up to `PlayerGames::MAX_GAMES`. */
+  maxActiveGames: number;
+  /** Season whose leaderboard settlements must update, until it is closed. */
+  season: OptionOrNullable<number | bigint>;
   bump: number;
 };
 
 /** Gets the encoder for {@link ConfigArgs} account data. */
-export function getConfigEncoder(): FixedSizeEncoder<ConfigArgs> {
+export function getConfigEncoder(): Encoder<ConfigArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
@@ -73,6 +105,13 @@ export function getConfigEncoder(): FixedSizeEncoder<ConfigArgs> {
       ["vault", getAddressEncoder()],
       ["maxGridSize", getU8Encoder()],
       ["fee", getU16Encoder()],
+      ["gameCounter", getU64Encoder()],
+      ["mapCounter", getU64Encoder()],
+      ["matchCounter", getU64Encoder()],
+      ["tournamentCounter", getU64Encoder()],
+      ["openGamesPages", getU32Encoder()],
+      ["maxActiveGames", getU8Encoder()],
+      ["season", getOptionEncoder(getU64Encoder())],
       ["bump", getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: CONFIG_DISCRIMINATOR }),
@@ -80,19 +119,26 @@ export function getConfigEncoder(): FixedSizeEncoder<ConfigArgs> {
 }
 
 /** Gets the decoder for {@link Config} account data. */
-export function getConfigDecoder(): FixedSizeDecoder<Config> {
+export function getConfigDecoder(): Decoder<Config> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["authority", getAddressDecoder()],
     ["vault", getAddressDecoder()],
     ["maxGridSize", getU8Decoder()],
     ["fee", getU16Decoder()],
+    ["gameCounter", getU64Decoder()],
+    ["mapCounter", getU64Decoder()],
+    ["matchCounter", getU64Decoder()],
+    ["tournamentCounter", getU64Decoder()],
+    ["openGamesPages", getU32Decoder()],
+    ["maxActiveGames", getU8Decoder()],
+    ["season", getOptionDecoder(getU64Decoder())],
     ["bump", getU8Decoder()],
   ]);
 }
 
 /** Gets the codec for {@link Config} account data. */
-export function getConfigCodec(): FixedSizeCodec<ConfigArgs, Config> {
+export function getConfigCodec(): Codec<ConfigArgs, Config> {
   return combineCodec(getConfigEncoder(), getConfigDecoder());
 }
 
@@ -148,7 +194,3 @@ export async function fetchAllMaybeConfig(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeConfig(maybeAccount));
 }
-
-export function getConfigSize(): number {
-  return 76;
-}
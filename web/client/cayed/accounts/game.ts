@@ -23,10 +23,14 @@ import {
   getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -47,16 +51,36 @@ import {
   type ReadonlyUint8Array,
 } from "@solana/kit";
 import {
+  getChargesDecoder,
+  getChargesEncoder,
+  getCoinFlipDecoder,
+  getCoinFlipEncoder,
+  getGameSettingsDecoder,
+  getGameSettingsEncoder,
   getGameStatusDecoder,
   getGameStatusEncoder,
+  getInviteDecoder,
+  getInviteEncoder,
   getMoveResultDecoder,
   getMoveResultEncoder,
+  getRematchOfferDecoder,
+  getRematchOfferEncoder,
   getShipCoordinatesDecoder,
   getShipCoordinatesEncoder,
+  type Charges,
+  type ChargesArgs,
+  type CoinFlip,
+  type CoinFlipArgs,
+  type GameSettings,
+  type GameSettingsArgs,
   type GameStatus,
   type GameStatusArgs,
+  type Invite,
+  type InviteArgs,
   type MoveResult,
   type MoveResultArgs,
+  type RematchOffer,
+  type RematchOfferArgs,
   type ShipCoordinates,
   type ShipCoordinatesArgs,
 } from "../types";
@@ -73,28 +97,116 @@ export type Game = {
   discriminator: ReadonlyUint8Array;
   id: bigint;
   gridSize: number;
+  /** Map the game is played on, if any. */
+  map: Option<Address>;
+  /** Copy of the map's blocked cells so ER instructions don't need the `Map` account. */
+  blockedMask: bigint;
+  /** Best-of-N series this game belongs to, if any. Match games carry no wager of their own. */
+  matchId: Option<bigint>;
+  /** Tournament this game is a bracket pairing of, if any. Carries no wager either. */
+  tournamentId: Option<bigint>;
   player1: Address;
   player2: Option<Address>;
+  /** Who may take the second seat; open to anyone when `None`. */
+  invite: Option<Invite>;
   revealedShipsPlayer1: Array<ShipCoordinates>;
   revealedShipsPlayer2: Array<ShipCoordinates>;
   moves: Array<MoveResult>;
+  /** Whose turn it is; meaningless until `first_move_decided`, then advanced by every move. */
   nextMovePlayer1: boolean;
+  /** Set once the opening player has been drawn by VRF or coin flip (or fixed by a match). */
+  firstMoveDecided: boolean;
+  /** Commit-reveal coin flip for the opening player, if the creator opted in. */
+  coinFlip: Option<CoinFlip>;
+  /** Set once each player's fleet is on their board. */
+  shipsHiddenPlayer1: boolean;
+  shipsHiddenPlayer2: boolean;
+  /** Set when a player hit a mine and must sit out their next turn. */
+  skipTurnPlayer1: boolean;
+  skipTurnPlayer2: boolean;
+  /** Extra shots already fired this turn under a `BonusShots` handicap. */
+  bonusShotsFired: number;
+  /** Shots each player has fired in a limited-ammo game. */
+  shotsFiredPlayer1: number;
+  shotsFiredPlayer2: number;
+  settings: GameSettings;
+  /** Special weapon charges left, seeded from `settings`. */
+  chargesPlayer1: Charges;
+  chargesPlayer2: Charges;
+  /** Seconds left on each player's clock when `settings.time_bank_secs` is set. */
+  timeLeftPlayer1: number;
+  timeLeftPlayer2: number;
+  /**
+   * When the player on turn started thinking; zero until both fleets are hidden and
+   * the opener is known.
+   */
+  turnStartedAt: bigint;
   wager: bigint;
   status: GameStatus;
+  /** Set when the loser ran out of time rather than ships. */
+  forfeit: boolean;
+  /** Set once `settle_game` has paid out the wagers. */
+  settled: boolean;
+  /** A rematch one player has proposed and the other has yet to accept. */
+  rematch: Option<RematchOffer>;
   bump: number;
 };
 
 export type GameArgs = {
   id: number | bigint;
   gridSize: number;
+  /** Map the game is played on, if any. */
+  map: OptionOrNullable<Address>;
+  /** Copy of the map's blocked cells so ER instructions don't need the `Map` account. */
+  blockedMask: number | bigint;
+  /** Best-of-N series this game belongs to, if any. Match games carry no wager of their own. */
+  matchId: OptionOrNullable<number | bigint>;
+  /** Tournament this game is a bracket pairing of, if any. Carries no wager either. */
+  tournamentId: OptionOrNullable<number | bigint>;
   player1: Address;
   player2: OptionOrNullable<Address>;
+  /** Who may take the second seat; open to anyone when `None`. */
+  invite: OptionOrNullable<InviteArgs>;
   revealedShipsPlayer1: Array<ShipCoordinatesArgs>;
   revealedShipsPlayer2: Array<ShipCoordinatesArgs>;
   moves: Array<MoveResultArgs>;
+  /** Whose turn it is; meaningless until `first_move_decided`, then advanced by every move. */
   nextMovePlayer1: boolean;
+  /** Set once the opening player has been drawn by VRF or coin flip (or fixed by a match). */
+  firstMoveDecided: boolean;
+  /** Commit-reveal coin flip for the opening player, if the creator opted in. */
+  coinFlip: OptionOrNullable<CoinFlipArgs>;
+  /** Set once each player's fleet is on their board. */
+  shipsHiddenPlayer1: boolean;
+  shipsHiddenPlayer2: boolean;
+  /** Set when a player hit a mine and must sit out their next turn. */
+  skipTurnPlayer1: boolean;
+  skipTurnPlayer2: boolean;
+  /** Extra shots already fired this turn under a `BonusShots` handicap. */
+  bonusShotsFired: number;
+  /** Shots each player has fired in a limited-ammo game. */
+  shotsFiredPlayer1: number;
+  shotsFiredPlayer2: number;
+  settings: GameSettingsArgs;
+  /** Special weapon charges left, seeded from `settings`. */
+  chargesPlayer1: ChargesArgs;
+  chargesPlayer2: ChargesArgs;
+  /** Seconds left on each player's clock when `settings.time_bank_secs` is set. */
+  timeLeftPlayer1: number;
+  timeLeftPlayer2: number;
+  /**
+   * When the player on turn started thinking; zero until both fleets are hidden and
+   * the opener is known.
+   */
+  turnStartedAt: number | bigint;
   wager: number | bigint;
   status: GameStatusArgs;
+  /** Set when the loser ran out of time rather than ships. */
+  forfeit: boolean;
+  /** Set once `settle_game` has paid out the wagers. */
+  settled: boolean;
+  /** A rematch one player has proposed and the other has yet to accept. */
+  rematch: OptionOrNullable<RematchOfferArgs>;
   bump: number;
 };
 
@@ -105,14 +217,37 @@ export function getGameEncoder(): Encoder<GameArgs> {
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["id", getU64Encoder()],
       ["gridSize", getU8Encoder()],
+      ["map", getOptionEncoder(getAddressEncoder())],
+      ["blockedMask", getU64Encoder()],
+      ["matchId", getOptionEncoder(getU64Encoder())],
+      ["tournamentId", getOptionEncoder(getU64Encoder())],
       ["player1", getAddressEncoder()],
       ["player2", getOptionEncoder(getAddressEncoder())],
+      ["invite", getOptionEncoder(getInviteEncoder())],
       ["revealedShipsPlayer1", getArrayEncoder(getShipCoordinatesEncoder())],
       ["revealedShipsPlayer2", getArrayEncoder(getShipCoordinatesEncoder())],
       ["moves", getArrayEncoder(getMoveResultEncoder())],
       ["nextMovePlayer1", getBooleanEncoder()],
+      ["firstMoveDecided", getBooleanEncoder()],
+      ["coinFlip", getOptionEncoder(getCoinFlipEncoder())],
+      ["shipsHiddenPlayer1", getBooleanEncoder()],
+      ["shipsHiddenPlayer2", getBooleanEncoder()],
+      ["skipTurnPlayer1", getBooleanEncoder()],
+      ["skipTurnPlayer2", getBooleanEncoder()],
+      ["bonusShotsFired", getU8Encoder()],
+      ["shotsFiredPlayer1", getU8Encoder()],
+      ["shotsFiredPlayer2", getU8Encoder()],
+      ["settings", getGameSettingsEncoder()],
+      ["chargesPlayer1", getChargesEncoder()],
+      ["chargesPlayer2", getChargesEncoder()],
+      ["timeLeftPlayer1", getU32Encoder()],
+      ["timeLeftPlayer2", getU32Encoder()],
+      ["turnStartedAt", getI64Encoder()],
       ["wager", getU64Encoder()],
       ["status", getGameStatusEncoder()],
+      ["forfeit", getBooleanEncoder()],
+      ["settled", getBooleanEncoder()],
+      ["rematch", getOptionEncoder(getRematchOfferEncoder())],
       ["bump", getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: GAME_DISCRIMINATOR }),
@@ -125,14 +260,37 @@ export function getGameDecoder(): Decoder<Game> {
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["id", getU64Decoder()],
     ["gridSize", getU8Decoder()],
+    ["map", getOptionDecoder(getAddressDecoder())],
+    ["blockedMask", getU64Decoder()],
+    ["matchId", getOptionDecoder(getU64Decoder())],
+    ["tournamentId", getOptionDecoder(getU64Decoder())],
     ["player1", getAddressDecoder()],
     ["player2", getOptionDecoder(getAddressDecoder())],
+    ["invite", getOptionDecoder(getInviteDecoder())],
     ["revealedShipsPlayer1", getArrayDecoder(getShipCoordinatesDecoder())],
     ["revealedShipsPlayer2", getArrayDecoder(getShipCoordinatesDecoder())],
     ["moves", getArrayDecoder(getMoveResultDecoder())],
     ["nextMovePlayer1", getBooleanDecoder()],
+    ["firstMoveDecided", getBooleanDecoder()],
+    ["coinFlip", getOptionDecoder(getCoinFlipDecoder())],
+    ["shipsHiddenPlayer1", getBooleanDecoder()],
+    ["shipsHiddenPlayer2", getBooleanDecoder()],
+    ["skipTurnPlayer1", getBooleanDecoder()],
+    ["skipTurnPlayer2", getBooleanDecoder()],
+    ["bonusShotsFired", getU8Decoder()],
+    ["shotsFiredPlayer1", getU8Decoder()],
+    ["shotsFiredPlayer2", getU8Decoder()],
+    ["settings", getGameSettingsDecoder()],
+    ["chargesPlayer1", getChargesDecoder()],
+    ["chargesPlayer2", getChargesDecoder()],
+    ["timeLeftPlayer1", getU32Decoder()],
+    ["timeLeftPlayer2", getU32Decoder()],
+    ["turnStartedAt", getI64Decoder()],
     ["wager", getU64Decoder()],
     ["status", getGameStatusDecoder()],
+    ["forfeit", getBooleanDecoder()],
+    ["settled", getBooleanDecoder()],
+    ["rematch", getOptionDecoder(getRematchOfferDecoder())],
     ["bump", getU8Decoder()],
   ]);
 }
@@ -6,7 +6,18 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./challengeReceipt";
 export * from "./config";
+export * from "./escrow";
 export * from "./game";
+export * from "./map";
+export * from "./match";
+export * from "./multiGame";
+export * from "./openGames";
 export * from "./playerBoard";
+export * from "./playerGames";
+export * from "./playerProfile";
+export * from "./queue";
+export * from "./season";
+export * from "./tournament";
 export * from "./vault";
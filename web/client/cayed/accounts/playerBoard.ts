@@ -72,6 +72,15 @@ export type PlayerBoard = {
   hitsBitmap: bigint;
   /** Per-ship sunk tracker - bit `i` set means `ship_coordinates[i]` is fully sunk. */
   sunkMask: number;
+  /** Hidden mine cells - hitting one costs the attacker a turn. */
+  minesMask: bigint;
+  /** Hidden decoy cells - report a hit but never count towards sinking. */
+  decoysMask: bigint;
+  /**
+   * Player's secret mixed into the public VRF output for auto-placement; wiped once
+   * the fleet is laid out.
+   */
+  placementSalt: Array<number>;
 };
 
 export type PlayerBoardArgs = {
@@ -88,6 +97,15 @@ export type PlayerBoardArgs = {
   hitsBitmap: number | bigint;
   /** Per-ship sunk tracker - bit `i` set means `ship_coordinates[i]` is fully sunk. */
   sunkMask: number;
+  /** Hidden mine cells - hitting one costs the attacker a turn. */
+  minesMask: number | bigint;
+  /** Hidden decoy cells - report a hit but never count towards sinking. */
+  decoysMask: number | bigint;
+  /**
+   * Player's secret mixed into the public VRF output for auto-placement; wiped once
+   * the fleet is laid out.
+   */
+  placementSalt: Array<number>;
 };
 
 /** Gets the encoder for {@link PlayerBoardArgs} account data. */
@@ -103,6 +121,9 @@ export function getPlayerBoardEncoder(): Encoder<PlayerBoardArgs> {
       ["allShipsMask", getU64Encoder()],
       ["hitsBitmap", getU64Encoder()],
       ["sunkMask", getU8Encoder()],
+      ["minesMask", getU64Encoder()],
+      ["decoysMask", getU64Encoder()],
+      ["placementSalt", getArrayEncoder(getU8Encoder(), { size: 32 })],
     ]),
     (value) => ({ ...value, discriminator: PLAYER_BOARD_DISCRIMINATOR }),
   );
@@ -120,6 +141,9 @@ export function getPlayerBoardDecoder(): Decoder<PlayerBoard> {
     ["allShipsMask", getU64Decoder()],
     ["hitsBitmap", getU64Decoder()],
     ["sunkMask", getU8Decoder()],
+    ["minesMask", getU64Decoder()],
+    ["decoysMask", getU64Decoder()],
+    ["placementSalt", getArrayDecoder(getU8Decoder(), { size: 32 })],
   ]);
 }
 
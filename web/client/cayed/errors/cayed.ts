@@ -60,57 +60,405 @@ export const CAYED_ERROR__ALL_SHIPS_SUNK = 0x1784; // 6020
 export const CAYED_ERROR__INVALID_GAME_STATUS = 0x1785; // 6021
 /** NotAllShipsSunk: Not all ships have been sunk yet */
 export const CAYED_ERROR__NOT_ALL_SHIPS_SUNK = 0x1786; // 6022
+/** BlockedCellOutOfBounds: Blocked cells must lie within the grid */
+export const CAYED_ERROR__BLOCKED_CELL_OUT_OF_BOUNDS = 0x1787; // 6023
+/** MapTooCrowded: Map leaves too few open cells to hide a fleet */
+export const CAYED_ERROR__MAP_TOO_CROWDED = 0x1788; // 6024
+/** MapGridMismatch: Map grid size does not match the game grid size */
+export const CAYED_ERROR__MAP_GRID_MISMATCH = 0x1789; // 6025
+/** ShipOnBlockedCell: Ships cannot be placed on blocked cells */
+export const CAYED_ERROR__SHIP_ON_BLOCKED_CELL = 0x178a; // 6026
+/** AttackOnBlockedCell: Blocked cells cannot be attacked */
+export const CAYED_ERROR__ATTACK_ON_BLOCKED_CELL = 0x178b; // 6027
+/** TooManyTraps: Mines and decoys are limited to half the grid size each */
+export const CAYED_ERROR__TOO_MANY_TRAPS = 0x178c; // 6028
+/** IncorrectTrapsLen: Incorrect number of mines or decoys for this game */
+export const CAYED_ERROR__INCORRECT_TRAPS_LEN = 0x178d; // 6029
+/** InvalidTrapPlacement: Mines and decoys must sit on open cells inside the grid, away from ships */
+export const CAYED_ERROR__INVALID_TRAP_PLACEMENT = 0x178e; // 6030
+/** BoardTooCrowded: Ships, mines and decoys don't fit on the board */
+export const CAYED_ERROR__BOARD_TOO_CROWDED = 0x178f; // 6031
+/** TooManyCharges: Special weapons are limited to 3 charges each */
+export const CAYED_ERROR__TOO_MANY_CHARGES = 0x1790; // 6032
+/** NoChargesLeft: No charges left for this special weapon */
+export const CAYED_ERROR__NO_CHARGES_LEFT = 0x1791; // 6033
+/** InvalidBestOf: Best-of must be an odd number of games up to 9 */
+export const CAYED_ERROR__INVALID_BEST_OF = 0x1792; // 6034
+/** MatchGameInProgress: The current match game has not been recorded yet */
+export const CAYED_ERROR__MATCH_GAME_IN_PROGRESS = 0x1793; // 6035
+/** NotCurrentMatchGame: Game is not the match's current game */
+export const CAYED_ERROR__NOT_CURRENT_MATCH_GAME = 0x1794; // 6036
+/** NotGameCreator: Only the creator can cancel a game */
+export const CAYED_ERROR__NOT_GAME_CREATOR = 0x1795; // 6037
+/** InvalidPlayerCount: Multiplayer games seat 3 or 4 players, team games exactly 4 */
+export const CAYED_ERROR__INVALID_PLAYER_COUNT = 0x1796; // 6038
+/** InvalidBoardAccounts: Board or permission accounts do not match the seated players */
+export const CAYED_ERROR__INVALID_BOARD_ACCOUNTS = 0x1797; // 6039
+/** PotAlreadyClaimed: The pot has already been claimed */
+export const CAYED_ERROR__POT_ALREADY_CLAIMED = 0x1798; // 6040
+/** InvalidRefundAccounts: Refund accounts must match the players being refunded */
+export const CAYED_ERROR__INVALID_REFUND_ACCOUNTS = 0x1799; // 6041
+/** NotATeamGame: This action is only available in team games */
+export const CAYED_ERROR__NOT_A_TEAM_GAME = 0x179a; // 6042
+/** TeamIncomplete: Both teams must be full before boards can be shared */
+export const CAYED_ERROR__TEAM_INCOMPLETE = 0x179b; // 6043
+/** FirstMoveAlreadyDecided: Who moves first has already been decided */
+export const CAYED_ERROR__FIRST_MOVE_ALREADY_DECIDED = 0x179c; // 6044
+/** FirstMoveNotDecided: Who moves first has not been decided yet */
+export const CAYED_ERROR__FIRST_MOVE_NOT_DECIDED = 0x179d; // 6045
+/** CoinFlipMismatch: Both players must commit to the coin flip, or neither */
+export const CAYED_ERROR__COIN_FLIP_MISMATCH = 0x179e; // 6046
+/** CoinFlipInUse: This game decides the first move by coin flip */
+export const CAYED_ERROR__COIN_FLIP_IN_USE = 0x179f; // 6047
+/** NoCoinFlip: This game has no coin flip */
+export const CAYED_ERROR__NO_COIN_FLIP = 0x17a0; // 6048
+/** SecretAlreadyRevealed: Secret already revealed */
+export const CAYED_ERROR__SECRET_ALREADY_REVEALED = 0x17a1; // 6049
+/** InvalidSecret: Secret does not match the commitment */
+export const CAYED_ERROR__INVALID_SECRET = 0x17a2; // 6050
+/** NoRoomForFleet: Not enough open water to place the fleet */
+export const CAYED_ERROR__NO_ROOM_FOR_FLEET = 0x17a3; // 6051
+/** InvalidTimeControl: A clock increment needs a time bank */
+export const CAYED_ERROR__INVALID_TIME_CONTROL = 0x17a4; // 6052
+/** TimeBankExpired: Your time bank has run out */
+export const CAYED_ERROR__TIME_BANK_EXPIRED = 0x17a5; // 6053
+/** ClockNotRunning: The clock isn't running for this game */
+export const CAYED_ERROR__CLOCK_NOT_RUNNING = 0x17a6; // 6054
+/** OpponentHasTime: The player on turn still has time left */
+export const CAYED_ERROR__OPPONENT_HAS_TIME = 0x17a7; // 6055
+/** CoinFlipNotRevealed: Reveal your own coin flip secret before claiming */
+export const CAYED_ERROR__COIN_FLIP_NOT_REVEALED = 0x17a8; // 6056
+/** RevealWindowOpen: The opponent can still reveal their coin flip secret */
+export const CAYED_ERROR__REVEAL_WINDOW_OPEN = 0x17a9; // 6057
+/** InvalidHandicap: Handicap is not valid for this grid */
+export const CAYED_ERROR__INVALID_HANDICAP = 0x17aa; // 6058
+/** ExtraShipTooLong: A handicap's extra ship must be a single cell, listed last */
+export const CAYED_ERROR__EXTRA_SHIP_TOO_LONG = 0x17ab; // 6059
+/** TooMuchAmmo: More ammunition than cells on the board */
+export const CAYED_ERROR__TOO_MUCH_AMMO = 0x17ac; // 6060
+/** NoAmmoLeft: You have fired all your shots */
+export const CAYED_ERROR__NO_AMMO_LEFT = 0x17ad; // 6061
+/** ManeuversDisabled: Maneuvers are not enabled for this game */
+export const CAYED_ERROR__MANEUVERS_DISABLED = 0x17ae; // 6062
+/** InvalidShipIndex: No ship at that index */
+export const CAYED_ERROR__INVALID_SHIP_INDEX = 0x17af; // 6063
+/** ShipDamaged: Damaged ships cannot maneuver */
+export const CAYED_ERROR__SHIP_DAMAGED = 0x17b0; // 6064
+/** IllegalManeuver: Ships must stay on open, unattacked water when maneuvering */
+export const CAYED_ERROR__ILLEGAL_MANEUVER = 0x17b1; // 6065
+/** NoManeuversLeft: You have used all your maneuvers */
+export const CAYED_ERROR__NO_MANEUVERS_LEFT = 0x17b2; // 6066
+/** NotInvited: This game is invite-only and you are not invited */
+export const CAYED_ERROR__NOT_INVITED = 0x17b3; // 6067
+/** ChallengeExpired: This challenge has expired */
+export const CAYED_ERROR__CHALLENGE_EXPIRED = 0x17b4; // 6068
+/** InvalidChallengeSignature: Challenge is not signed by the challenger */
+export const CAYED_ERROR__INVALID_CHALLENGE_SIGNATURE = 0x17b5; // 6069
+/** EscrowRequired: A wagered challenge needs the challenger's escrow */
+export const CAYED_ERROR__ESCROW_REQUIRED = 0x17b6; // 6070
+/** InsufficientEscrow: Not enough lamports in escrow */
+export const CAYED_ERROR__INSUFFICIENT_ESCROW = 0x17b7; // 6071
+/** InvalidWagerBucket: No such wager bucket */
+export const CAYED_ERROR__INVALID_WAGER_BUCKET = 0x17b8; // 6072
+/** AlreadyQueued: You are already waiting in this queue */
+export const CAYED_ERROR__ALREADY_QUEUED = 0x17b9; // 6073
+/** QueueFull: The queue is full */
+export const CAYED_ERROR__QUEUE_FULL = 0x17ba; // 6074
+/** NotQueued: You are not in this queue */
+export const CAYED_ERROR__NOT_QUEUED = 0x17bb; // 6075
+/** NotEnoughPlayers: At least two players must be queued to make a match */
+export const CAYED_ERROR__NOT_ENOUGH_PLAYERS = 0x17bc; // 6076
+/** InvalidOpenGamesPage: Open games pages must be created in order */
+export const CAYED_ERROR__INVALID_OPEN_GAMES_PAGE = 0x17bd; // 6077
+/** OpenGamesPageFull: This open games page is full */
+export const CAYED_ERROR__OPEN_GAMES_PAGE_FULL = 0x17be; // 6078
+/** GameNotListed: The game is not listed on this open games page */
+export const CAYED_ERROR__GAME_NOT_LISTED = 0x17bf; // 6079
+/** GameAlreadySettled: The game has already been settled */
+export const CAYED_ERROR__GAME_ALREADY_SETTLED = 0x17c0; // 6080
+/** InvalidActiveGamesCap: Active games cap must be between 1 and 32 */
+export const CAYED_ERROR__INVALID_ACTIVE_GAMES_CAP = 0x17c1; // 6081
+/** TooManyActiveGames: You are already in as many games as allowed */
+export const CAYED_ERROR__TOO_MANY_ACTIVE_GAMES = 0x17c2; // 6082
+/** InvalidFee: Fee cannot exceed 10,000 basis points */
+export const CAYED_ERROR__INVALID_FEE = 0x17c3; // 6083
+/** RematchAlreadyOffered: A rematch has already been offered */
+export const CAYED_ERROR__REMATCH_ALREADY_OFFERED = 0x17c4; // 6084
+/** NoRematchOffer: No rematch has been offered */
+export const CAYED_ERROR__NO_REMATCH_OFFER = 0x17c5; // 6085
+/** RematchNeedsWinner: Double or nothing needs a winner */
+export const CAYED_ERROR__REMATCH_NEEDS_WINNER = 0x17c6; // 6086
+/** CannotAcceptOwnRematch: The other player has to accept the rematch */
+export const CAYED_ERROR__CANNOT_ACCEPT_OWN_REMATCH = 0x17c7; // 6087
+/** RankedHandicap: Ranked games cannot have a handicap */
+export const CAYED_ERROR__RANKED_HANDICAP = 0x17c8; // 6088
+/** InvalidSeasonSlots: Season must end after it starts */
+export const CAYED_ERROR__INVALID_SEASON_SLOTS = 0x17c9; // 6089
+/** InvalidPrizeSplit: Prize split must have at most 10 places and add up to no more than 100% */
+export const CAYED_ERROR__INVALID_PRIZE_SPLIT = 0x17ca; // 6090
+/** SeasonInProgress: Another season is still running */
+export const CAYED_ERROR__SEASON_IN_PROGRESS = 0x17cb; // 6091
+/** SeasonRequired: The current season account must be passed */
+export const CAYED_ERROR__SEASON_REQUIRED = 0x17cc; // 6092
+/** SeasonNotOver: The season has not ended yet */
+export const CAYED_ERROR__SEASON_NOT_OVER = 0x17cd; // 6093
+/** SeasonClosed: The season is already closed */
+export const CAYED_ERROR__SEASON_CLOSED = 0x17ce; // 6094
+/** SeasonNotClosed: The season has not closed yet */
+export const CAYED_ERROR__SEASON_NOT_CLOSED = 0x17cf; // 6095
+/** NoPrize: Nothing to claim */
+export const CAYED_ERROR__NO_PRIZE = 0x17d0; // 6096
+/** PrizeAlreadyClaimed: Prize already claimed */
+export const CAYED_ERROR__PRIZE_ALREADY_CLAIMED = 0x17d1; // 6097
+/** InvalidTournamentSize: Tournaments take a power of two between 2 and 16 players */
+export const CAYED_ERROR__INVALID_TOURNAMENT_SIZE = 0x17d2; // 6098
+/** TournamentFull: The tournament is full */
+export const CAYED_ERROR__TOURNAMENT_FULL = 0x17d3; // 6099
+/** AlreadyRegistered: You are already registered */
+export const CAYED_ERROR__ALREADY_REGISTERED = 0x17d4; // 6100
+/** RegistrationClosed: The tournament is not taking registrations */
+export const CAYED_ERROR__REGISTRATION_CLOSED = 0x17d5; // 6101
+/** TournamentNotInProgress: The tournament is not in progress */
+export const CAYED_ERROR__TOURNAMENT_NOT_IN_PROGRESS = 0x17d6; // 6102
+/** InvalidPairing: No such pairing in this round */
+export const CAYED_ERROR__INVALID_PAIRING = 0x17d7; // 6103
+/** PairingStarted: This pairing already has a game or a winner */
+export const CAYED_ERROR__PAIRING_STARTED = 0x17d8; // 6104
+/** NotTournamentGame: The game is not part of this tournament's current round */
+export const CAYED_ERROR__NOT_TOURNAMENT_GAME = 0x17d9; // 6105
+/** TournamentNotFinished: The tournament has not finished */
+export const CAYED_ERROR__TOURNAMENT_NOT_FINISHED = 0x17da; // 6106
+/** TooFewEntrants: A tournament needs at least two entrants */
+export const CAYED_ERROR__TOO_FEW_ENTRANTS = 0x17db; // 6107
+/** PairingNotOverdue: The pairing's deadline has not passed */
+export const CAYED_ERROR__PAIRING_NOT_OVERDUE = 0x17dc; // 6108
+/** PairingUnderway: Both fleets are hidden and the clock is running */
+export const CAYED_ERROR__PAIRING_UNDERWAY = 0x17dd; // 6109
 
 export type CayedError =
   | typeof CAYED_ERROR__ALL_SHIPS_SUNK
+  | typeof CAYED_ERROR__ALREADY_QUEUED
+  | typeof CAYED_ERROR__ALREADY_REGISTERED
+  | typeof CAYED_ERROR__ATTACK_ON_BLOCKED_CELL
   | typeof CAYED_ERROR__ATTACK_OUT_OF_BOUNDS
+  | typeof CAYED_ERROR__BLOCKED_CELL_OUT_OF_BOUNDS
+  | typeof CAYED_ERROR__BOARD_TOO_CROWDED
+  | typeof CAYED_ERROR__CANNOT_ACCEPT_OWN_REMATCH
   | typeof CAYED_ERROR__CANNOT_JOIN_SELF_GAME
   | typeof CAYED_ERROR__CELL_ALREADY_ATTACKED
+  | typeof CAYED_ERROR__CHALLENGE_EXPIRED
+  | typeof CAYED_ERROR__CLOCK_NOT_RUNNING
+  | typeof CAYED_ERROR__COIN_FLIP_IN_USE
+  | typeof CAYED_ERROR__COIN_FLIP_MISMATCH
+  | typeof CAYED_ERROR__COIN_FLIP_NOT_REVEALED
+  | typeof CAYED_ERROR__ESCROW_REQUIRED
+  | typeof CAYED_ERROR__EXTRA_SHIP_TOO_LONG
+  | typeof CAYED_ERROR__FIRST_MOVE_ALREADY_DECIDED
+  | typeof CAYED_ERROR__FIRST_MOVE_NOT_DECIDED
+  | typeof CAYED_ERROR__GAME_ALREADY_SETTLED
   | typeof CAYED_ERROR__GAME_FULL
+  | typeof CAYED_ERROR__GAME_NOT_LISTED
   | typeof CAYED_ERROR__GAME_NOT_STARTED
   | typeof CAYED_ERROR__GRID_NOT_EVEN
   | typeof CAYED_ERROR__GRID_SIZE_TOO_LARGE
+  | typeof CAYED_ERROR__ILLEGAL_MANEUVER
   | typeof CAYED_ERROR__INCORRECT_SHIPS_LEN
+  | typeof CAYED_ERROR__INCORRECT_TRAPS_LEN
+  | typeof CAYED_ERROR__INSUFFICIENT_ESCROW
+  | typeof CAYED_ERROR__INVALID_ACTIVE_GAMES_CAP
+  | typeof CAYED_ERROR__INVALID_BEST_OF
+  | typeof CAYED_ERROR__INVALID_BOARD_ACCOUNTS
+  | typeof CAYED_ERROR__INVALID_CHALLENGE_SIGNATURE
+  | typeof CAYED_ERROR__INVALID_FEE
   | typeof CAYED_ERROR__INVALID_GAME_STATUS
+  | typeof CAYED_ERROR__INVALID_HANDICAP
+  | typeof CAYED_ERROR__INVALID_OPEN_GAMES_PAGE
   | typeof CAYED_ERROR__INVALID_OPPONENT
+  | typeof CAYED_ERROR__INVALID_PAIRING
+  | typeof CAYED_ERROR__INVALID_PLAYER_COUNT
+  | typeof CAYED_ERROR__INVALID_PRIZE_SPLIT
+  | typeof CAYED_ERROR__INVALID_REFUND_ACCOUNTS
+  | typeof CAYED_ERROR__INVALID_SEASON_SLOTS
+  | typeof CAYED_ERROR__INVALID_SECRET
+  | typeof CAYED_ERROR__INVALID_SHIP_INDEX
   | typeof CAYED_ERROR__INVALID_SHIP_PLACEMENT
+  | typeof CAYED_ERROR__INVALID_TIME_CONTROL
+  | typeof CAYED_ERROR__INVALID_TOURNAMENT_SIZE
+  | typeof CAYED_ERROR__INVALID_TRAP_PLACEMENT
   | typeof CAYED_ERROR__INVALID_TURN
+  | typeof CAYED_ERROR__INVALID_WAGER_BUCKET
+  | typeof CAYED_ERROR__MANEUVERS_DISABLED
+  | typeof CAYED_ERROR__MAP_GRID_MISMATCH
+  | typeof CAYED_ERROR__MAP_TOO_CROWDED
+  | typeof CAYED_ERROR__MATCH_GAME_IN_PROGRESS
   | typeof CAYED_ERROR__MAX_GRID_SIZE_TOO_LARGE
   | typeof CAYED_ERROR__MINIMUM_WAGER
+  | typeof CAYED_ERROR__NO_AMMO_LEFT
+  | typeof CAYED_ERROR__NO_CHARGES_LEFT
+  | typeof CAYED_ERROR__NO_COIN_FLIP
+  | typeof CAYED_ERROR__NO_MANEUVERS_LEFT
+  | typeof CAYED_ERROR__NO_PRIZE
+  | typeof CAYED_ERROR__NO_REMATCH_OFFER
+  | typeof CAYED_ERROR__NO_ROOM_FOR_FLEET
   | typeof CAYED_ERROR__NOT_ALL_SHIPS_SUNK
+  | typeof CAYED_ERROR__NOT_A_TEAM_GAME
+  | typeof CAYED_ERROR__NOT_CURRENT_MATCH_GAME
+  | typeof CAYED_ERROR__NOT_ENOUGH_PLAYERS
+  | typeof CAYED_ERROR__NOT_GAME_CREATOR
+  | typeof CAYED_ERROR__NOT_INVITED
+  | typeof CAYED_ERROR__NOT_QUEUED
+  | typeof CAYED_ERROR__NOT_TOURNAMENT_GAME
+  | typeof CAYED_ERROR__OPEN_GAMES_PAGE_FULL
+  | typeof CAYED_ERROR__OPPONENT_HAS_TIME
   | typeof CAYED_ERROR__OVERFLOW
+  | typeof CAYED_ERROR__PAIRING_NOT_OVERDUE
+  | typeof CAYED_ERROR__PAIRING_STARTED
+  | typeof CAYED_ERROR__PAIRING_UNDERWAY
+  | typeof CAYED_ERROR__POT_ALREADY_CLAIMED
+  | typeof CAYED_ERROR__PRIZE_ALREADY_CLAIMED
+  | typeof CAYED_ERROR__QUEUE_FULL
+  | typeof CAYED_ERROR__RANKED_HANDICAP
+  | typeof CAYED_ERROR__REGISTRATION_CLOSED
+  | typeof CAYED_ERROR__REMATCH_ALREADY_OFFERED
+  | typeof CAYED_ERROR__REMATCH_NEEDS_WINNER
+  | typeof CAYED_ERROR__REVEAL_WINDOW_OPEN
+  | typeof CAYED_ERROR__SEASON_CLOSED
+  | typeof CAYED_ERROR__SEASON_IN_PROGRESS
+  | typeof CAYED_ERROR__SEASON_NOT_CLOSED
+  | typeof CAYED_ERROR__SEASON_NOT_OVER
+  | typeof CAYED_ERROR__SEASON_REQUIRED
+  | typeof CAYED_ERROR__SECRET_ALREADY_REVEALED
   | typeof CAYED_ERROR__SHIP_COORDS_REVERSED
+  | typeof CAYED_ERROR__SHIP_DAMAGED
   | typeof CAYED_ERROR__SHIP_NOT_LINEAR
+  | typeof CAYED_ERROR__SHIP_ON_BLOCKED_CELL
   | typeof CAYED_ERROR__SHIP_OVERLAP
   | typeof CAYED_ERROR__SHIPS_ALREADY_PLACED
   | typeof CAYED_ERROR__SHIPS_NOT_PLACED
+  | typeof CAYED_ERROR__TEAM_INCOMPLETE
+  | typeof CAYED_ERROR__TIME_BANK_EXPIRED
+  | typeof CAYED_ERROR__TOO_FEW_ENTRANTS
+  | typeof CAYED_ERROR__TOO_MANY_ACTIVE_GAMES
+  | typeof CAYED_ERROR__TOO_MANY_CHARGES
+  | typeof CAYED_ERROR__TOO_MANY_TRAPS
+  | typeof CAYED_ERROR__TOO_MUCH_AMMO
+  | typeof CAYED_ERROR__TOURNAMENT_FULL
+  | typeof CAYED_ERROR__TOURNAMENT_NOT_FINISHED
+  | typeof CAYED_ERROR__TOURNAMENT_NOT_IN_PROGRESS
   | typeof CAYED_ERROR__UNAUTHORIZED;
 
 let cayedErrorMessages: Record<CayedError, string> | undefined;
 if (process.env.NODE_ENV !== "production") {
   cayedErrorMessages = {
     [CAYED_ERROR__ALL_SHIPS_SUNK]: `You are trying to make a move when all your ships are sunken`,
+    [CAYED_ERROR__ALREADY_QUEUED]: `You are already waiting in this queue`,
+    [CAYED_ERROR__ALREADY_REGISTERED]: `You are already registered`,
+    [CAYED_ERROR__ATTACK_ON_BLOCKED_CELL]: `Blocked cells cannot be attacked`,
     [CAYED_ERROR__ATTACK_OUT_OF_BOUNDS]: `Attack coordinates are out of the grid bounds`,
+    [CAYED_ERROR__BLOCKED_CELL_OUT_OF_BOUNDS]: `Blocked cells must lie within the grid`,
+    [CAYED_ERROR__BOARD_TOO_CROWDED]: `Ships, mines and decoys don't fit on the board`,
+    [CAYED_ERROR__CANNOT_ACCEPT_OWN_REMATCH]: `The other player has to accept the rematch`,
     [CAYED_ERROR__CANNOT_JOIN_SELF_GAME]: `Cannot join a game created by yourself`,
     [CAYED_ERROR__CELL_ALREADY_ATTACKED]: `This cell has already been attacked`,
+    [CAYED_ERROR__CHALLENGE_EXPIRED]: `This challenge has expired`,
+    [CAYED_ERROR__CLOCK_NOT_RUNNING]: `The clock isn't running for this game`,
+    [CAYED_ERROR__COIN_FLIP_IN_USE]: `This game decides the first move by coin flip`,
+    [CAYED_ERROR__COIN_FLIP_MISMATCH]: `Both players must commit to the coin flip, or neither`,
+    [CAYED_ERROR__COIN_FLIP_NOT_REVEALED]: `Reveal your own coin flip secret before claiming`,
+    [CAYED_ERROR__ESCROW_REQUIRED]: `A wagered challenge needs the challenger's escrow`,
+    [CAYED_ERROR__EXTRA_SHIP_TOO_LONG]: `A handicap's extra ship must be a single cell, listed last`,
+    [CAYED_ERROR__FIRST_MOVE_ALREADY_DECIDED]: `Who moves first has already been decided`,
+    [CAYED_ERROR__FIRST_MOVE_NOT_DECIDED]: `Who moves first has not been decided yet`,
+    [CAYED_ERROR__GAME_ALREADY_SETTLED]: `The game has already been settled`,
     [CAYED_ERROR__GAME_FULL]: `The game has already been joined by someone else`,
+    [CAYED_ERROR__GAME_NOT_LISTED]: `The game is not listed on this open games page`,
     [CAYED_ERROR__GAME_NOT_STARTED]: `Can't move game hasn't been joined by any player_2`,
     [CAYED_ERROR__GRID_NOT_EVEN]: `Grid size must be a positive multiple of 2`,
     [CAYED_ERROR__GRID_SIZE_TOO_LARGE]: `Grid size exceeds the maximum allowed by config`,
+    [CAYED_ERROR__ILLEGAL_MANEUVER]: `Ships must stay on open, unattacked water when maneuvering`,
     [CAYED_ERROR__INCORRECT_SHIPS_LEN]: `Incorrect number of ships placed on grid (0.5 * grid)`,
+    [CAYED_ERROR__INCORRECT_TRAPS_LEN]: `Incorrect number of mines or decoys for this game`,
+    [CAYED_ERROR__INSUFFICIENT_ESCROW]: `Not enough lamports in escrow`,
+    [CAYED_ERROR__INVALID_ACTIVE_GAMES_CAP]: `Active games cap must be between 1 and 32`,
+    [CAYED_ERROR__INVALID_BEST_OF]: `Best-of must be an odd number of games up to 9`,
+    [CAYED_ERROR__INVALID_BOARD_ACCOUNTS]: `Board or permission accounts do not match the seated players`,
+    [CAYED_ERROR__INVALID_CHALLENGE_SIGNATURE]: `Challenge is not signed by the challenger`,
+    [CAYED_ERROR__INVALID_FEE]: `Fee cannot exceed 10,000 basis points`,
     [CAYED_ERROR__INVALID_GAME_STATUS]: `Game is not in the correct state for this action`,
+    [CAYED_ERROR__INVALID_HANDICAP]: `Handicap is not valid for this grid`,
+    [CAYED_ERROR__INVALID_OPEN_GAMES_PAGE]: `Open games pages must be created in order`,
     [CAYED_ERROR__INVALID_OPPONENT]: `Provided opponent account is incorrect`,
+    [CAYED_ERROR__INVALID_PAIRING]: `No such pairing in this round`,
+    [CAYED_ERROR__INVALID_PLAYER_COUNT]: `Multiplayer games seat 3 or 4 players, team games exactly 4`,
+    [CAYED_ERROR__INVALID_PRIZE_SPLIT]: `Prize split must have at most 10 places and add up to no more than 100%`,
+    [CAYED_ERROR__INVALID_REFUND_ACCOUNTS]: `Refund accounts must match the players being refunded`,
+    [CAYED_ERROR__INVALID_SEASON_SLOTS]: `Season must end after it starts`,
+    [CAYED_ERROR__INVALID_SECRET]: `Secret does not match the commitment`,
+    [CAYED_ERROR__INVALID_SHIP_INDEX]: `No ship at that index`,
     [CAYED_ERROR__INVALID_SHIP_PLACEMENT]: `Ship coordinates are out of the grid bounds`,
+    [CAYED_ERROR__INVALID_TIME_CONTROL]: `A clock increment needs a time bank`,
+    [CAYED_ERROR__INVALID_TOURNAMENT_SIZE]: `Tournaments take a power of two between 2 and 16 players`,
+    [CAYED_ERROR__INVALID_TRAP_PLACEMENT]: `Mines and decoys must sit on open cells inside the grid, away from ships`,
     [CAYED_ERROR__INVALID_TURN]: `Signer tried to make a move out of turn`,
+    [CAYED_ERROR__INVALID_WAGER_BUCKET]: `No such wager bucket`,
+    [CAYED_ERROR__MANEUVERS_DISABLED]: `Maneuvers are not enabled for this game`,
+    [CAYED_ERROR__MAP_GRID_MISMATCH]: `Map grid size does not match the game grid size`,
+    [CAYED_ERROR__MAP_TOO_CROWDED]: `Map leaves too few open cells to hide a fleet`,
+    [CAYED_ERROR__MATCH_GAME_IN_PROGRESS]: `The current match game has not been recorded yet`,
     [CAYED_ERROR__MAX_GRID_SIZE_TOO_LARGE]: `Max grid size cannot exceed 10`,
     [CAYED_ERROR__MINIMUM_WAGER]: `Wager was supplied but below minimum`,
+    [CAYED_ERROR__NO_AMMO_LEFT]: `You have fired all your shots`,
+    [CAYED_ERROR__NO_CHARGES_LEFT]: `No charges left for this special weapon`,
+    [CAYED_ERROR__NO_COIN_FLIP]: `This game has no coin flip`,
+    [CAYED_ERROR__NO_MANEUVERS_LEFT]: `You have used all your maneuvers`,
+    [CAYED_ERROR__NO_PRIZE]: `Nothing to claim`,
+    [CAYED_ERROR__NO_REMATCH_OFFER]: `No rematch has been offered`,
+    [CAYED_ERROR__NO_ROOM_FOR_FLEET]: `Not enough open water to place the fleet`,
     [CAYED_ERROR__NOT_ALL_SHIPS_SUNK]: `Not all ships have been sunk yet`,
+    [CAYED_ERROR__NOT_A_TEAM_GAME]: `This action is only available in team games`,
+    [CAYED_ERROR__NOT_CURRENT_MATCH_GAME]: `Game is not the match's current game`,
+    [CAYED_ERROR__NOT_ENOUGH_PLAYERS]: `At least two players must be queued to make a match`,
+    [CAYED_ERROR__NOT_GAME_CREATOR]: `Only the creator can cancel a game`,
+    [CAYED_ERROR__NOT_INVITED]: `This game is invite-only and you are not invited`,
+    [CAYED_ERROR__NOT_QUEUED]: `You are not in this queue`,
+    [CAYED_ERROR__NOT_TOURNAMENT_GAME]: `The game is not part of this tournament's current round`,
+    [CAYED_ERROR__OPEN_GAMES_PAGE_FULL]: `This open games page is full`,
+    [CAYED_ERROR__OPPONENT_HAS_TIME]: `The player on turn still has time left`,
     [CAYED_ERROR__OVERFLOW]: `Number overflowed`,
+    [CAYED_ERROR__PAIRING_NOT_OVERDUE]: `The pairing's deadline has not passed`,
+    [CAYED_ERROR__PAIRING_STARTED]: `This pairing already has a game or a winner`,
+    [CAYED_ERROR__PAIRING_UNDERWAY]: `Both fleets are hidden and the clock is running`,
+    [CAYED_ERROR__POT_ALREADY_CLAIMED]: `The pot has already been claimed`,
+    [CAYED_ERROR__PRIZE_ALREADY_CLAIMED]: `Prize already claimed`,
+    [CAYED_ERROR__QUEUE_FULL]: `The queue is full`,
+    [CAYED_ERROR__RANKED_HANDICAP]: `Ranked games cannot have a handicap`,
+    [CAYED_ERROR__REGISTRATION_CLOSED]: `The tournament is not taking registrations`,
+    [CAYED_ERROR__REMATCH_ALREADY_OFFERED]: `A rematch has already been offered`,
+    [CAYED_ERROR__REMATCH_NEEDS_WINNER]: `Double or nothing needs a winner`,
+    [CAYED_ERROR__REVEAL_WINDOW_OPEN]: `The opponent can still reveal their coin flip secret`,
+    [CAYED_ERROR__SEASON_CLOSED]: `The season is already closed`,
+    [CAYED_ERROR__SEASON_IN_PROGRESS]: `Another season is still running`,
+    [CAYED_ERROR__SEASON_NOT_CLOSED]: `The season has not closed yet`,
+    [CAYED_ERROR__SEASON_NOT_OVER]: `The season has not ended yet`,
+    [CAYED_ERROR__SEASON_REQUIRED]: `The current season account must be passed`,
+    [CAYED_ERROR__SECRET_ALREADY_REVEALED]: `Secret already revealed`,
     [CAYED_ERROR__SHIP_COORDS_REVERSED]: `Ship start coordinates must be <= end coordinates`,
+    [CAYED_ERROR__SHIP_DAMAGED]: `Damaged ships cannot maneuver`,
     [CAYED_ERROR__SHIP_NOT_LINEAR]: `Ships must be horizontal or vertical, not diagonal or rectangular`,
+    [CAYED_ERROR__SHIP_ON_BLOCKED_CELL]: `Ships cannot be placed on blocked cells`,
     [CAYED_ERROR__SHIP_OVERLAP]: `Two or more ships occupy the same cell`,
     [CAYED_ERROR__SHIPS_ALREADY_PLACED]: `Ships have already been placed on this board`,
     [CAYED_ERROR__SHIPS_NOT_PLACED]: `Ships have not been placed yet`,
+    [CAYED_ERROR__TEAM_INCOMPLETE]: `Both teams must be full before boards can be shared`,
+    [CAYED_ERROR__TIME_BANK_EXPIRED]: `Your time bank has run out`,
+    [CAYED_ERROR__TOO_FEW_ENTRANTS]: `A tournament needs at least two entrants`,
+    [CAYED_ERROR__TOO_MANY_ACTIVE_GAMES]: `You are already in as many games as allowed`,
+    [CAYED_ERROR__TOO_MANY_CHARGES]: `Special weapons are limited to 3 charges each`,
+    [CAYED_ERROR__TOO_MANY_TRAPS]: `Mines and decoys are limited to half the grid size each`,
+    [CAYED_ERROR__TOO_MUCH_AMMO]: `More ammunition than cells on the board`,
+    [CAYED_ERROR__TOURNAMENT_FULL]: `The tournament is full`,
+    [CAYED_ERROR__TOURNAMENT_NOT_FINISHED]: `The tournament has not finished`,
+    [CAYED_ERROR__TOURNAMENT_NOT_IN_PROGRESS]: `The tournament is not in progress`,
     [CAYED_ERROR__UNAUTHORIZED]: `Not authorized to perform this action`,
   };
 }
@@ -11,11 +11,17 @@ import {
   fixDecoderSize,
   fixEncoderSize,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -24,12 +30,14 @@ import {
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -43,6 +51,16 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from "../shared";
+import {
+  getGameSettingsDecoder,
+  getGameSettingsEncoder,
+  getInviteDecoder,
+  getInviteEncoder,
+  type GameSettings,
+  type GameSettingsArgs,
+  type Invite,
+  type InviteArgs,
+} from "../types";
 
 export const CREATE_GAME_DISCRIMINATOR = new Uint8Array([
   124, 69, 75, 66, 184, 220, 72, 206,
@@ -57,7 +75,10 @@ export type CreateGameInstruction<
   TAccountPlayer extends string | AccountMeta<string> = string,
   TAccountGame extends string | AccountMeta<string> = string,
   TAccountPlayerBoard extends string | AccountMeta<string> = string,
+  TAccountPlayerGames extends string | AccountMeta<string> = string,
   TAccountConfig extends string | AccountMeta<string> = string,
+  TAccountOpenGames extends string | AccountMeta<string> = string,
+  TAccountMap extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     "11111111111111111111111111111111",
@@ -76,9 +97,16 @@ export type CreateGameInstruction<
       TAccountPlayerBoard extends string
         ? WritableAccount<TAccountPlayerBoard>
         : TAccountPlayerBoard,
+      TAccountPlayerGames extends string
+        ? WritableAccount<TAccountPlayerGames>
+        : TAccountPlayerGames,
       TAccountConfig extends string
-        ? ReadonlyAccount<TAccountConfig>
+        ? WritableAccount<TAccountConfig>
         : TAccountConfig,
+      TAccountOpenGames extends string
+        ? WritableAccount<TAccountOpenGames>
+        : TAccountOpenGames,
+      TAccountMap extends string ? ReadonlyAccount<TAccountMap> : TAccountMap,
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
@@ -91,39 +119,57 @@ export type CreateGameInstruction<
 
 export type CreateGameInstructionData = {
   discriminator: ReadonlyUint8Array;
-  id: bigint;
   gridSize: number;
   wager: bigint;
+  settings: GameSettings;
+  firstMoveCommitment: Option<Array<number>>;
+  invite: Option<Invite>;
+  page: number;
 };
 
 export type CreateGameInstructionDataArgs = {
-  id: number | bigint;
   gridSize: number;
   wager: number | bigint;
+  settings: GameSettingsArgs;
+  firstMoveCommitment: OptionOrNullable<Array<number>>;
+  invite: OptionOrNullable<InviteArgs>;
+  page: number;
 };
 
-export function getCreateGameInstructionDataEncoder(): FixedSizeEncoder<CreateGameInstructionDataArgs> {
+export function getCreateGameInstructionDataEncoder(): Encoder<CreateGameInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
-      ["id", getU64Encoder()],
       ["gridSize", getU8Encoder()],
       ["wager", getU64Encoder()],
+      ["settings", getGameSettingsEncoder()],
+      [
+        "firstMoveCommitment",
+        getOptionEncoder(getArrayEncoder(getU8Encoder(), { size: 32 })),
+      ],
+      ["invite", getOptionEncoder(getInviteEncoder())],
+      ["page", getU32Encoder()],
     ]),
     (value) => ({ ...value, discriminator: CREATE_GAME_DISCRIMINATOR }),
   );
 }
 
-export function getCreateGameInstructionDataDecoder(): FixedSizeDecoder<CreateGameInstructionData> {
+export function getCreateGameInstructionDataDecoder(): Decoder<CreateGameInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
-    ["id", getU64Decoder()],
     ["gridSize", getU8Decoder()],
     ["wager", getU64Decoder()],
+    ["settings", getGameSettingsDecoder()],
+    [
+      "firstMoveCommitment",
+      getOptionDecoder(getArrayDecoder(getU8Decoder(), { size: 32 })),
+    ],
+    ["invite", getOptionDecoder(getInviteDecoder())],
+    ["page", getU32Decoder()],
   ]);
 }
 
-export function getCreateGameInstructionDataCodec(): FixedSizeCodec<
+export function getCreateGameInstructionDataCodec(): Codec<
   CreateGameInstructionDataArgs,
   CreateGameInstructionData
 > {
@@ -137,26 +183,38 @@ export type CreateGameAsyncInput<
   TAccountPlayer extends string = string,
   TAccountGame extends string = string,
   TAccountPlayerBoard extends string = string,
+  TAccountPlayerGames extends string = string,
   TAccountConfig extends string = string,
+  TAccountOpenGames extends string = string,
+  TAccountMap extends string = string,
   TAccountVault extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   player: TransactionSigner<TAccountPlayer>;
-  game?: Address<TAccountGame>;
-  playerBoard?: Address<TAccountPlayerBoard>;
+  game: Address<TAccountGame>;
+  playerBoard: Address<TAccountPlayerBoard>;
+  playerGames?: Address<TAccountPlayerGames>;
   config?: Address<TAccountConfig>;
+  openGames?: Address<TAccountOpenGames>;
+  map?: Address<TAccountMap>;
   vault?: Address<TAccountVault>;
   systemProgram?: Address<TAccountSystemProgram>;
-  id: CreateGameInstructionDataArgs["id"];
   gridSize: CreateGameInstructionDataArgs["gridSize"];
   wager: CreateGameInstructionDataArgs["wager"];
+  settings: CreateGameInstructionDataArgs["settings"];
+  firstMoveCommitment: CreateGameInstructionDataArgs["firstMoveCommitment"];
+  invite: CreateGameInstructionDataArgs["invite"];
+  page: CreateGameInstructionDataArgs["page"];
 };
 
 export async function getCreateGameInstructionAsync<
   TAccountPlayer extends string,
   TAccountGame extends string,
   TAccountPlayerBoard extends string,
+  TAccountPlayerGames extends string,
   TAccountConfig extends string,
+  TAccountOpenGames extends string,
+  TAccountMap extends string,
   TAccountVault extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof CAYED_PROGRAM_ADDRESS,
@@ -165,7 +223,10 @@ export async function getCreateGameInstructionAsync<
     TAccountPlayer,
     TAccountGame,
     TAccountPlayerBoard,
+    TAccountPlayerGames,
     TAccountConfig,
+    TAccountOpenGames,
+    TAccountMap,
     TAccountVault,
     TAccountSystemProgram
   >,
@@ -176,7 +237,10 @@ export async function getCreateGameInstructionAsync<
     TAccountPlayer,
     TAccountGame,
     TAccountPlayerBoard,
+    TAccountPlayerGames,
     TAccountConfig,
+    TAccountOpenGames,
+    TAccountMap,
     TAccountVault,
     TAccountSystemProgram
   >
@@ -189,7 +253,10 @@ export async function getCreateGameInstructionAsync<
     player: { value: input.player ?? null, isWritable: true },
     game: { value: input.game ?? null, isWritable: true },
     playerBoard: { value: input.playerBoard ?? null, isWritable: true },
-    config: { value: input.config ?? null, isWritable: false },
+    playerGames: { value: input.playerGames ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: true },
+    openGames: { value: input.openGames ?? null, isWritable: true },
+    map: { value: input.map ?? null, isWritable: false },
     vault: { value: input.vault ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -202,30 +269,35 @@ export async function getCreateGameInstructionAsync<
   const args = { ...input };
 
   // Resolve default values.
-  if (!accounts.game.value) {
-    accounts.game.value = await getProgramDerivedAddress({
+  if (!accounts.playerGames.value) {
+    accounts.playerGames.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
-        getBytesEncoder().encode(new Uint8Array([103, 97, 109, 101])),
-        getU64Encoder().encode(expectSome(args.id)),
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 108, 97, 121, 101, 114, 95, 103, 97, 109, 101, 115,
+          ]),
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.player.value)),
       ],
     });
   }
-  if (!accounts.playerBoard.value) {
-    accounts.playerBoard.value = await getProgramDerivedAddress({
+  if (!accounts.config.value) {
+    accounts.config.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
-        getBytesEncoder().encode(new Uint8Array([112, 108, 97, 121, 101, 114])),
-        getU64Encoder().encode(expectSome(args.id)),
-        getAddressEncoder().encode(expectAddress(accounts.player.value)),
+        getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103])),
       ],
     });
   }
-  if (!accounts.config.value) {
-    accounts.config.value = await getProgramDerivedAddress({
+  if (!accounts.openGames.value) {
+    accounts.openGames.value = await getProgramDerivedAddress({
       programAddress,
       seeds: [
-        getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103])),
+        getBytesEncoder().encode(
+          new Uint8Array([111, 112, 101, 110, 95, 103, 97, 109, 101, 115]),
+        ),
+        getU32Encoder().encode(expectSome(args.page)),
       ],
     });
   }
@@ -248,7 +320,10 @@ export async function getCreateGameInstructionAsync<
       getAccountMeta(accounts.player),
       getAccountMeta(accounts.game),
       getAccountMeta(accounts.playerBoard),
+      getAccountMeta(accounts.playerGames),
       getAccountMeta(accounts.config),
+      getAccountMeta(accounts.openGames),
+      getAccountMeta(accounts.map),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -261,7 +336,10 @@ export async function getCreateGameInstructionAsync<
     TAccountPlayer,
     TAccountGame,
     TAccountPlayerBoard,
+    TAccountPlayerGames,
     TAccountConfig,
+    TAccountOpenGames,
+    TAccountMap,
     TAccountVault,
     TAccountSystemProgram
   >);
@@ -271,26 +349,38 @@ export type CreateGameInput<
   TAccountPlayer extends string = string,
   TAccountGame extends string = string,
   TAccountPlayerBoard extends string = string,
+  TAccountPlayerGames extends string = string,
   TAccountConfig extends string = string,
+  TAccountOpenGames extends string = string,
+  TAccountMap extends string = string,
   TAccountVault extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   player: TransactionSigner<TAccountPlayer>;
   game: Address<TAccountGame>;
   playerBoard: Address<TAccountPlayerBoard>;
+  playerGames: Address<TAccountPlayerGames>;
   config: Address<TAccountConfig>;
+  openGames: Address<TAccountOpenGames>;
+  map?: Address<TAccountMap>;
   vault: Address<TAccountVault>;
   systemProgram?: Address<TAccountSystemProgram>;
-  id: CreateGameInstructionDataArgs["id"];
   gridSize: CreateGameInstructionDataArgs["gridSize"];
   wager: CreateGameInstructionDataArgs["wager"];
+  settings: CreateGameInstructionDataArgs["settings"];
+  firstMoveCommitment: CreateGameInstructionDataArgs["firstMoveCommitment"];
+  invite: CreateGameInstructionDataArgs["invite"];
+  page: CreateGameInstructionDataArgs["page"];
 };
 
 export function getCreateGameInstruction<
   TAccountPlayer extends string,
   TAccountGame extends string,
   TAccountPlayerBoard extends string,
+  TAccountPlayerGames extends string,
   TAccountConfig extends string,
+  TAccountOpenGames extends string,
+  TAccountMap extends string,
   TAccountVault extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof CAYED_PROGRAM_ADDRESS,
@@ -299,7 +389,10 @@ export function getCreateGameInstruction<
     TAccountPlayer,
     TAccountGame,
     TAccountPlayerBoard,
+    TAccountPlayerGames,
     TAccountConfig,
+    TAccountOpenGames,
+    TAccountMap,
     TAccountVault,
     TAccountSystemProgram
   >,
@@ -309,7 +402,10 @@ export function getCreateGameInstruction<
   TAccountPlayer,
   TAccountGame,
   TAccountPlayerBoard,
+  TAccountPlayerGames,
   TAccountConfig,
+  TAccountOpenGames,
+  TAccountMap,
   TAccountVault,
   TAccountSystemProgram
 > {
@@ -321,7 +417,10 @@ export function getCreateGameInstruction<
     player: { value: input.player ?? null, isWritable: true },
     game: { value: input.game ?? null, isWritable: true },
     playerBoard: { value: input.playerBoard ?? null, isWritable: true },
-    config: { value: input.config ?? null, isWritable: false },
+    playerGames: { value: input.playerGames ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: true },
+    openGames: { value: input.openGames ?? null, isWritable: true },
+    map: { value: input.map ?? null, isWritable: false },
     vault: { value: input.vault ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -345,7 +444,10 @@ export function getCreateGameInstruction<
       getAccountMeta(accounts.player),
       getAccountMeta(accounts.game),
       getAccountMeta(accounts.playerBoard),
+      getAccountMeta(accounts.playerGames),
       getAccountMeta(accounts.config),
+      getAccountMeta(accounts.openGames),
+      getAccountMeta(accounts.map),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -358,7 +460,10 @@ export function getCreateGameInstruction<
     TAccountPlayer,
     TAccountGame,
     TAccountPlayerBoard,
+    TAccountPlayerGames,
     TAccountConfig,
+    TAccountOpenGames,
+    TAccountMap,
     TAccountVault,
     TAccountSystemProgram
   >);
@@ -373,9 +478,12 @@ export type ParsedCreateGameInstruction<
     player: TAccountMetas[0];
     game: TAccountMetas[1];
     playerBoard: TAccountMetas[2];
-    config: TAccountMetas[3];
-    vault: TAccountMetas[4];
-    systemProgram: TAccountMetas[5];
+    playerGames: TAccountMetas[3];
+    config: TAccountMetas[4];
+    openGames: TAccountMetas[5];
+    map?: TAccountMetas[6] | undefined;
+    vault: TAccountMetas[7];
+    systemProgram: TAccountMetas[8];
   };
   data: CreateGameInstructionData;
 };
@@ -388,7 +496,7 @@ export function parseCreateGameInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedCreateGameInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -398,13 +506,22 @@ export function parseCreateGameInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === CAYED_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       player: getNextAccount(),
       game: getNextAccount(),
       playerBoard: getNextAccount(),
+      playerGames: getNextAccount(),
       config: getNextAccount(),
+      openGames: getNextAccount(),
+      map: getNextOptionalAccount(),
       vault: getNextAccount(),
       systemProgram: getNextAccount(),
     },
@@ -26,7 +26,6 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
-  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -35,8 +34,12 @@ import {
 import { CAYED_PROGRAM_ADDRESS } from "../programs";
 import { getAccountMetaFactory, type ResolvedAccount } from "../shared";
 import {
+  getCellDecoder,
+  getCellEncoder,
   getShipCoordinatesDecoder,
   getShipCoordinatesEncoder,
+  type Cell,
+  type CellArgs,
   type ShipCoordinates,
   type ShipCoordinatesArgs,
 } from "../types";
@@ -64,7 +67,7 @@ export type HideShipsInstruction<
             AccountSignerMeta<TAccountPlayer>
         : TAccountPlayer,
       TAccountGame extends string
-        ? ReadonlyAccount<TAccountGame>
+        ? WritableAccount<TAccountGame>
         : TAccountGame,
       TAccountPlayerBoard extends string
         ? WritableAccount<TAccountPlayerBoard>
@@ -76,10 +79,14 @@ export type HideShipsInstruction<
 export type HideShipsInstructionData = {
   discriminator: ReadonlyUint8Array;
   ships: Array<ShipCoordinates>;
+  mines: Array<Cell>;
+  decoys: Array<Cell>;
 };
 
 export type HideShipsInstructionDataArgs = {
   ships: Array<ShipCoordinatesArgs>;
+  mines: Array<CellArgs>;
+  decoys: Array<CellArgs>;
 };
 
 export function getHideShipsInstructionDataEncoder(): Encoder<HideShipsInstructionDataArgs> {
@@ -87,6 +94,8 @@ export function getHideShipsInstructionDataEncoder(): Encoder<HideShipsInstructi
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["ships", getArrayEncoder(getShipCoordinatesEncoder())],
+      ["mines", getArrayEncoder(getCellEncoder())],
+      ["decoys", getArrayEncoder(getCellEncoder())],
     ]),
     (value) => ({ ...value, discriminator: HIDE_SHIPS_DISCRIMINATOR }),
   );
@@ -96,6 +105,8 @@ export function getHideShipsInstructionDataDecoder(): Decoder<HideShipsInstructi
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["ships", getArrayDecoder(getShipCoordinatesDecoder())],
+    ["mines", getArrayDecoder(getCellDecoder())],
+    ["decoys", getArrayDecoder(getCellDecoder())],
   ]);
 }
 
@@ -118,6 +129,8 @@ export type HideShipsInput<
   game: Address<TAccountGame>;
   playerBoard: Address<TAccountPlayerBoard>;
   ships: HideShipsInstructionDataArgs["ships"];
+  mines: HideShipsInstructionDataArgs["mines"];
+  decoys: HideShipsInstructionDataArgs["decoys"];
 };
 
 export function getHideShipsInstruction<
@@ -140,7 +153,7 @@ export function getHideShipsInstruction<
   // Original accounts.
   const originalAccounts = {
     player: { value: input.player ?? null, isWritable: true },
-    game: { value: input.game ?? null, isWritable: false },
+    game: { value: input.game ?? null, isWritable: true },
     playerBoard: { value: input.playerBoard ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
@@ -6,12 +6,59 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from "./acceptChallenge";
+export * from "./acceptRematch";
+export * from "./autoPlaceShips";
+export * from "./callbackAutoPlaceShips";
+export * from "./callbackFirstMove";
+export * from "./callbackMultiFirstMove";
+export * from "./cancelGame";
+export * from "./cancelMatch";
+export * from "./cancelMultiGame";
+export * from "./cancelTournament";
+export * from "./claimMultiPot";
+export * from "./claimSeasonPrize";
+export * from "./claimTimeout";
+export * from "./claimTournamentPrize";
+export * from "./closeSeason";
 export * from "./createGame";
+export * from "./createMap";
+export * from "./createMatch";
+export * from "./createMultiGame";
 export * from "./createPermission";
+export * from "./createSeason";
+export * from "./createTournament";
 export * from "./delegatePda";
+export * from "./enqueue";
+export * from "./fireTorpedo";
+export * from "./fundEscrow";
+export * from "./hideMultiShips";
 export * from "./hideShips";
 export * from "./initConfig";
 export * from "./joinGame";
+export * from "./joinMatch";
+export * from "./joinMultiGame";
+export * from "./leaveQueue";
 export * from "./makeMove";
+export * from "./maneuverShip";
+export * from "./matchPlayers";
+export * from "./multiMakeMove";
+export * from "./offerRematch";
 export * from "./processUndelegation";
+export * from "./recallTournamentGame";
+export * from "./recordMatchGame";
+export * from "./recordTournamentGame";
+export * from "./registerTournament";
+export * from "./requestFirstMove";
+export * from "./requestMultiFirstMove";
+export * from "./revealCoinFlip";
+export * from "./revealMultiWinner";
 export * from "./revealWinner";
+export * from "./settleGame";
+export * from "./shareBoardWithTeam";
+export * from "./sonarSweep";
+export * from "./startMatchGame";
+export * from "./startTournament";
+export * from "./startTournamentGame";
+export * from "./sweepTournament";
+export * from "./withdrawEscrow";
@@ -79,11 +79,13 @@ export type InitConfigInstructionData = {
   discriminator: ReadonlyUint8Array;
   maxGridSize: number;
   fee: number;
+  maxActiveGames: number;
 };
 
 export type InitConfigInstructionDataArgs = {
   maxGridSize: number;
   fee: number;
+  maxActiveGames: number;
 };
 
 export function getInitConfigInstructionDataEncoder(): FixedSizeEncoder<InitConfigInstructionDataArgs> {
@@ -92,6 +94,7 @@ export function getInitConfigInstructionDataEncoder(): FixedSizeEncoder<InitConf
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["maxGridSize", getU8Encoder()],
       ["fee", getU16Encoder()],
+      ["maxActiveGames", getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: INIT_CONFIG_DISCRIMINATOR }),
   );
@@ -102,6 +105,7 @@ export function getInitConfigInstructionDataDecoder(): FixedSizeDecoder<InitConf
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["maxGridSize", getU8Decoder()],
     ["fee", getU16Decoder()],
+    ["maxActiveGames", getU8Decoder()],
   ]);
 }
 
@@ -127,6 +131,7 @@ export type InitConfigAsyncInput<
   systemProgram?: Address<TAccountSystemProgram>;
   maxGridSize: InitConfigInstructionDataArgs["maxGridSize"];
   fee: InitConfigInstructionDataArgs["fee"];
+  maxActiveGames: InitConfigInstructionDataArgs["maxActiveGames"];
 };
 
 export async function getInitConfigInstructionAsync<
@@ -225,6 +230,7 @@ export type InitConfigInput<
   systemProgram?: Address<TAccountSystemProgram>;
   maxGridSize: InitConfigInstructionDataArgs["maxGridSize"];
   fee: InitConfigInstructionDataArgs["fee"];
+  maxActiveGames: InitConfigInstructionDataArgs["maxActiveGames"];
 };
 
 export function getInitConfigInstruction<
@@ -7,24 +7,37 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -32,7 +45,11 @@ import {
   type WritableSignerAccount,
 } from "@solana/kit";
 import { CAYED_PROGRAM_ADDRESS } from "../programs";
-import { getAccountMetaFactory, type ResolvedAccount } from "../shared";
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from "../shared";
 
 export const JOIN_GAME_DISCRIMINATOR = new Uint8Array([
   107, 112, 18, 38, 56, 173, 60, 128,
@@ -47,6 +64,9 @@ export type JoinGameInstruction<
   TAccountPlayer extends string | AccountMeta<string> = string,
   TAccountGame extends string | AccountMeta<string> = string,
   TAccountPlayerBoard extends string | AccountMeta<string> = string,
+  TAccountPlayerGames extends string | AccountMeta<string> = string,
+  TAccountOpenGames extends string | AccountMeta<string> = string,
+  TAccountConfig extends string | AccountMeta<string> = string,
   TAccountVault extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
     "11111111111111111111111111111111",
@@ -65,6 +85,15 @@ export type JoinGameInstruction<
       TAccountPlayerBoard extends string
         ? WritableAccount<TAccountPlayerBoard>
         : TAccountPlayerBoard,
+      TAccountPlayerGames extends string
+        ? WritableAccount<TAccountPlayerGames>
+        : TAccountPlayerGames,
+      TAccountOpenGames extends string
+        ? WritableAccount<TAccountOpenGames>
+        : TAccountOpenGames,
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
       TAccountVault extends string
         ? WritableAccount<TAccountVault>
         : TAccountVault,
@@ -75,24 +104,53 @@ export type JoinGameInstruction<
     ]
   >;
 
-export type JoinGameInstructionData = { discriminator: ReadonlyUint8Array };
+export type JoinGameInstructionData = {
+  discriminator: ReadonlyUint8Array;
+  firstMoveCommitment: Option<Array<number>>;
+  inviteCode: Option<ReadonlyUint8Array>;
+};
 
-export type JoinGameInstructionDataArgs = {};
+export type JoinGameInstructionDataArgs = {
+  firstMoveCommitment: OptionOrNullable<Array<number>>;
+  inviteCode: OptionOrNullable<ReadonlyUint8Array>;
+};
 
-export function getJoinGameInstructionDataEncoder(): FixedSizeEncoder<JoinGameInstructionDataArgs> {
+export function getJoinGameInstructionDataEncoder(): Encoder<JoinGameInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([["discriminator", fixEncoderSize(getBytesEncoder(), 8)]]),
+    getStructEncoder([
+      ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
+      [
+        "firstMoveCommitment",
+        getOptionEncoder(getArrayEncoder(getU8Encoder(), { size: 32 })),
+      ],
+      [
+        "inviteCode",
+        getOptionEncoder(
+          addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
+        ),
+      ],
+    ]),
     (value) => ({ ...value, discriminator: JOIN_GAME_DISCRIMINATOR }),
   );
 }
 
-export function getJoinGameInstructionDataDecoder(): FixedSizeDecoder<JoinGameInstructionData> {
+export function getJoinGameInstructionDataDecoder(): Decoder<JoinGameInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
+    [
+      "firstMoveCommitment",
+      getOptionDecoder(getArrayDecoder(getU8Decoder(), { size: 32 })),
+    ],
+    [
+      "inviteCode",
+      getOptionDecoder(
+        addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
+      ),
+    ],
   ]);
 }
 
-export function getJoinGameInstructionDataCodec(): FixedSizeCodec<
+export function getJoinGameInstructionDataCodec(): Codec<
   JoinGameInstructionDataArgs,
   JoinGameInstructionData
 > {
@@ -106,20 +164,31 @@ export type JoinGameAsyncInput<
   TAccountPlayer extends string = string,
   TAccountGame extends string = string,
   TAccountPlayerBoard extends string = string,
+  TAccountPlayerGames extends string = string,
+  TAccountOpenGames extends string = string,
+  TAccountConfig extends string = string,
   TAccountVault extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   player: TransactionSigner<TAccountPlayer>;
   game: Address<TAccountGame>;
   playerBoard: Address<TAccountPlayerBoard>;
+  playerGames?: Address<TAccountPlayerGames>;
+  openGames: Address<TAccountOpenGames>;
+  config?: Address<TAccountConfig>;
   vault?: Address<TAccountVault>;
   systemProgram?: Address<TAccountSystemProgram>;
+  firstMoveCommitment: JoinGameInstructionDataArgs["firstMoveCommitment"];
+  inviteCode: JoinGameInstructionDataArgs["inviteCode"];
 };
 
 export async function getJoinGameInstructionAsync<
   TAccountPlayer extends string,
   TAccountGame extends string,
   TAccountPlayerBoard extends string,
+  TAccountPlayerGames extends string,
+  TAccountOpenGames extends string,
+  TAccountConfig extends string,
   TAccountVault extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof CAYED_PROGRAM_ADDRESS,
@@ -128,6 +197,9 @@ export async function getJoinGameInstructionAsync<
     TAccountPlayer,
     TAccountGame,
     TAccountPlayerBoard,
+    TAccountPlayerGames,
+    TAccountOpenGames,
+    TAccountConfig,
     TAccountVault,
     TAccountSystemProgram
   >,
@@ -138,6 +210,9 @@ export async function getJoinGameInstructionAsync<
     TAccountPlayer,
     TAccountGame,
     TAccountPlayerBoard,
+    TAccountPlayerGames,
+    TAccountOpenGames,
+    TAccountConfig,
     TAccountVault,
     TAccountSystemProgram
   >
@@ -150,6 +225,9 @@ export async function getJoinGameInstructionAsync<
     player: { value: input.player ?? null, isWritable: true },
     game: { value: input.game ?? null, isWritable: true },
     playerBoard: { value: input.playerBoard ?? null, isWritable: true },
+    playerGames: { value: input.playerGames ?? null, isWritable: true },
+    openGames: { value: input.openGames ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
     vault: { value: input.vault ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -158,7 +236,31 @@ export async function getJoinGameInstructionAsync<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
+  if (!accounts.playerGames.value) {
+    accounts.playerGames.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            112, 108, 97, 121, 101, 114, 95, 103, 97, 109, 101, 115,
+          ]),
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.player.value)),
+      ],
+    });
+  }
+  if (!accounts.config.value) {
+    accounts.config.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(new Uint8Array([99, 111, 110, 102, 105, 103])),
+      ],
+    });
+  }
   if (!accounts.vault.value) {
     accounts.vault.value = await getProgramDerivedAddress({
       programAddress,
@@ -178,16 +280,24 @@ export async function getJoinGameInstructionAsync<
       getAccountMeta(accounts.player),
       getAccountMeta(accounts.game),
       getAccountMeta(accounts.playerBoard),
+      getAccountMeta(accounts.playerGames),
+      getAccountMeta(accounts.openGames),
+      getAccountMeta(accounts.config),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getJoinGameInstructionDataEncoder().encode({}),
+    data: getJoinGameInstructionDataEncoder().encode(
+      args as JoinGameInstructionDataArgs,
+    ),
     programAddress,
   } as JoinGameInstruction<
     TProgramAddress,
     TAccountPlayer,
     TAccountGame,
     TAccountPlayerBoard,
+    TAccountPlayerGames,
+    TAccountOpenGames,
+    TAccountConfig,
     TAccountVault,
     TAccountSystemProgram
   >);
@@ -197,20 +307,31 @@ export type JoinGameInput<
   TAccountPlayer extends string = string,
   TAccountGame extends string = string,
   TAccountPlayerBoard extends string = string,
+  TAccountPlayerGames extends string = string,
+  TAccountOpenGames extends string = string,
+  TAccountConfig extends string = string,
   TAccountVault extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   player: TransactionSigner<TAccountPlayer>;
   game: Address<TAccountGame>;
   playerBoard: Address<TAccountPlayerBoard>;
+  playerGames: Address<TAccountPlayerGames>;
+  openGames: Address<TAccountOpenGames>;
+  config: Address<TAccountConfig>;
   vault: Address<TAccountVault>;
   systemProgram?: Address<TAccountSystemProgram>;
+  firstMoveCommitment: JoinGameInstructionDataArgs["firstMoveCommitment"];
+  inviteCode: JoinGameInstructionDataArgs["inviteCode"];
 };
 
 export function getJoinGameInstruction<
   TAccountPlayer extends string,
   TAccountGame extends string,
   TAccountPlayerBoard extends string,
+  TAccountPlayerGames extends string,
+  TAccountOpenGames extends string,
+  TAccountConfig extends string,
   TAccountVault extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof CAYED_PROGRAM_ADDRESS,
@@ -219,6 +340,9 @@ export function getJoinGameInstruction<
     TAccountPlayer,
     TAccountGame,
     TAccountPlayerBoard,
+    TAccountPlayerGames,
+    TAccountOpenGames,
+    TAccountConfig,
     TAccountVault,
     TAccountSystemProgram
   >,
@@ -228,6 +352,9 @@ export function getJoinGameInstruction<
   TAccountPlayer,
   TAccountGame,
   TAccountPlayerBoard,
+  TAccountPlayerGames,
+  TAccountOpenGames,
+  TAccountConfig,
   TAccountVault,
   TAccountSystemProgram
 > {
@@ -239,6 +366,9 @@ export function getJoinGameInstruction<
     player: { value: input.player ?? null, isWritable: true },
     game: { value: input.game ?? null, isWritable: true },
     playerBoard: { value: input.playerBoard ?? null, isWritable: true },
+    playerGames: { value: input.playerGames ?? null, isWritable: true },
+    openGames: { value: input.openGames ?? null, isWritable: true },
+    config: { value: input.config ?? null, isWritable: false },
     vault: { value: input.vault ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -247,6 +377,9 @@ export function getJoinGameInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -259,16 +392,24 @@ export function getJoinGameInstruction<
       getAccountMeta(accounts.player),
       getAccountMeta(accounts.game),
       getAccountMeta(accounts.playerBoard),
+      getAccountMeta(accounts.playerGames),
+      getAccountMeta(accounts.openGames),
+      getAccountMeta(accounts.config),
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getJoinGameInstructionDataEncoder().encode({}),
+    data: getJoinGameInstructionDataEncoder().encode(
+      args as JoinGameInstructionDataArgs,
+    ),
     programAddress,
   } as JoinGameInstruction<
     TProgramAddress,
     TAccountPlayer,
     TAccountGame,
     TAccountPlayerBoard,
+    TAccountPlayerGames,
+    TAccountOpenGames,
+    TAccountConfig,
     TAccountVault,
     TAccountSystemProgram
   >);
@@ -283,8 +424,11 @@ export type ParsedJoinGameInstruction<
     player: TAccountMetas[0];
     game: TAccountMetas[1];
     playerBoard: TAccountMetas[2];
-    vault: TAccountMetas[3];
-    systemProgram: TAccountMetas[4];
+    playerGames: TAccountMetas[3];
+    openGames: TAccountMetas[4];
+    config: TAccountMetas[5];
+    vault: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
   };
   data: JoinGameInstructionData;
 };
@@ -297,7 +441,7 @@ export function parseJoinGameInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedJoinGameInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -313,6 +457,9 @@ export function parseJoinGameInstruction<
       player: getNextAccount(),
       game: getNextAccount(),
       playerBoard: getNextAccount(),
+      playerGames: getNextAccount(),
+      openGames: getNextAccount(),
+      config: getNextAccount(),
       vault: getNextAccount(),
       systemProgram: getNextAccount(),
     },
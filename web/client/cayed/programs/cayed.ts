@@ -17,33 +17,138 @@ import {
   type ReadonlyUint8Array,
 } from "@solana/kit";
 import {
+  parseAcceptChallengeInstruction,
+  parseAcceptRematchInstruction,
+  parseAutoPlaceShipsInstruction,
+  parseCallbackAutoPlaceShipsInstruction,
+  parseCallbackFirstMoveInstruction,
+  parseCallbackMultiFirstMoveInstruction,
+  parseCancelGameInstruction,
+  parseCancelMatchInstruction,
+  parseCancelMultiGameInstruction,
+  parseCancelTournamentInstruction,
+  parseClaimMultiPotInstruction,
+  parseClaimSeasonPrizeInstruction,
+  parseClaimTimeoutInstruction,
+  parseClaimTournamentPrizeInstruction,
+  parseCloseSeasonInstruction,
   parseCreateGameInstruction,
+  parseCreateMapInstruction,
+  parseCreateMatchInstruction,
+  parseCreateMultiGameInstruction,
   parseCreatePermissionInstruction,
+  parseCreateSeasonInstruction,
+  parseCreateTournamentInstruction,
   parseDelegatePdaInstruction,
+  parseEnqueueInstruction,
+  parseFireTorpedoInstruction,
+  parseFundEscrowInstruction,
+  parseHideMultiShipsInstruction,
   parseHideShipsInstruction,
   parseInitConfigInstruction,
   parseJoinGameInstruction,
+  parseJoinMatchInstruction,
+  parseJoinMultiGameInstruction,
+  parseLeaveQueueInstruction,
   parseMakeMoveInstruction,
+  parseManeuverShipInstruction,
+  parseMatchPlayersInstruction,
+  parseMultiMakeMoveInstruction,
+  parseOfferRematchInstruction,
   parseProcessUndelegationInstruction,
+  parseRecallTournamentGameInstruction,
+  parseRecordMatchGameInstruction,
+  parseRecordTournamentGameInstruction,
+  parseRegisterTournamentInstruction,
+  parseRequestFirstMoveInstruction,
+  parseRequestMultiFirstMoveInstruction,
+  parseRevealCoinFlipInstruction,
+  parseRevealMultiWinnerInstruction,
   parseRevealWinnerInstruction,
+  parseSettleGameInstruction,
+  parseShareBoardWithTeamInstruction,
+  parseSonarSweepInstruction,
+  parseStartMatchGameInstruction,
+  parseStartTournamentGameInstruction,
+  parseStartTournamentInstruction,
+  parseSweepTournamentInstruction,
+  parseWithdrawEscrowInstruction,
+  type ParsedAcceptChallengeInstruction,
+  type ParsedAcceptRematchInstruction,
+  type ParsedAutoPlaceShipsInstruction,
+  type ParsedCallbackAutoPlaceShipsInstruction,
+  type ParsedCallbackFirstMoveInstruction,
+  type ParsedCallbackMultiFirstMoveInstruction,
+  type ParsedCancelGameInstruction,
+  type ParsedCancelMatchInstruction,
+  type ParsedCancelMultiGameInstruction,
+  type ParsedCancelTournamentInstruction,
+  type ParsedClaimMultiPotInstruction,
+  type ParsedClaimSeasonPrizeInstruction,
+  type ParsedClaimTimeoutInstruction,
+  type ParsedClaimTournamentPrizeInstruction,
+  type ParsedCloseSeasonInstruction,
   type ParsedCreateGameInstruction,
+  type ParsedCreateMapInstruction,
+  type ParsedCreateMatchInstruction,
+  type ParsedCreateMultiGameInstruction,
   type ParsedCreatePermissionInstruction,
+  type ParsedCreateSeasonInstruction,
+  type ParsedCreateTournamentInstruction,
   type ParsedDelegatePdaInstruction,
+  type ParsedEnqueueInstruction,
+  type ParsedFireTorpedoInstruction,
+  type ParsedFundEscrowInstruction,
+  type ParsedHideMultiShipsInstruction,
   type ParsedHideShipsInstruction,
   type ParsedInitConfigInstruction,
   type ParsedJoinGameInstruction,
+  type ParsedJoinMatchInstruction,
+  type ParsedJoinMultiGameInstruction,
+  type ParsedLeaveQueueInstruction,
   type ParsedMakeMoveInstruction,
+  type ParsedManeuverShipInstruction,
+  type ParsedMatchPlayersInstruction,
+  type ParsedMultiMakeMoveInstruction,
+  type ParsedOfferRematchInstruction,
   type ParsedProcessUndelegationInstruction,
+  type ParsedRecallTournamentGameInstruction,
+  type ParsedRecordMatchGameInstruction,
+  type ParsedRecordTournamentGameInstruction,
+  type ParsedRegisterTournamentInstruction,
+  type ParsedRequestFirstMoveInstruction,
+  type ParsedRequestMultiFirstMoveInstruction,
+  type ParsedRevealCoinFlipInstruction,
+  type ParsedRevealMultiWinnerInstruction,
   type ParsedRevealWinnerInstruction,
+  type ParsedSettleGameInstruction,
+  type ParsedShareBoardWithTeamInstruction,
+  type ParsedSonarSweepInstruction,
+  type ParsedStartMatchGameInstruction,
+  type ParsedStartTournamentGameInstruction,
+  type ParsedStartTournamentInstruction,
+  type ParsedSweepTournamentInstruction,
+  type ParsedWithdrawEscrowInstruction,
 } from "../instructions";
 
 export const CAYED_PROGRAM_ADDRESS =
   "6xLHbAHw2ibrmdVEPHm7jDkDmghw3fp3gUCBy511DMKV" as Address<"6xLHbAHw2ibrmdVEPHm7jDkDmghw3fp3gUCBy511DMKV">;
 
 export enum CayedAccount {
+  ChallengeReceipt,
   Config,
+  Escrow,
   Game,
+  Map,
+  Match,
+  MultiGame,
+  OpenGames,
   PlayerBoard,
+  PlayerGames,
+  PlayerProfile,
+  Queue,
+  Season,
+  Tournament,
   Vault,
 }
 
@@ -51,6 +156,17 @@ export function identifyCayedAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array,
 ): CayedAccount {
   const data = "data" in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([238, 85, 50, 147, 103, 23, 130, 37]),
+      ),
+      0,
+    )
+  ) {
+    return CayedAccount.ChallengeReceipt;
+  }
   if (
     containsBytes(
       data,
@@ -62,6 +178,17 @@ export function identifyCayedAccount(
   ) {
     return CayedAccount.Config;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([31, 213, 123, 187, 186, 22, 218, 155]),
+      ),
+      0,
+    )
+  ) {
+    return CayedAccount.Escrow;
+  }
   if (
     containsBytes(
       data,
@@ -73,6 +200,50 @@ export function identifyCayedAccount(
   ) {
     return CayedAccount.Game;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([182, 30, 142, 151, 42, 241, 180, 244]),
+      ),
+      0,
+    )
+  ) {
+    return CayedAccount.Map;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([236, 63, 169, 38, 15, 56, 196, 162]),
+      ),
+      0,
+    )
+  ) {
+    return CayedAccount.Match;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([96, 6, 172, 242, 139, 84, 7, 157]),
+      ),
+      0,
+    )
+  ) {
+    return CayedAccount.MultiGame;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([249, 223, 236, 186, 122, 111, 11, 55]),
+      ),
+      0,
+    )
+  ) {
+    return CayedAccount.OpenGames;
+  }
   if (
     containsBytes(
       data,
@@ -84,6 +255,61 @@ export function identifyCayedAccount(
   ) {
     return CayedAccount.PlayerBoard;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([152, 30, 0, 98, 182, 99, 110, 192]),
+      ),
+      0,
+    )
+  ) {
+    return CayedAccount.PlayerGames;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([82, 226, 99, 87, 164, 130, 181, 80]),
+      ),
+      0,
+    )
+  ) {
+    return CayedAccount.PlayerProfile;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([204, 167, 6, 247, 20, 33, 2, 188]),
+      ),
+      0,
+    )
+  ) {
+    return CayedAccount.Queue;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([76, 67, 93, 156, 180, 157, 248, 47]),
+      ),
+      0,
+    )
+  ) {
+    return CayedAccount.Season;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([175, 139, 119, 242, 115, 194, 57, 92]),
+      ),
+      0,
+    )
+  ) {
+    return CayedAccount.Tournament;
+  }
   if (
     containsBytes(
       data,
@@ -101,15 +327,62 @@ export function identifyCayedAccount(
 }
 
 export enum CayedInstruction {
+  AcceptChallenge,
+  AcceptRematch,
+  AutoPlaceShips,
+  CallbackAutoPlaceShips,
+  CallbackFirstMove,
+  CallbackMultiFirstMove,
+  CancelGame,
+  CancelMatch,
+  CancelMultiGame,
+  CancelTournament,
+  ClaimMultiPot,
+  ClaimSeasonPrize,
+  ClaimTimeout,
+  ClaimTournamentPrize,
+  CloseSeason,
   CreateGame,
+  CreateMap,
+  CreateMatch,
+  CreateMultiGame,
   CreatePermission,
+  CreateSeason,
+  CreateTournament,
   DelegatePda,
+  Enqueue,
+  FireTorpedo,
+  FundEscrow,
+  HideMultiShips,
   HideShips,
   InitConfig,
   JoinGame,
+  JoinMatch,
+  JoinMultiGame,
+  LeaveQueue,
   MakeMove,
+  ManeuverShip,
+  MatchPlayers,
+  MultiMakeMove,
+  OfferRematch,
   ProcessUndelegation,
+  RecallTournamentGame,
+  RecordMatchGame,
+  RecordTournamentGame,
+  RegisterTournament,
+  RequestFirstMove,
+  RequestMultiFirstMove,
+  RevealCoinFlip,
+  RevealMultiWinner,
   RevealWinner,
+  SettleGame,
+  ShareBoardWithTeam,
+  SonarSweep,
+  StartMatchGame,
+  StartTournament,
+  StartTournamentGame,
+  SweepTournament,
+  WithdrawEscrow,
 }
 
 export function identifyCayedInstruction(
@@ -120,100 +393,617 @@ export function identifyCayedInstruction(
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([124, 69, 75, 66, 184, 220, 72, 206]),
+        new Uint8Array([195, 227, 139, 241, 55, 193, 153, 105]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.AcceptChallenge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([139, 79, 31, 212, 167, 74, 60, 165]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.AcceptRematch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([129, 197, 27, 234, 125, 116, 84, 212]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.AutoPlaceShips;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([219, 203, 242, 167, 139, 190, 209, 67]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CallbackAutoPlaceShips;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([141, 101, 67, 25, 115, 121, 11, 104]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CallbackFirstMove;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([58, 176, 79, 161, 217, 172, 208, 214]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CallbackMultiFirstMove;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([121, 194, 154, 118, 103, 235, 149, 52]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CancelGame;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([142, 136, 247, 45, 92, 112, 180, 83]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CancelMatch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([142, 217, 230, 53, 189, 41, 120, 126]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CancelMultiGame;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([249, 227, 133, 5, 9, 142, 29, 122]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CancelTournament;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([178, 191, 37, 18, 50, 128, 89, 139]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.ClaimMultiPot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([151, 171, 59, 18, 116, 101, 22, 202]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.ClaimSeasonPrize;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([130, 234, 45, 53, 120, 90, 86, 178]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.ClaimTimeout;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([219, 207, 183, 94, 201, 32, 78, 193]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.ClaimTournamentPrize;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([162, 198, 31, 37, 77, 0, 199, 152]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CloseSeason;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([124, 69, 75, 66, 184, 220, 72, 206]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CreateGame;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([119, 8, 165, 241, 187, 193, 182, 112]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CreateMap;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([107, 2, 184, 145, 70, 142, 17, 165]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CreateMatch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([73, 214, 13, 191, 95, 140, 30, 31]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CreateMultiGame;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([190, 182, 26, 164, 156, 221, 8, 0]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CreatePermission;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([38, 108, 29, 127, 60, 126, 101, 3]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CreateSeason;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([158, 137, 233, 231, 73, 132, 191, 68]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.CreateTournament;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([248, 217, 193, 46, 124, 191, 64, 135]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.DelegatePda;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([151, 163, 241, 121, 38, 25, 78, 144]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.Enqueue;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([164, 105, 59, 7, 97, 48, 97, 78]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.FireTorpedo;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([155, 18, 218, 141, 182, 213, 69, 201]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.FundEscrow;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([211, 187, 36, 88, 39, 239, 139, 246]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.HideMultiShips;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([51, 136, 140, 51, 235, 220, 133, 69]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.HideShips;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([23, 235, 115, 232, 168, 96, 1, 231]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.InitConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([107, 112, 18, 38, 56, 173, 60, 128]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.JoinGame;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([244, 8, 47, 130, 192, 59, 179, 44]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.JoinMatch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([255, 80, 122, 221, 240, 248, 196, 243]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.JoinMultiGame;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([95, 75, 87, 92, 172, 245, 65, 97]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.LeaveQueue;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([78, 77, 152, 203, 222, 211, 208, 233]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.MakeMove;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([193, 211, 126, 141, 48, 232, 111, 143]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.ManeuverShip;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([29, 175, 224, 34, 171, 46, 252, 234]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.MatchPlayers;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([244, 107, 153, 93, 160, 100, 240, 196]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.MultiMakeMove;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([48, 46, 17, 31, 141, 124, 38, 177]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.OfferRematch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([196, 28, 41, 206, 48, 37, 51, 167]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.ProcessUndelegation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([79, 101, 126, 249, 25, 26, 49, 131]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.RecallTournamentGame;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([114, 19, 239, 105, 191, 90, 55, 142]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.RecordMatchGame;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([126, 27, 174, 46, 150, 77, 220, 75]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.RecordTournamentGame;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([205, 0, 187, 189, 181, 124, 208, 97]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.RegisterTournament;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([190, 170, 96, 222, 161, 231, 218, 157]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.RequestFirstMove;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([146, 109, 87, 229, 142, 131, 253, 151]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.RequestMultiFirstMove;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([57, 8, 28, 244, 191, 182, 156, 55]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.RevealCoinFlip;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([248, 175, 103, 87, 19, 6, 222, 226]),
+      ),
+      0,
+    )
+  ) {
+    return CayedInstruction.RevealMultiWinner;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([234, 209, 237, 109, 16, 196, 64, 254]),
       ),
       0,
     )
   ) {
-    return CayedInstruction.CreateGame;
+    return CayedInstruction.RevealWinner;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([190, 182, 26, 164, 156, 221, 8, 0]),
+        new Uint8Array([96, 54, 24, 189, 239, 198, 86, 29]),
       ),
       0,
     )
   ) {
-    return CayedInstruction.CreatePermission;
+    return CayedInstruction.SettleGame;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([248, 217, 193, 46, 124, 191, 64, 135]),
+        new Uint8Array([41, 42, 130, 39, 116, 34, 70, 9]),
       ),
       0,
     )
   ) {
-    return CayedInstruction.DelegatePda;
+    return CayedInstruction.ShareBoardWithTeam;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([51, 136, 140, 51, 235, 220, 133, 69]),
+        new Uint8Array([82, 89, 117, 28, 234, 127, 178, 189]),
       ),
       0,
     )
   ) {
-    return CayedInstruction.HideShips;
+    return CayedInstruction.SonarSweep;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([23, 235, 115, 232, 168, 96, 1, 231]),
+        new Uint8Array([205, 16, 241, 251, 158, 57, 68, 123]),
       ),
       0,
     )
   ) {
-    return CayedInstruction.InitConfig;
+    return CayedInstruction.StartMatchGame;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([107, 112, 18, 38, 56, 173, 60, 128]),
+        new Uint8Array([164, 168, 208, 157, 43, 10, 220, 241]),
       ),
       0,
     )
   ) {
-    return CayedInstruction.JoinGame;
+    return CayedInstruction.StartTournament;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([78, 77, 152, 203, 222, 211, 208, 233]),
+        new Uint8Array([143, 62, 107, 223, 32, 253, 49, 152]),
       ),
       0,
     )
   ) {
-    return CayedInstruction.MakeMove;
+    return CayedInstruction.StartTournamentGame;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([196, 28, 41, 206, 48, 37, 51, 167]),
+        new Uint8Array([191, 69, 17, 214, 52, 32, 108, 135]),
       ),
       0,
     )
   ) {
-    return CayedInstruction.ProcessUndelegation;
+    return CayedInstruction.SweepTournament;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([234, 209, 237, 109, 16, 196, 64, 254]),
+        new Uint8Array([81, 84, 226, 128, 245, 47, 96, 104]),
       ),
       0,
     )
   ) {
-    return CayedInstruction.RevealWinner;
+    return CayedInstruction.WithdrawEscrow;
   }
   throw new Error(
     "The provided instruction could not be identified as a cayed instruction.",
@@ -223,15 +1013,87 @@ export function identifyCayedInstruction(
 export type ParsedCayedInstruction<
   TProgram extends string = "6xLHbAHw2ibrmdVEPHm7jDkDmghw3fp3gUCBy511DMKV",
 > =
+  | ({
+      instructionType: CayedInstruction.AcceptChallenge;
+    } & ParsedAcceptChallengeInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.AcceptRematch;
+    } & ParsedAcceptRematchInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.AutoPlaceShips;
+    } & ParsedAutoPlaceShipsInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CallbackAutoPlaceShips;
+    } & ParsedCallbackAutoPlaceShipsInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CallbackFirstMove;
+    } & ParsedCallbackFirstMoveInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CallbackMultiFirstMove;
+    } & ParsedCallbackMultiFirstMoveInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CancelGame;
+    } & ParsedCancelGameInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CancelMatch;
+    } & ParsedCancelMatchInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CancelMultiGame;
+    } & ParsedCancelMultiGameInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CancelTournament;
+    } & ParsedCancelTournamentInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.ClaimMultiPot;
+    } & ParsedClaimMultiPotInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.ClaimSeasonPrize;
+    } & ParsedClaimSeasonPrizeInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.ClaimTimeout;
+    } & ParsedClaimTimeoutInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.ClaimTournamentPrize;
+    } & ParsedClaimTournamentPrizeInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CloseSeason;
+    } & ParsedCloseSeasonInstruction<TProgram>)
   | ({
       instructionType: CayedInstruction.CreateGame;
     } & ParsedCreateGameInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CreateMap;
+    } & ParsedCreateMapInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CreateMatch;
+    } & ParsedCreateMatchInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CreateMultiGame;
+    } & ParsedCreateMultiGameInstruction<TProgram>)
   | ({
       instructionType: CayedInstruction.CreatePermission;
     } & ParsedCreatePermissionInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CreateSeason;
+    } & ParsedCreateSeasonInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.CreateTournament;
+    } & ParsedCreateTournamentInstruction<TProgram>)
   | ({
       instructionType: CayedInstruction.DelegatePda;
     } & ParsedDelegatePdaInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.Enqueue;
+    } & ParsedEnqueueInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.FireTorpedo;
+    } & ParsedFireTorpedoInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.FundEscrow;
+    } & ParsedFundEscrowInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.HideMultiShips;
+    } & ParsedHideMultiShipsInstruction<TProgram>)
   | ({
       instructionType: CayedInstruction.HideShips;
     } & ParsedHideShipsInstruction<TProgram>)
@@ -241,21 +1103,195 @@ export type ParsedCayedInstruction<
   | ({
       instructionType: CayedInstruction.JoinGame;
     } & ParsedJoinGameInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.JoinMatch;
+    } & ParsedJoinMatchInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.JoinMultiGame;
+    } & ParsedJoinMultiGameInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.LeaveQueue;
+    } & ParsedLeaveQueueInstruction<TProgram>)
   | ({
       instructionType: CayedInstruction.MakeMove;
     } & ParsedMakeMoveInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.ManeuverShip;
+    } & ParsedManeuverShipInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.MatchPlayers;
+    } & ParsedMatchPlayersInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.MultiMakeMove;
+    } & ParsedMultiMakeMoveInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.OfferRematch;
+    } & ParsedOfferRematchInstruction<TProgram>)
   | ({
       instructionType: CayedInstruction.ProcessUndelegation;
     } & ParsedProcessUndelegationInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.RecallTournamentGame;
+    } & ParsedRecallTournamentGameInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.RecordMatchGame;
+    } & ParsedRecordMatchGameInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.RecordTournamentGame;
+    } & ParsedRecordTournamentGameInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.RegisterTournament;
+    } & ParsedRegisterTournamentInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.RequestFirstMove;
+    } & ParsedRequestFirstMoveInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.RequestMultiFirstMove;
+    } & ParsedRequestMultiFirstMoveInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.RevealCoinFlip;
+    } & ParsedRevealCoinFlipInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.RevealMultiWinner;
+    } & ParsedRevealMultiWinnerInstruction<TProgram>)
   | ({
       instructionType: CayedInstruction.RevealWinner;
-    } & ParsedRevealWinnerInstruction<TProgram>);
+    } & ParsedRevealWinnerInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.SettleGame;
+    } & ParsedSettleGameInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.ShareBoardWithTeam;
+    } & ParsedShareBoardWithTeamInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.SonarSweep;
+    } & ParsedSonarSweepInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.StartMatchGame;
+    } & ParsedStartMatchGameInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.StartTournament;
+    } & ParsedStartTournamentInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.StartTournamentGame;
+    } & ParsedStartTournamentGameInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.SweepTournament;
+    } & ParsedSweepTournamentInstruction<TProgram>)
+  | ({
+      instructionType: CayedInstruction.WithdrawEscrow;
+    } & ParsedWithdrawEscrowInstruction<TProgram>);
 
 export function parseCayedInstruction<TProgram extends string>(
   instruction: Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array>,
 ): ParsedCayedInstruction<TProgram> {
   const instructionType = identifyCayedInstruction(instruction);
   switch (instructionType) {
+    case CayedInstruction.AcceptChallenge: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.AcceptChallenge,
+        ...parseAcceptChallengeInstruction(instruction),
+      };
+    }
+    case CayedInstruction.AcceptRematch: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.AcceptRematch,
+        ...parseAcceptRematchInstruction(instruction),
+      };
+    }
+    case CayedInstruction.AutoPlaceShips: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.AutoPlaceShips,
+        ...parseAutoPlaceShipsInstruction(instruction),
+      };
+    }
+    case CayedInstruction.CallbackAutoPlaceShips: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CallbackAutoPlaceShips,
+        ...parseCallbackAutoPlaceShipsInstruction(instruction),
+      };
+    }
+    case CayedInstruction.CallbackFirstMove: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CallbackFirstMove,
+        ...parseCallbackFirstMoveInstruction(instruction),
+      };
+    }
+    case CayedInstruction.CallbackMultiFirstMove: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CallbackMultiFirstMove,
+        ...parseCallbackMultiFirstMoveInstruction(instruction),
+      };
+    }
+    case CayedInstruction.CancelGame: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CancelGame,
+        ...parseCancelGameInstruction(instruction),
+      };
+    }
+    case CayedInstruction.CancelMatch: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CancelMatch,
+        ...parseCancelMatchInstruction(instruction),
+      };
+    }
+    case CayedInstruction.CancelMultiGame: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CancelMultiGame,
+        ...parseCancelMultiGameInstruction(instruction),
+      };
+    }
+    case CayedInstruction.CancelTournament: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CancelTournament,
+        ...parseCancelTournamentInstruction(instruction),
+      };
+    }
+    case CayedInstruction.ClaimMultiPot: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.ClaimMultiPot,
+        ...parseClaimMultiPotInstruction(instruction),
+      };
+    }
+    case CayedInstruction.ClaimSeasonPrize: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.ClaimSeasonPrize,
+        ...parseClaimSeasonPrizeInstruction(instruction),
+      };
+    }
+    case CayedInstruction.ClaimTimeout: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.ClaimTimeout,
+        ...parseClaimTimeoutInstruction(instruction),
+      };
+    }
+    case CayedInstruction.ClaimTournamentPrize: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.ClaimTournamentPrize,
+        ...parseClaimTournamentPrizeInstruction(instruction),
+      };
+    }
+    case CayedInstruction.CloseSeason: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CloseSeason,
+        ...parseCloseSeasonInstruction(instruction),
+      };
+    }
     case CayedInstruction.CreateGame: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -263,6 +1299,27 @@ export function parseCayedInstruction<TProgram extends string>(
         ...parseCreateGameInstruction(instruction),
       };
     }
+    case CayedInstruction.CreateMap: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CreateMap,
+        ...parseCreateMapInstruction(instruction),
+      };
+    }
+    case CayedInstruction.CreateMatch: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CreateMatch,
+        ...parseCreateMatchInstruction(instruction),
+      };
+    }
+    case CayedInstruction.CreateMultiGame: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CreateMultiGame,
+        ...parseCreateMultiGameInstruction(instruction),
+      };
+    }
     case CayedInstruction.CreatePermission: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -270,6 +1327,20 @@ export function parseCayedInstruction<TProgram extends string>(
         ...parseCreatePermissionInstruction(instruction),
       };
     }
+    case CayedInstruction.CreateSeason: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CreateSeason,
+        ...parseCreateSeasonInstruction(instruction),
+      };
+    }
+    case CayedInstruction.CreateTournament: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.CreateTournament,
+        ...parseCreateTournamentInstruction(instruction),
+      };
+    }
     case CayedInstruction.DelegatePda: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -277,6 +1348,34 @@ export function parseCayedInstruction<TProgram extends string>(
         ...parseDelegatePdaInstruction(instruction),
       };
     }
+    case CayedInstruction.Enqueue: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.Enqueue,
+        ...parseEnqueueInstruction(instruction),
+      };
+    }
+    case CayedInstruction.FireTorpedo: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.FireTorpedo,
+        ...parseFireTorpedoInstruction(instruction),
+      };
+    }
+    case CayedInstruction.FundEscrow: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.FundEscrow,
+        ...parseFundEscrowInstruction(instruction),
+      };
+    }
+    case CayedInstruction.HideMultiShips: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.HideMultiShips,
+        ...parseHideMultiShipsInstruction(instruction),
+      };
+    }
     case CayedInstruction.HideShips: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -298,6 +1397,27 @@ export function parseCayedInstruction<TProgram extends string>(
         ...parseJoinGameInstruction(instruction),
       };
     }
+    case CayedInstruction.JoinMatch: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.JoinMatch,
+        ...parseJoinMatchInstruction(instruction),
+      };
+    }
+    case CayedInstruction.JoinMultiGame: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.JoinMultiGame,
+        ...parseJoinMultiGameInstruction(instruction),
+      };
+    }
+    case CayedInstruction.LeaveQueue: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.LeaveQueue,
+        ...parseLeaveQueueInstruction(instruction),
+      };
+    }
     case CayedInstruction.MakeMove: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -305,6 +1425,34 @@ export function parseCayedInstruction<TProgram extends string>(
         ...parseMakeMoveInstruction(instruction),
       };
     }
+    case CayedInstruction.ManeuverShip: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.ManeuverShip,
+        ...parseManeuverShipInstruction(instruction),
+      };
+    }
+    case CayedInstruction.MatchPlayers: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.MatchPlayers,
+        ...parseMatchPlayersInstruction(instruction),
+      };
+    }
+    case CayedInstruction.MultiMakeMove: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.MultiMakeMove,
+        ...parseMultiMakeMoveInstruction(instruction),
+      };
+    }
+    case CayedInstruction.OfferRematch: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.OfferRematch,
+        ...parseOfferRematchInstruction(instruction),
+      };
+    }
     case CayedInstruction.ProcessUndelegation: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -312,6 +1460,62 @@ export function parseCayedInstruction<TProgram extends string>(
         ...parseProcessUndelegationInstruction(instruction),
       };
     }
+    case CayedInstruction.RecallTournamentGame: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.RecallTournamentGame,
+        ...parseRecallTournamentGameInstruction(instruction),
+      };
+    }
+    case CayedInstruction.RecordMatchGame: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.RecordMatchGame,
+        ...parseRecordMatchGameInstruction(instruction),
+      };
+    }
+    case CayedInstruction.RecordTournamentGame: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.RecordTournamentGame,
+        ...parseRecordTournamentGameInstruction(instruction),
+      };
+    }
+    case CayedInstruction.RegisterTournament: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.RegisterTournament,
+        ...parseRegisterTournamentInstruction(instruction),
+      };
+    }
+    case CayedInstruction.RequestFirstMove: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.RequestFirstMove,
+        ...parseRequestFirstMoveInstruction(instruction),
+      };
+    }
+    case CayedInstruction.RequestMultiFirstMove: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.RequestMultiFirstMove,
+        ...parseRequestMultiFirstMoveInstruction(instruction),
+      };
+    }
+    case CayedInstruction.RevealCoinFlip: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.RevealCoinFlip,
+        ...parseRevealCoinFlipInstruction(instruction),
+      };
+    }
+    case CayedInstruction.RevealMultiWinner: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.RevealMultiWinner,
+        ...parseRevealMultiWinnerInstruction(instruction),
+      };
+    }
     case CayedInstruction.RevealWinner: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -319,6 +1523,62 @@ export function parseCayedInstruction<TProgram extends string>(
         ...parseRevealWinnerInstruction(instruction),
       };
     }
+    case CayedInstruction.SettleGame: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.SettleGame,
+        ...parseSettleGameInstruction(instruction),
+      };
+    }
+    case CayedInstruction.ShareBoardWithTeam: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.ShareBoardWithTeam,
+        ...parseShareBoardWithTeamInstruction(instruction),
+      };
+    }
+    case CayedInstruction.SonarSweep: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.SonarSweep,
+        ...parseSonarSweepInstruction(instruction),
+      };
+    }
+    case CayedInstruction.StartMatchGame: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.StartMatchGame,
+        ...parseStartMatchGameInstruction(instruction),
+      };
+    }
+    case CayedInstruction.StartTournament: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.StartTournament,
+        ...parseStartTournamentInstruction(instruction),
+      };
+    }
+    case CayedInstruction.StartTournamentGame: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.StartTournamentGame,
+        ...parseStartTournamentGameInstruction(instruction),
+      };
+    }
+    case CayedInstruction.SweepTournament: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.SweepTournament,
+        ...parseSweepTournamentInstruction(instruction),
+      };
+    }
+    case CayedInstruction.WithdrawEscrow: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: CayedInstruction.WithdrawEscrow,
+        ...parseWithdrawEscrowInstruction(instruction),
+      };
+    }
     default:
       throw new Error(
         `Unrecognized instruction type: ${instructionType as string}`,
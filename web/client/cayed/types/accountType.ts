@@ -26,11 +26,15 @@ import {
 
 export type AccountType =
   | { __kind: "Game"; gameId: bigint }
-  | { __kind: "PlayerBoard"; gameId: bigint; player: Address };
+  | { __kind: "PlayerBoard"; gameId: bigint; player: Address }
+  | { __kind: "MultiGame"; gameId: bigint }
+  | { __kind: "MultiPlayerBoard"; gameId: bigint; player: Address };
 
 export type AccountTypeArgs =
   | { __kind: "Game"; gameId: number | bigint }
-  | { __kind: "PlayerBoard"; gameId: number | bigint; player: Address };
+  | { __kind: "PlayerBoard"; gameId: number | bigint; player: Address }
+  | { __kind: "MultiGame"; gameId: number | bigint }
+  | { __kind: "MultiPlayerBoard"; gameId: number | bigint; player: Address };
 
 export function getAccountTypeEncoder(): Encoder<AccountTypeArgs> {
   return getDiscriminatedUnionEncoder([
@@ -42,6 +46,14 @@ export function getAccountTypeEncoder(): Encoder<AccountTypeArgs> {
         ["player", getAddressEncoder()],
       ]),
     ],
+    ["MultiGame", getStructEncoder([["gameId", getU64Encoder()]])],
+    [
+      "MultiPlayerBoard",
+      getStructEncoder([
+        ["gameId", getU64Encoder()],
+        ["player", getAddressEncoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -55,6 +67,14 @@ export function getAccountTypeDecoder(): Decoder<AccountType> {
         ["player", getAddressDecoder()],
       ]),
     ],
+    ["MultiGame", getStructDecoder([["gameId", getU64Decoder()]])],
+    [
+      "MultiPlayerBoard",
+      getStructDecoder([
+        ["gameId", getU64Decoder()],
+        ["player", getAddressDecoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -75,6 +95,22 @@ export function accountType(
     "PlayerBoard"
   >,
 ): GetDiscriminatedUnionVariant<AccountTypeArgs, "__kind", "PlayerBoard">;
+export function accountType(
+  kind: "MultiGame",
+  data: GetDiscriminatedUnionVariantContent<
+    AccountTypeArgs,
+    "__kind",
+    "MultiGame"
+  >,
+): GetDiscriminatedUnionVariant<AccountTypeArgs, "__kind", "MultiGame">;
+export function accountType(
+  kind: "MultiPlayerBoard",
+  data: GetDiscriminatedUnionVariantContent<
+    AccountTypeArgs,
+    "__kind",
+    "MultiPlayerBoard"
+  >,
+): GetDiscriminatedUnionVariant<AccountTypeArgs, "__kind", "MultiPlayerBoard">;
 export function accountType<K extends AccountTypeArgs["__kind"], Data>(
   kind: K,
   data?: Data,
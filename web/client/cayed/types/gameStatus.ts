@@ -12,6 +12,8 @@ import {
   getAddressEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getUnitDecoder,
@@ -22,6 +24,8 @@ import {
   type Encoder,
   type GetDiscriminatedUnionVariant,
   type GetDiscriminatedUnionVariantContent,
+  type Option,
+  type OptionOrNullable,
 } from "@solana/kit";
 
 export type GameStatus =
@@ -31,9 +35,20 @@ export type GameStatus =
   | { __kind: "Cancelled" }
   | { __kind: "Completed"; winner: Address }
   | { __kind: "Forfeited"; winner: Address }
+  | { __kind: "OutOfAmmo"; winner: Option<Address> }
+  | { __kind: "Drawn" }
   | { __kind: "WinnerRevealed"; winner: Address };
 
-export type GameStatusArgs = GameStatus;
+export type GameStatusArgs =
+  | { __kind: "AwaitingPlayerTwo" }
+  | { __kind: "HidingShips" }
+  | { __kind: "InProgress" }
+  | { __kind: "Cancelled" }
+  | { __kind: "Completed"; winner: Address }
+  | { __kind: "Forfeited"; winner: Address }
+  | { __kind: "OutOfAmmo"; winner: OptionOrNullable<Address> }
+  | { __kind: "Drawn" }
+  | { __kind: "WinnerRevealed"; winner: Address };
 
 export function getGameStatusEncoder(): Encoder<GameStatusArgs> {
   return getDiscriminatedUnionEncoder([
@@ -43,6 +58,11 @@ export function getGameStatusEncoder(): Encoder<GameStatusArgs> {
     ["Cancelled", getUnitEncoder()],
     ["Completed", getStructEncoder([["winner", getAddressEncoder()]])],
     ["Forfeited", getStructEncoder([["winner", getAddressEncoder()]])],
+    [
+      "OutOfAmmo",
+      getStructEncoder([["winner", getOptionEncoder(getAddressEncoder())]]),
+    ],
+    ["Drawn", getUnitEncoder()],
     ["WinnerRevealed", getStructEncoder([["winner", getAddressEncoder()]])],
   ]);
 }
@@ -55,6 +75,11 @@ export function getGameStatusDecoder(): Decoder<GameStatus> {
     ["Cancelled", getUnitDecoder()],
     ["Completed", getStructDecoder([["winner", getAddressDecoder()]])],
     ["Forfeited", getStructDecoder([["winner", getAddressDecoder()]])],
+    [
+      "OutOfAmmo",
+      getStructDecoder([["winner", getOptionDecoder(getAddressDecoder())]]),
+    ],
+    ["Drawn", getUnitDecoder()],
     ["WinnerRevealed", getStructDecoder([["winner", getAddressDecoder()]])],
   ]);
 }
@@ -92,6 +117,17 @@ export function gameStatus(
     "Forfeited"
   >,
 ): GetDiscriminatedUnionVariant<GameStatusArgs, "__kind", "Forfeited">;
+export function gameStatus(
+  kind: "OutOfAmmo",
+  data: GetDiscriminatedUnionVariantContent<
+    GameStatusArgs,
+    "__kind",
+    "OutOfAmmo"
+  >,
+): GetDiscriminatedUnionVariant<GameStatusArgs, "__kind", "OutOfAmmo">;
+export function gameStatus(
+  kind: "Drawn",
+): GetDiscriminatedUnionVariant<GameStatusArgs, "__kind", "Drawn">;
 export function gameStatus(
   kind: "WinnerRevealed",
   data: GetDiscriminatedUnionVariantContent<
@@ -7,7 +7,29 @@
  */
 
 export * from "./accountType";
+export * from "./cell";
+export * from "./challenge";
+export * from "./charges";
+export * from "./coinFlip";
+export * from "./entrant";
+export * from "./gameCreated";
+export * from "./gameKind";
+export * from "./gameSettings";
 export * from "./gameStatus";
+export * from "./handicap";
+export * from "./heading";
+export * from "./invite";
+export * from "./matchStatus";
 export * from "./member";
+export * from "./moveOutcome";
 export * from "./moveResult";
+export * from "./multiMoveResult";
+export * from "./openGame";
+export * from "./pairing";
+export * from "./rematchOffer";
+export * from "./revealedShip";
+export * from "./seasonRanking";
 export * from "./shipCoordinates";
+export * from "./shotResult";
+export * from "./standing";
+export * from "./tournamentStatus";
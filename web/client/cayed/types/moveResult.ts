@@ -8,40 +8,78 @@
 
 import {
   combineCodec,
-  getBooleanDecoder,
-  getBooleanEncoder,
+  getAddressDecoder,
+  getAddressEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type Option,
+  type OptionOrNullable,
 } from "@solana/kit";
+import {
+  getMoveOutcomeDecoder,
+  getMoveOutcomeEncoder,
+  type MoveOutcome,
+  type MoveOutcomeArgs,
+} from ".";
 
-export type MoveResult = { x: number; y: number; isHit: boolean };
+export type MoveResult = {
+  shooter: Address;
+  x: number;
+  y: number;
+  outcome: MoveOutcome;
+  /** Index into the opponent's fleet of the ship this move sank, if any. */
+  sunkShip: Option<number>;
+  slot: bigint;
+  unixTimestamp: bigint;
+};
 
-export type MoveResultArgs = MoveResult;
+export type MoveResultArgs = {
+  shooter: Address;
+  x: number;
+  y: number;
+  outcome: MoveOutcomeArgs;
+  /** Index into the opponent's fleet of the ship this move sank, if any. */
+  sunkShip: OptionOrNullable<number>;
+  slot: number | bigint;
+  unixTimestamp: number | bigint;
+};
 
-export function getMoveResultEncoder(): FixedSizeEncoder<MoveResultArgs> {
+export function getMoveResultEncoder(): Encoder<MoveResultArgs> {
   return getStructEncoder([
+    ["shooter", getAddressEncoder()],
     ["x", getU8Encoder()],
     ["y", getU8Encoder()],
-    ["isHit", getBooleanEncoder()],
+    ["outcome", getMoveOutcomeEncoder()],
+    ["sunkShip", getOptionEncoder(getU8Encoder())],
+    ["slot", getU64Encoder()],
+    ["unixTimestamp", getI64Encoder()],
   ]);
 }
 
-export function getMoveResultDecoder(): FixedSizeDecoder<MoveResult> {
+export function getMoveResultDecoder(): Decoder<MoveResult> {
   return getStructDecoder([
+    ["shooter", getAddressDecoder()],
     ["x", getU8Decoder()],
     ["y", getU8Decoder()],
-    ["isHit", getBooleanDecoder()],
+    ["outcome", getMoveOutcomeDecoder()],
+    ["sunkShip", getOptionDecoder(getU8Decoder())],
+    ["slot", getU64Decoder()],
+    ["unixTimestamp", getI64Decoder()],
   ]);
 }
 
-export function getMoveResultCodec(): FixedSizeCodec<
-  MoveResultArgs,
-  MoveResult
-> {
+export function getMoveResultCodec(): Codec<MoveResultArgs, MoveResult> {
   return combineCodec(getMoveResultEncoder(), getMoveResultDecoder());
 }
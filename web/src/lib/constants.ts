@@ -1,4 +1,4 @@
-import { CAYED_PROGRAM_ADDRESS } from '@client/cayed';
+import { CAYED_PROGRAM_ADDRESS, type GameSettingsArgs } from '@client/cayed';
 
 export { CAYED_PROGRAM_ADDRESS };
 
@@ -7,6 +7,23 @@ export const LAMPORTS_PER_SOL = 1_000_000_000;
 /** Minimum wager in lamports when non-zero (on-chain: 100,000 lamports). */
 export const MIN_WAGER_LAMPORTS = 100_000;
 
+/** Open-games entries per page (on-chain: `OpenGames::MAX_ENTRIES`). */
+export const OPEN_GAMES_PAGE_SIZE = 32;
+
+/** Classic unranked rules: no mines, decoys, special weapons, clock, handicap or ammo limit. */
+export const DEFAULT_GAME_SETTINGS: GameSettingsArgs = {
+  mines: 0,
+  decoys: 0,
+  sonarCharges: 0,
+  torpedoCharges: 0,
+  timeBankSecs: 0,
+  incrementSecs: 0,
+  handicap: { __kind: 'None' },
+  ammo: 0,
+  maneuvers: false,
+  ranked: false,
+};
+
 /** Ship sizes for a given grid size. Max 5 ships (program limit). Ships count = gridSize / 2. */
 export function getShipSizes(gridSize: number): number[] {
   if (gridSize <= 4) return [2, 1];
@@ -1,29 +1,71 @@
-import type { Game, GameStatus, PlayerBoard } from '@client/cayed';
-import type { MaybeAccount } from '@solana/kit';
+import type {
+  CoinFlip,
+  Game,
+  GameStatus,
+  MoveResult,
+  PlayerBoard,
+  RematchOffer,
+} from '@client/cayed';
+import { isSome, none, some, type MaybeAccount, type Option } from '@solana/kit';
 
 /**
  * On-chain account types decoded by Codama use `bigint` for u64 fields.
  * React 19 dev tooling JSON-serializes component state and throws on bigint,
  * which breaks renders and all UI interaction. Store these UI-safe copies instead.
  */
-export type UiGame = Omit<Game, 'id' | 'wager'> & {
+export type UiMoveResult = Omit<MoveResult, 'slot' | 'unixTimestamp'> & {
+  slot: number;
+  unixTimestamp: number;
+};
+
+export type UiCoinFlip = Omit<CoinFlip, 'revealDeadline'> & {
+  revealDeadline: number;
+};
+
+export type UiRematchOffer = Omit<RematchOffer, 'deposit'> & {
+  deposit: number;
+};
+
+export type UiGame = Omit<
+  Game,
+  | 'id'
+  | 'blockedMask'
+  | 'matchId'
+  | 'tournamentId'
+  | 'moves'
+  | 'coinFlip'
+  | 'turnStartedAt'
+  | 'wager'
+  | 'rematch'
+> & {
   id: number;
+  blockedMask: number;
+  matchId: Option<number>;
+  tournamentId: Option<number>;
+  moves: UiMoveResult[];
+  coinFlip: Option<UiCoinFlip>;
+  turnStartedAt: number;
   wager: number;
+  rematch: Option<UiRematchOffer>;
 };
 
 export type UiPlayerBoard = Omit<
   PlayerBoard,
-  'gameId' | 'shipMasks' | 'allShipsMask' | 'hitsBitmap'
+  'gameId' | 'shipMasks' | 'allShipsMask' | 'hitsBitmap' | 'minesMask' | 'decoysMask'
 > & {
   gameId: number;
   shipMasks: number[];
   allShipsMask: number;
   hitsBitmap: number;
+  minesMask: number;
+  decoysMask: number;
 };
 
 const TERMINAL_GAME_STATUSES = new Set<GameStatus['__kind']>([
   'Completed',
   'Forfeited',
+  'OutOfAmmo',
+  'Drawn',
   'WinnerRevealed',
   'Cancelled',
 ]);
@@ -51,20 +93,30 @@ export function pickAuthoritativeGame(
   return e.moves.length >= b.moves.length ? ephemeral : base;
 }
 
+function toUiOption(value: Option<bigint>): Option<number> {
+  return isSome(value) ? some(Number(value.value)) : none();
+}
+
 export function toUiGame(game: Game): UiGame {
   return {
-    discriminator: game.discriminator,
-    gridSize: game.gridSize,
-    player1: game.player1,
-    player2: game.player2,
-    revealedShipsPlayer1: game.revealedShipsPlayer1,
-    revealedShipsPlayer2: game.revealedShipsPlayer2,
-    moves: game.moves,
-    nextMovePlayer1: game.nextMovePlayer1,
-    status: game.status,
-    bump: game.bump,
+    ...game,
     id: Number(game.id),
+    blockedMask: Number(game.blockedMask),
+    matchId: toUiOption(game.matchId),
+    tournamentId: toUiOption(game.tournamentId),
+    moves: game.moves.map(move => ({
+      ...move,
+      slot: Number(move.slot),
+      unixTimestamp: Number(move.unixTimestamp),
+    })),
+    coinFlip: isSome(game.coinFlip)
+      ? some({ ...game.coinFlip.value, revealDeadline: Number(game.coinFlip.value.revealDeadline) })
+      : none(),
+    turnStartedAt: Number(game.turnStartedAt),
     wager: Number(game.wager),
+    rematch: isSome(game.rematch)
+      ? some({ ...game.rematch.value, deposit: Number(game.rematch.value.deposit) })
+      : none(),
   };
 }
 
@@ -79,5 +131,8 @@ export function toUiPlayerBoard(board: PlayerBoard): UiPlayerBoard {
     shipMasks: board.shipMasks.map(m => Number(m)),
     allShipsMask: Number(board.allShipsMask),
     hitsBitmap: Number(board.hitsBitmap),
+    minesMask: Number(board.minesMask),
+    decoysMask: Number(board.decoysMask),
+    placementSalt: board.placementSalt,
   };
 }
@@ -123,6 +123,8 @@ function BattleshipGameInner({
   const gameOver =
     status === 'Completed' ||
     status === 'Forfeited' ||
+    status === 'OutOfAmmo' ||
+    status === 'Drawn' ||
     status === 'WinnerRevealed' ||
     status === 'Cancelled';
   const isPlacing = status === 'HidingShips' && !myShipsPlaced;
@@ -329,6 +331,9 @@ function BattleshipGameInner({
         allShipsMask: prev?.allShipsMask ?? 0,
         hitsBitmap: prev?.hitsBitmap ?? 0,
         sunkMask: prev?.sunkMask ?? 0,
+        minesMask: prev?.minesMask ?? 0,
+        decoysMask: prev?.decoysMask ?? 0,
+        placementSalt: prev?.placementSalt ?? [],
       }));
 
       await fetchState();
@@ -521,7 +526,9 @@ function BattleshipGameInner({
       ? game.status.winner
       : game?.status.__kind === 'WinnerRevealed'
         ? game.status.winner
-        : null;
+        : game?.status.__kind === 'OutOfAmmo' && isSome(game.status.winner)
+          ? game.status.winner.value
+          : null;
 
   // Game link for sharing
   const gameLink =
@@ -604,7 +611,7 @@ function BattleshipGameInner({
     );
   }
 
-  if (status === 'Completed') {
+  if (status === 'Completed' || status === 'OutOfAmmo') {
     return (
       <FinishedStage
         {...stageBase}
@@ -616,7 +623,7 @@ function BattleshipGameInner({
     );
   }
 
-  if (status === 'WinnerRevealed') {
+  if (status === 'WinnerRevealed' || status === 'Drawn') {
     return (
       <RevealedStage
         {...stageBase}
@@ -120,12 +120,8 @@ function LobbyConnected({ account }: { account: UiWalletAccount }) {
     setCreating(true);
     setError(null);
     try {
-      const gameId = BigInt(Date.now());
-
-      // FIX: Await the createGame call
-      await gameService.createGame({
+      const { gameId } = await gameService.createGame({
         player: signer,
-        gameId,
         gridSize,
         wager: wagerLamports,
       });
@@ -310,6 +306,10 @@ function statusLabel(status: UiGame['status']): { text: string; color: string }
       return { text: 'CANCELLED', color: 'text-arcade-muted' };
     case 'Forfeited':
       return { text: 'FORFEITED', color: 'text-arcade-muted' };
+    case 'OutOfAmmo':
+      return { text: 'OUT OF AMMO', color: 'text-arcade-green' };
+    case 'Drawn':
+      return { text: 'DRAWN', color: 'text-arcade-muted' };
     default:
       return { text: 'UNKNOWN', color: 'text-arcade-muted' };
   }
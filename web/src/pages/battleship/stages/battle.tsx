@@ -35,6 +35,8 @@ export function BattleStage({
   const gameOver =
     statusKind === 'Completed' ||
     statusKind === 'Forfeited' ||
+    statusKind === 'OutOfAmmo' ||
+    statusKind === 'Drawn' ||
     statusKind === 'WinnerRevealed';
   const canSelect = !!canAttack && !!isMyTurn && !sending && !gameOver;
   const targetKey = selectedTarget ? `${selectedTarget.x},${selectedTarget.y}` : null;
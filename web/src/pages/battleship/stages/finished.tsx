@@ -27,7 +27,7 @@ export function FinishedStage({
               ALL ENEMY SHIPS DESTROYED
             </p>
           </>
-        ) : isPlayer ? (
+        ) : isPlayer && winner ? (
           <>
             <h2 className="text-arcade-red font-pixel animate-pixel-shake text-lg uppercase">
               DEFEAT
@@ -25,7 +25,7 @@ export function RevealedStage({
           <h2 className="text-arcade-green font-pixel animate-pixel-bounce text-lg uppercase">
             VICTORY!
           </h2>
-        ) : isPlayer ? (
+        ) : isPlayer && winner ? (
           <h2 className="text-arcade-red font-pixel text-lg uppercase">DEFEAT</h2>
         ) : (
           <h2 className="text-arcade-yellow font-pixel text-sm uppercase">GAME OVER</h2>
@@ -7,7 +7,11 @@
  * - Provides auth-token management for TEE-based ephemeral validators
  */
 import {
+  CAYED_ERROR__INVALID_OPEN_GAMES_PAGE,
+  CAYED_ERROR__OPEN_GAMES_PAGE_FULL,
   accountType,
+  fetchAllOpenGames,
+  fetchConfig,
   getCreateGameInstruction,
   getCreatePermissionInstruction,
   getDelegatePdaInstruction,
@@ -15,6 +19,10 @@ import {
   getJoinGameInstruction,
   getMakeMoveInstruction,
   getRevealWinnerInstruction,
+  type CellArgs,
+  type GameSettingsArgs,
+  type InviteArgs,
+  type OpenGames,
   type ShipCoordinatesArgs,
 } from '@client/cayed';
 import {
@@ -29,16 +37,30 @@ import {
   waitUntilPermissionActive,
 } from '@magicblock-labs/ephemeral-rollups-kit';
 import {
+  SOLANA_ERROR__INSTRUCTION_ERROR__CUSTOM,
   address,
+  isSolanaError,
   type Address,
   type Instruction,
+  type ReadonlyUint8Array,
   type TransactionSigner,
 } from '@solana/kit';
 import { connect, type Connection } from 'solana-kite';
 
-import { CAYED_PROGRAM_ADDRESS } from '@/lib/constants';
+import {
+  CAYED_PROGRAM_ADDRESS,
+  DEFAULT_GAME_SETTINGS,
+  OPEN_GAMES_PAGE_SIZE,
+} from '@/lib/constants';
 import { sendTransactionWithWallet } from '@/lib/send-transaction';
-import { deriveGamePdas, derivePlayerBoardPda, type GamePdas } from '@/services/pda';
+import {
+  deriveConfigPda,
+  deriveGamePdas,
+  deriveOpenGamesPda,
+  derivePlayerBoardPda,
+  derivePlayerGamesPda,
+  type GamePdas,
+} from '@/services/pda';
 
 // ─── Configuration ───────────────────────────────────────────────────
 
@@ -89,6 +111,34 @@ export const DEFAULT_GAME_SERVICE_CONFIG: GameServiceConfig = {
   erValidator: erValidatorAddress(),
 };
 
+// ─── Errors ──────────────────────────────────────────────────────────
+
+/** Sends `createGame` makes before giving up on a contended game id or page. */
+const CREATE_GAME_ATTEMPTS = 3;
+
+/**
+ * Errors `createGame` hits when another creator got in first: Anchor's
+ * `ConstraintSeeds` (a stale game id), or a page that filled up or was opened.
+ */
+const CREATE_GAME_RACE_ERRORS = [
+  2006,
+  CAYED_ERROR__INVALID_OPEN_GAMES_PAGE,
+  CAYED_ERROR__OPEN_GAMES_PAGE_FULL,
+];
+
+/** Custom program error code behind a failed send, if there is one. */
+function customErrorCode(err: unknown): number | undefined {
+  for (let e: unknown = err; e; e = (e as { cause?: unknown }).cause) {
+    if (isSolanaError(e, SOLANA_ERROR__INSTRUCTION_ERROR__CUSTOM)) {
+      return e.context.code;
+    }
+    // `sendTransactionWithWallet` reports confirmed failures as JSON
+    const match = /"Custom":(\d+)/.exec(String(e));
+    if (match) return Number(match[1]);
+  }
+  return undefined;
+}
+
 // ─── Service ─────────────────────────────────────────────────────────
 
 export class GameService {
@@ -205,6 +255,12 @@ export class GameService {
   /**
    * Create a new game **and** delegate the creator's player-board to the ER.
    *
+   * The program assigns the game id from `Config.gameCounter`, so the id is
+   * read from the config account and returned alongside the PDAs. Another
+   * creator can take that id, or fill the chosen open-games page, before this
+   * transaction lands; the program then rejects it and it is rebuilt from a
+   * fresh config, up to `CREATE_GAME_ATTEMPTS` times.
+   *
    * Sends a single transaction containing:
    * 1. `createGame`
    * 2. `createPermission` (player board)
@@ -213,34 +269,73 @@ export class GameService {
    */
   async createGame(opts: {
     player: TransactionSigner;
-    gameId: bigint;
     gridSize: number;
     wager: bigint;
-  }): Promise<{ pdas: GamePdas }> {
-    const { player, gameId, gridSize, wager } = opts;
-    const pdas = await deriveGamePdas(gameId, player.address);
-
-    // 1. Create game instruction
-    const createGameIx = getCreateGameInstruction({
+    settings?: GameSettingsArgs;
+    invite?: InviteArgs;
+    map?: Address;
+  }): Promise<{ gameId: bigint; pdas: GamePdas }> {
+    const {
       player,
-      game: pdas.gamePda,
-      playerBoard: pdas.playerBoardPda,
-      config: pdas.configPda,
-      vault: pdas.vaultPda,
-      id: gameId,
       gridSize,
       wager,
-    });
+      settings = DEFAULT_GAME_SETTINGS,
+      invite,
+      map,
+    } = opts;
+    for (let attempt = 1; ; attempt++) {
+      const config = await fetchConfig(this.devnet.rpc, await deriveConfigPda());
+      const gameId = config.data.gameCounter;
+
+      // List the game on the first page with room, or open the next page
+      const pages = await this.fetchOpenGamesPages(config.data.openGamesPages);
+      const free = pages.findIndex(p => p.entries.length < OPEN_GAMES_PAGE_SIZE);
+      const page = free === -1 ? pages.length : free;
+
+      const [pdas, playerGames, openGames] = await Promise.all([
+        deriveGamePdas(gameId, player.address),
+        derivePlayerGamesPda(player.address),
+        deriveOpenGamesPda(page),
+      ]);
+
+      // 1. Create game instruction
+      const createGameIx = getCreateGameInstruction({
+        player,
+        game: pdas.gamePda,
+        playerBoard: pdas.playerBoardPda,
+        playerGames,
+        config: pdas.configPda,
+        openGames,
+        map,
+        vault: pdas.vaultPda,
+        gridSize,
+        wager,
+        settings,
+        firstMoveCommitment: null,
+        invite: invite ?? null,
+        page,
+      });
 
-    // 2-4. Permission + delegation for the player board
-    const delegationIxs = await this.buildBoardDelegationIxs(
-      player,
-      pdas.playerBoardPda,
-      gameId
-    );
+      // 2-4. Permission + delegation for the player board
+      const delegationIxs = await this.buildBoardDelegationIxs(
+        player,
+        pdas.playerBoardPda,
+        gameId
+      );
 
-    await this.sendOnDevnet(player, [createGameIx, ...delegationIxs]);
-    return { pdas };
+      try {
+        await this.sendOnDevnet(player, [createGameIx, ...delegationIxs]);
+        return { gameId, pdas };
+      } catch (err) {
+        const code = customErrorCode(err);
+        const raced = code !== undefined && CREATE_GAME_RACE_ERRORS.includes(code);
+        if (!raced || attempt === CREATE_GAME_ATTEMPTS) throw err;
+        console.warn(
+          `createGame attempt ${attempt}/${CREATE_GAME_ATTEMPTS} raced another creator, retrying...`,
+          err
+        );
+      }
+    }
   }
 
   /**
@@ -257,17 +352,36 @@ export class GameService {
   async joinGame(opts: {
     player: TransactionSigner;
     gameId: bigint;
+    /** Secret code for games created with a `CodeHash` invite. */
+    inviteCode?: ReadonlyUint8Array;
   }): Promise<{ playerBoardPda: Address }> {
-    const { player, gameId } = opts;
-    const { gamePda, vaultPda } = await deriveGamePdas(gameId, player.address);
+    const { player, gameId, inviteCode } = opts;
+    const { gamePda, configPda, vaultPda } = await deriveGamePdas(gameId, player.address);
     const playerBoardPda = await derivePlayerBoardPda(gameId, player.address);
 
+    // Joining unlists the game, so pass the page that lists it
+    const config = await fetchConfig(this.devnet.rpc, configPda);
+    const pages = await this.fetchOpenGamesPages(config.data.openGamesPages);
+    const page = pages.findIndex(p => p.entries.some(e => e.id === gameId));
+    if (page === -1) {
+      throw new Error(`Game ${gameId} is not open for joining`);
+    }
+    const [playerGames, openGames] = await Promise.all([
+      derivePlayerGamesPda(player.address),
+      deriveOpenGamesPda(page),
+    ]);
+
     // 1. Join game — separate tx so the total size stays under 1232 bytes
     const joinGameIx = getJoinGameInstruction({
       player,
       game: gamePda,
       playerBoard: playerBoardPda,
+      playerGames,
+      openGames,
+      config: configPda,
       vault: vaultPda,
+      firstMoveCommitment: null,
+      inviteCode: inviteCode ?? null,
     });
 
     // await this.sendOnDevnet(player, [joinGameIx]);
@@ -294,6 +408,7 @@ export class GameService {
 
   /**
    * Place ships on the player's board.  Sent on the **ephemeral** connection.
+   * Mines and decoys are only needed when the game settings ask for them.
    *
    * Optionally waits for the board permission to become active on the ER
    * before sending (set `waitForPermission: true`).
@@ -303,9 +418,19 @@ export class GameService {
     gamePda: Address;
     playerBoardPda: Address;
     ships: ShipCoordinatesArgs[];
+    mines?: CellArgs[];
+    decoys?: CellArgs[];
     waitForPermission?: boolean;
   }): Promise<void> {
-    const { player, gamePda, playerBoardPda, ships, waitForPermission } = opts;
+    const {
+      player,
+      gamePda,
+      playerBoardPda,
+      ships,
+      mines = [],
+      decoys = [],
+      waitForPermission,
+    } = opts;
 
     if (waitForPermission) {
       const ok = await waitUntilPermissionActive(
@@ -325,6 +450,8 @@ export class GameService {
       game: gamePda,
       playerBoard: playerBoardPda,
       ships,
+      mines,
+      decoys,
     });
 
     await this.sendOnEphemeral(player, [ix]);
@@ -397,6 +524,17 @@ export class GameService {
     return waitUntilPermissionActive(this.config.ephemeralUrl, boardPda, timeout);
   }
 
+  // ─── Internal: open-games registry ────────────────────────────────
+
+  /** Fetch every open-games page, in page order. */
+  private async fetchOpenGamesPages(pageCount: number): Promise<OpenGames[]> {
+    const addresses = await Promise.all(
+      Array.from({ length: pageCount }, (_, page) => deriveOpenGamesPda(page))
+    );
+    const pages = await fetchAllOpenGames(this.devnet.rpc, addresses);
+    return pages.map(p => p.data);
+  }
+
   // ─── Internal: delegation instruction builders ────────────────────
 
   /**
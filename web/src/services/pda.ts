@@ -4,12 +4,11 @@
  * Every PDA the app needs is derived here so that no page/component ever
  * has to assemble raw seeds.
  */
+import { getU32Encoder, type Address } from '@solana/kit';
 import { getPDAAndBump } from 'solana-kite';
 
 import { CAYED_PROGRAM_ADDRESS } from '@/lib/constants';
 
-import type { Address } from '@solana/kit';
-
 /** All PDAs associated with a specific game + a specific player. */
 export type GamePdas = {
   gamePda: Address;
@@ -48,6 +47,20 @@ export async function deriveVaultPda(): Promise<Address> {
   return pda;
 }
 
+export async function derivePlayerGamesPda(player: Address): Promise<Address> {
+  const { pda } = await getPDAAndBump(CAYED_PROGRAM_ADDRESS, ['player_games', player]);
+  return pda;
+}
+
+/** Open-games pages are keyed by a `u32` page number, not a `u64`. */
+export async function deriveOpenGamesPda(page: number): Promise<Address> {
+  const { pda } = await getPDAAndBump(CAYED_PROGRAM_ADDRESS, [
+    'open_games',
+    getU32Encoder().encode(page),
+  ]);
+  return pda;
+}
+
 // ─── Batch derivers ──────────────────────────────────────────────────
 
 /** Derive the core set of PDAs needed for a single player in a game. */